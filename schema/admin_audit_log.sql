@@ -0,0 +1,24 @@
+CREATE TABLE IF NOT EXISTS admin_audit_log (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    actor_id TEXT NOT NULL,
+    tool TEXT NOT NULL,
+    action TEXT NOT NULL,
+    target_id TEXT NOT NULL,
+    before JSONB,
+    after JSONB,
+    arguments JSONB NOT NULL DEFAULT '{}'::jsonb,
+    result JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_admin_audit_log_created
+    ON admin_audit_log (created_at DESC, id DESC);
+
+CREATE INDEX IF NOT EXISTS idx_admin_audit_log_actor
+    ON admin_audit_log (actor_id, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_admin_audit_log_target
+    ON admin_audit_log (target_id, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_admin_audit_log_action
+    ON admin_audit_log (action, created_at DESC);
@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS admin_user_suspensions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    reason TEXT NOT NULL,
+    previous_status TEXT NOT NULL,
+    suspended_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ,
+    lifted_at TIMESTAMPTZ,
+    ended_sessions BIGINT NOT NULL DEFAULT 0
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_admin_user_suspensions_open
+    ON admin_user_suspensions (user_id)
+    WHERE lifted_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_admin_user_suspensions_expiry
+    ON admin_user_suspensions (expires_at)
+    WHERE lifted_at IS NULL AND expires_at IS NOT NULL;
@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS admin_user_tokens (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    purpose TEXT NOT NULL CHECK (purpose IN ('invite', 'password_reset')),
+    token_hash TEXT NOT NULL UNIQUE,
+    delivery_channel TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_admin_user_tokens_user
+    ON admin_user_tokens (user_id, purpose)
+    WHERE used_at IS NULL;
@@ -1,13 +1,56 @@
-#[must_use]
-pub fn build_admin_analysis_prompt(focus_area: &str, time_period: &str) -> String {
-    format!(
+use anyhow::Result;
+use rmcp::model::Tool;
+
+use super::tool_guide::{tool_steps, ToolGuide};
+
+pub const TIME_PERIODS: [&str; 4] = ["1h", "24h", "7d", "30d"];
+
+pub fn build_admin_analysis_prompt(
+    tools: &[Tool],
+    focus_area: &str,
+    time_period: &str,
+) -> Result<String> {
+    let guide = ToolGuide::new(tools);
+    let traffic_range = traffic_time_range(time_period);
+
+    let steps = tool_steps(vec![
+        guide.step(
+            "logs",
+            &[("level", "ERROR")],
+            "to review system errors, warnings and patterns",
+        ),
+        guide.step(
+            "jobs",
+            &[],
+            "to check scheduler jobs, their last status and failures",
+        ),
+        guide.step(
+            "conversations",
+            &[("time_range", time_period)],
+            "to analyze conversation volume, failures and execution time",
+        ),
+        guide.step(
+            "traffic",
+            &[("time_range", traffic_range)],
+            "to analyze sessions, requests, devices and geography",
+        ),
+        guide.step(
+            "user",
+            &[("action", "list")],
+            "to review user accounts, roles and session counts",
+        ),
+        guide.step(
+            "operations",
+            &[("action", "validate_config")],
+            "to validate agent and skill configuration",
+        ),
+    ])?;
+
+    Ok(format!(
         "You are a SystemPrompt administrator analyzing the system. Focus on: {focus_area}\n\
         Time period: {time_period}\n\n\
         Use the following tools to gather comprehensive data:\n\
-        1. get_logs - Review system logs for errors, warnings, and patterns\n\
-        2. db_admin - Analyze database performance and structure\n\
-        3. system_status - Check disk space, memory, and critical resources\n\
-        4. user_activity - Analyze user engagement and activity patterns\n\n\
+        {steps}\n\n\
         Provide analysis in this structure:\n\
         ## Executive Summary\n\
         - Overall system health status\n\
@@ -17,23 +60,31 @@ pub fn build_admin_analysis_prompt(focus_area: &str, time_period: &str) -> Strin
         ### Logs Analysis\n\
         - Error patterns and frequency\n\
         - Warning trends\n\
-        - Performance indicators\n\n\
-        ### Database Health\n\
-        - Connection status and performance\n\
-        - Table sizes and growth patterns\n\
-        - Query performance indicators\n\n\
-        ### System Resources\n\
-        - Disk space utilization and trends\n\
-        - Memory usage patterns\n\
-        - Critical resource availability\n\n\
+        - Modules generating the most errors\n\n\
+        ### Scheduler Health\n\
+        - Failed or stale jobs\n\
+        - Recurring job errors\n\n\
+        ### Conversations and Agents\n\
+        - Conversation volume and failure rate\n\
+        - Slow or failing agents\n\n\
         ### User Activity\n\
         - Active user counts and trends\n\
-        - Engagement patterns\n\
+        - Traffic sources and engagement patterns\n\
         - Growth metrics\n\n\
         ## Recommendations\n\
         - Immediate actions required\n\
         - Medium-term optimizations\n\
         - Long-term strategic considerations\n\n\
-        Focus your analysis on the {focus_area} area with a {time_period} time horizon."
-    )
+        Focus your analysis on the {focus_area} area with a {time_period} time horizon.\n\n\
+        {}",
+        guide.reference()
+    ))
+}
+
+fn traffic_time_range(time_period: &str) -> &'static str {
+    match time_period {
+        "90d" => "90d",
+        "30d" => "30d",
+        _ => "7d",
+    }
 }
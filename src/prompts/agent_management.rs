@@ -143,7 +143,7 @@ pub fn build_agent_prompt_content(task_type: &str, domain: &str) -> String {
         - Update agents with operation='update' for improvements\n\
         - Consider agent communication patterns\n\n\
         ### For 'troubleshoot' tasks:\n\
-        - Check agent logs with get_logs tool\n\
+        - Check agent logs with the logs tool\n\
         - Verify agent configurations\n\
         - Test agent endpoints and connectivity\n\
         - Diagnose and fix issues\n\n\
@@ -0,0 +1,107 @@
+use anyhow::Result;
+use chrono::{DateTime, TimeDelta, Utc};
+use systemprompt::database::DbPool;
+use systemprompt::scheduler::repository::SchedulerRepository;
+
+use crate::tools::conversations::models::ConversationSummary;
+use crate::tools::conversations::repository::ConversationsRepository;
+use crate::tools::logs::models::{LogFilter, LogStats};
+use crate::tools::logs::repository::LogsRepository;
+
+const CONVERSATION_WINDOW: &str = "1 day";
+const LOG_WINDOW_HOURS: i64 = 24;
+
+#[derive(Debug, serde::Serialize)]
+pub struct SnapshotJob {
+    pub job_name: String,
+    pub schedule: String,
+    pub last_run: Option<DateTime<Utc>>,
+    pub last_status: Option<String>,
+    pub run_count: i64,
+    pub last_error: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+pub struct SystemHealthSnapshot {
+    pub generated_at: DateTime<Utc>,
+    pub logs_24h: LogStats,
+    pub jobs: Vec<SnapshotJob>,
+    pub conversations_24h: ConversationSummary,
+}
+
+pub async fn fetch_system_health_snapshot(pool: &DbPool) -> Result<SystemHealthSnapshot> {
+    let generated_at = Utc::now();
+    let log_filter = LogFilter {
+        since: Some(generated_at - TimeDelta::hours(LOG_WINDOW_HOURS)),
+        ..LogFilter::default()
+    };
+    let logs_24h = LogsRepository::new(pool.clone())?
+        .fetch_log_stats(&log_filter)
+        .await?;
+    let jobs = SchedulerRepository::new(pool)?.list_enabled_jobs().await?;
+    let conversations_24h = ConversationsRepository::new(pool.clone())?
+        .get_conversation_summary(CONVERSATION_WINDOW)
+        .await?;
+
+    Ok(SystemHealthSnapshot {
+        generated_at,
+        logs_24h,
+        jobs: jobs
+            .into_iter()
+            .map(|job| SnapshotJob {
+                job_name: job.job_name,
+                schedule: job.schedule,
+                last_run: job.last_run,
+                last_status: job.last_status,
+                run_count: i64::from(job.run_count),
+                last_error: job.last_error,
+            })
+            .collect(),
+        conversations_24h,
+    })
+}
+
+#[must_use]
+pub fn summarize_snapshot(snapshot: &SystemHealthSnapshot) -> String {
+    let logs = &snapshot.logs_24h;
+    let conversations = &snapshot.conversations_24h;
+    let failing_jobs: Vec<&str> = snapshot
+        .jobs
+        .iter()
+        .filter(|job| job.last_status.as_deref() == Some("failed"))
+        .map(|job| job.job_name.as_str())
+        .collect();
+
+    format!(
+        "Live metrics captured at {}. Start your assessment from these numbers and only call tools to drill down.\n\n\
+        - **Logs (24h)**: {} total, {} errors, {} warnings across {} modules (last entry: {})\n\
+        - **Scheduler**: {} enabled jobs, {} failing{}\n\
+        - **Conversations (24h)**: {} conversations, {} messages, {} failed, {:.0}ms average execution time\n\n\
+        The full snapshot follows as JSON.",
+        snapshot.generated_at.to_rfc3339(),
+        logs.total_logs,
+        logs.error_count,
+        logs.warn_count,
+        logs.unique_modules,
+        logs.last_log_time.as_deref().unwrap_or("never"),
+        snapshot.jobs.len(),
+        failing_jobs.len(),
+        if failing_jobs.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", failing_jobs.join(", "))
+        },
+        conversations.total_conversations,
+        conversations.total_messages,
+        conversations.failed_conversations,
+        conversations.avg_execution_time_ms,
+    )
+}
+
+pub fn snapshot_message(snapshot: &SystemHealthSnapshot) -> Result<String> {
+    Ok(format!(
+        "{}\n\n```json\n{}\n```",
+        summarize_snapshot(snapshot),
+        serde_json::to_string_pretty(snapshot)?
+    ))
+}
@@ -1,27 +1,32 @@
 pub mod admin_analysis;
 pub mod agent_management;
+pub mod live_metrics;
 pub mod system_health;
+pub mod tool_guide;
 
 use anyhow::Result;
 use rmcp::{
     model::{
         GetPromptRequestParam, GetPromptResult, ListPromptsResult, PaginatedRequestParam, Prompt,
-        PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole,
+        PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole, Tool,
     },
     service::RequestContext,
     ErrorData as McpError, RoleServer,
 };
+use serde_json::{Map as JsonMap, Value as JsonValue};
 use systemprompt::database::DbPool;
 
+use crate::tools::permissions::Caller;
+
 pub use admin_analysis::build_admin_analysis_prompt;
 pub use agent_management::{
     build_agent_prompt_content, get_agent_operation_schema, AGENT_MANAGEMENT_PROMPT,
 };
-pub use system_health::build_system_health_prompt;
+pub use system_health::{build_system_health_prompt, SYSTEM_HEALTH_PERMISSION};
 
 #[derive(Debug, Clone)]
 pub struct AdminPrompts {
-    _db_pool: DbPool,
+    db_pool: DbPool,
     _server_name: String,
 }
 
@@ -29,7 +34,7 @@ impl AdminPrompts {
     #[must_use]
     pub fn new(db_pool: DbPool, server_name: String) -> Self {
         Self {
-            _db_pool: db_pool,
+            db_pool,
             _server_name: server_name,
         }
     }
@@ -38,67 +43,15 @@ impl AdminPrompts {
         &self,
         _request: Option<PaginatedRequestParam>,
         _ctx: RequestContext<RoleServer>,
+        caller: &Caller,
     ) -> Result<ListPromptsResult, McpError> {
+        let mut prompts = prompt_catalog();
+        if !caller.has_permission(SYSTEM_HEALTH_PERMISSION) {
+            prompts.retain(|prompt| prompt.name != "system_health");
+        }
+
         Ok(ListPromptsResult {
-            prompts: vec![
-                Prompt {
-                    name: "admin_analysis".into(),
-                    description: Some("Comprehensive system analysis prompt for administrative tasks".into()),
-                    arguments: Some(vec![
-                        PromptArgument {
-                            name: "focus_area".into(),
-                            description: Some("Area to focus analysis on (logs, database, system, users, all)".into()),
-                            required: Some(false),
-                            title: None,
-                        },
-                        PromptArgument {
-                            name: "time_period".into(),
-                            description: Some("Time period for analysis (1h, 24h, 7d, 30d)".into()),
-                            required: Some(false),
-                            title: None,
-                        },
-                    ]),
-                    title: None,
-                    icons: None,
-                    meta: None,
-                },
-                Prompt {
-                    name: "system_health".into(),
-                    description: Some("System health check prompt with detailed analysis guidelines".into()),
-                    arguments: Some(vec![
-                        PromptArgument {
-                            name: "include_recommendations".into(),
-                            description: Some("Include actionable recommendations in the analysis".into()),
-                            required: Some(false),
-                            title: None,
-                        },
-                    ]),
-                    title: None,
-                    icons: None,
-                    meta: None,
-                },
-                Prompt {
-                    name: "agent_management".into(),
-                    description: Some("Agent management prompt for creating, updating, and managing agents with AI assistance".into()),
-                    arguments: Some(vec![
-                        PromptArgument {
-                            name: "task_type".into(),
-                            description: Some("Type of agent task: design, review, optimize, troubleshoot".into()),
-                            required: Some(false),
-                            title: None,
-                        },
-                        PromptArgument {
-                            name: "domain".into(),
-                            description: Some("Agent domain or specialization area".into()),
-                            required: Some(false),
-                            title: None,
-                        },
-                    ]),
-                    title: None,
-                    icons: None,
-                    meta: None,
-                },
-            ],
+            prompts,
             next_cursor: None,
             meta: None,
         })
@@ -108,56 +61,17 @@ impl AdminPrompts {
         &self,
         request: GetPromptRequestParam,
         _ctx: RequestContext<RoleServer>,
+        caller: &Caller,
+        tools: &[Tool],
     ) -> Result<GetPromptResult, McpError> {
         match request.name.as_ref() {
-            "admin_analysis" => {
-                let focus_area = request
-                    .arguments
-                    .as_ref()
-                    .and_then(|args| args.get("focus_area"))
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("all");
-
-                let time_period = request
-                    .arguments
-                    .as_ref()
-                    .and_then(|args| args.get("time_period"))
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("24h");
-
-                let prompt_content =
-                    admin_analysis::build_admin_analysis_prompt(focus_area, time_period);
-
-                Ok(GetPromptResult {
-                    description: Some(format!(
-                        "Administrative analysis focused on {focus_area} over {time_period}"
-                    )),
-                    messages: vec![PromptMessage {
-                        role: PromptMessageRole::User,
-                        content: PromptMessageContent::text(prompt_content),
-                    }],
-                })
-            }
+            "admin_analysis" => admin_analysis_prompt(request.arguments.as_ref(), tools),
             "system_health" => {
-                let include_recommendations = request
-                    .arguments
-                    .as_ref()
-                    .and_then(|args| args.get("include_recommendations"))
-                    .and_then(serde_json::Value::as_bool)
-                    .unwrap_or(true);
-
-                let prompt_content =
-                    system_health::build_system_health_prompt(include_recommendations);
-
-                Ok(GetPromptResult {
-                    description: Some(
-                        "Comprehensive system health check with diagnostic guidance".to_string(),
-                    ),
-                    messages: vec![PromptMessage {
-                        role: PromptMessageRole::User,
-                        content: PromptMessageContent::text(prompt_content),
-                    }],
-                })
+                caller
+                    .require_permission("the system_health prompt", SYSTEM_HEALTH_PERMISSION)
+                    .map_err(|message| McpError::invalid_request(message, None))?;
+                self.system_health_prompt(request.arguments.as_ref(), tools)
+                    .await
             }
             "agent_management" => {
                 let task_type = request
@@ -193,4 +107,154 @@ impl AdminPrompts {
             )),
         }
     }
+
+    async fn system_health_prompt(
+        &self,
+        arguments: Option<&JsonMap<String, JsonValue>>,
+        tools: &[Tool],
+    ) -> Result<GetPromptResult, McpError> {
+        let flag = |name: &str| {
+            arguments
+                .and_then(|args| args.get(name))
+                .and_then(JsonValue::as_bool)
+                .unwrap_or(true)
+        };
+
+        let prompt_content =
+            system_health::build_system_health_prompt(tools, flag("include_recommendations"))
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+        let mut messages = vec![PromptMessage {
+            role: PromptMessageRole::User,
+            content: PromptMessageContent::text(prompt_content),
+        }];
+
+        if flag("include_live_metrics") {
+            messages.push(self.live_metrics_message().await?);
+        }
+
+        Ok(GetPromptResult {
+            description: Some(
+                "Comprehensive system health check with diagnostic guidance".to_string(),
+            ),
+            messages,
+        })
+    }
+
+    async fn live_metrics_message(&self) -> Result<PromptMessage, McpError> {
+        let snapshot = live_metrics::fetch_system_health_snapshot(&self.db_pool)
+            .await
+            .and_then(|snapshot| live_metrics::snapshot_message(&snapshot))
+            .map_err(|e| {
+                McpError::internal_error(format!("Failed to fetch live system metrics: {e}"), None)
+            })?;
+
+        Ok(PromptMessage::new_text(PromptMessageRole::User, snapshot))
+    }
+}
+
+fn prompt_catalog() -> Vec<Prompt> {
+    vec![
+        Prompt {
+            name: "admin_analysis".into(),
+            description: Some("Comprehensive system analysis prompt for administrative tasks".into()),
+            arguments: Some(vec![
+                PromptArgument {
+                    name: "focus_area".into(),
+                    description: Some("Area to focus analysis on (logs, scheduler, conversations, users, all)".into()),
+                    required: Some(false),
+                    title: None,
+                },
+                PromptArgument {
+                    name: "time_period".into(),
+                    description: Some("Time period for analysis (1h, 24h, 7d, 30d)".into()),
+                    required: Some(false),
+                    title: None,
+                },
+            ]),
+            title: None,
+            icons: None,
+            meta: None,
+        },
+        Prompt {
+            name: "system_health".into(),
+            description: Some("System health check prompt pre-loaded with live log, scheduler and conversation metrics".into()),
+            arguments: Some(vec![
+                PromptArgument {
+                    name: "include_recommendations".into(),
+                    description: Some("Include actionable recommendations in the analysis".into()),
+                    required: Some(false),
+                    title: None,
+                },
+                PromptArgument {
+                    name: "include_live_metrics".into(),
+                    description: Some("Embed current log, scheduler and conversation metrics (default true)".into()),
+                    required: Some(false),
+                    title: None,
+                },
+            ]),
+            title: None,
+            icons: None,
+            meta: None,
+        },
+        Prompt {
+            name: "agent_management".into(),
+            description: Some("Agent management prompt for creating, updating, and managing agents with AI assistance".into()),
+            arguments: Some(vec![
+                PromptArgument {
+                    name: "task_type".into(),
+                    description: Some("Type of agent task: design, review, optimize, troubleshoot".into()),
+                    required: Some(false),
+                    title: None,
+                },
+                PromptArgument {
+                    name: "domain".into(),
+                    description: Some("Agent domain or specialization area".into()),
+                    required: Some(false),
+                    title: None,
+                },
+            ]),
+            title: None,
+            icons: None,
+            meta: None,
+        },
+    ]
+}
+
+fn admin_analysis_prompt(
+    arguments: Option<&JsonMap<String, JsonValue>>,
+    tools: &[Tool],
+) -> Result<GetPromptResult, McpError> {
+    let focus_area = arguments
+        .and_then(|args| args.get("focus_area"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("all");
+
+    let time_period = arguments
+        .and_then(|args| args.get("time_period"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("24h");
+    if !admin_analysis::TIME_PERIODS.contains(&time_period) {
+        return Err(McpError::invalid_params(
+            format!(
+                "Invalid time_period: {time_period}. Expected one of {}",
+                admin_analysis::TIME_PERIODS.join(", ")
+            ),
+            None,
+        ));
+    }
+
+    let prompt_content =
+        admin_analysis::build_admin_analysis_prompt(tools, focus_area, time_period)
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    Ok(GetPromptResult {
+        description: Some(format!(
+            "Administrative analysis focused on {focus_area} over {time_period}"
+        )),
+        messages: vec![PromptMessage {
+            role: PromptMessageRole::User,
+            content: PromptMessageContent::text(prompt_content),
+        }],
+    })
 }
@@ -1,40 +1,100 @@
-#[must_use]
-pub fn build_system_health_prompt(include_recommendations: bool) -> String {
-    format!(
-        "Perform a comprehensive SystemPrompt health check using all available admin tools.\n\n\
+use anyhow::Result;
+use rmcp::model::Tool;
+
+use super::tool_guide::{tool_steps, ToolGuide};
+
+pub const SYSTEM_HEALTH_PERMISSION: &str = "admin.system.read";
+
+pub fn build_system_health_prompt(tools: &[Tool], include_recommendations: bool) -> Result<String> {
+    let guide = ToolGuide::new(tools);
+
+    let infrastructure_steps = tool_steps(vec![guide.step(
+        "health",
+        &[],
+        "to check database latency, pool utilisation, table growth, disk and memory",
+    )])?;
+
+    let scheduler_steps = tool_steps(vec![guide.step(
+        "jobs",
+        &[],
+        "to list every scheduler job with its last run, status, run count and last error",
+    )])?;
+
+    let log_steps = tool_steps(vec![
+        guide.step(
+            "logs",
+            &[("level", "ERROR")],
+            "to identify critical issues and the modules raising them",
+        ),
+        guide.step(
+            "logs",
+            &[("level", "WARN")],
+            "to spot potential problems before they become errors",
+        ),
+        guide.step(
+            "logs",
+            &[("mode", "clusters")],
+            "to group recurring problems and find the fastest growing ones",
+        ),
+    ])?;
+
+    let conversation_steps = tool_steps(vec![guide.step(
+        "conversations",
+        &[("time_range", "24h")],
+        "to check failed conversations and average execution time",
+    )])?;
+
+    let activity_steps = tool_steps(vec![
+        guide.step(
+            "traffic",
+            &[("time_range", "7d")],
+            "to assess sessions, requests and AI cost",
+        ),
+        guide.step(
+            "user",
+            &[("action", "list")],
+            "to review recent sign-ups and session counts",
+        ),
+    ])?;
+
+    Ok(format!(
+        "Perform a comprehensive SystemPrompt health check using the admin tools available to you on this server.\n\n\
         Execute the following diagnostic sequence:\n\n\
-        1. **System Status Check**\n\
-        - Use system_status tool to check disk space, memory, and database connectivity\n\
-        - Identify any resource constraints or critical thresholds\n\n\
-        2. **Database Health Assessment**\n\
-        - Use db_admin with action='info' to check database connectivity and version\n\
-        - Use db_admin with action='tables' to review table structure and sizes\n\
-        - Look for unusually large tables or growth patterns\n\n\
+        1. **Infrastructure Check**\n\
+        {infrastructure_steps}\n\
+        - Treat an amber or red overall status as a finding to explain\n\n\
+        2. **Scheduler Status Check**\n\
+        {scheduler_steps}\n\
+        - Flag jobs whose last status is failed or that have not run recently\n\n\
         3. **Log Analysis**\n\
-        - Use get_logs with level_filter='error' to identify critical issues\n\
-        - Use get_logs with level_filter='warn' to spot potential problems\n\
+        {log_steps}\n\
         - Analyze recent log patterns for anomalies\n\n\
-        4. **User Activity Review**\n\
-        - Use user_activity to assess system usage and growth\n\
+        4. **Conversation Health**\n\
+        {conversation_steps}\n\
+        - Look for agents with unusually high failure rates\n\n\
+        5. **User Activity Review**\n\
+        {activity_steps}\n\
         - Check for unusual activity patterns or user engagement drops\n\n\
         Provide your health assessment in this format:\n\n\
         # SystemPrompt Health Report\n\
         **Generated**: [Current timestamp]\n\
         **Status**: 🟢 HEALTHY / 🟡 WARNING / 🔴 CRITICAL\n\n\
-        ## Resource Status\n\
-        - **Disk Space**: [Available/Used with percentage]\n\
-        - **Memory**: [Usage statistics]\n\
-        - **Database**: [Connection status and performance]\n\n\
+        ## Scheduler Status\n\
+        - **Failing Jobs**: [Jobs with a failed last status and their errors]\n\
+        - **Stale Jobs**: [Jobs that have not run on schedule]\n\n\
         ## Critical Issues\n\
         [List any immediate concerns requiring attention]\n\n\
         ## Warning Indicators\n\
         [List potential issues that should be monitored]\n\n\
         ## Performance Metrics\n\
+        - **Database Latency**: [Latency and pool utilisation from the health check]\n\
+        - **Resource Usage**: [Disk and memory usage]\n\
         - **Recent Errors**: [Count from logs]\n\
-        - **User Activity**: [Recent activity trends]\n\
-        - **System Responsiveness**: [Database response times]\n\n\
+        - **Conversation Failures**: [Failed conversations and execution time]\n\
+        - **User Activity**: [Recent activity trends]\n\n\
         {}\n\n\
-        **Next Review**: Recommend scheduling next health check",
+        **Next Review**: Recommend scheduling next health check\n\n\
+        {}",
         if include_recommendations {
             "## Recommendations\n\
             ### Immediate Actions\n\
@@ -45,6 +105,7 @@ pub fn build_system_health_prompt(include_recommendations: bool) -> String {
             [Strategic improvements for system growth]"
         } else {
             ""
-        }
-    )
+        },
+        guide.reference()
+    ))
 }
@@ -0,0 +1,144 @@
+use anyhow::{anyhow, Result};
+use rmcp::model::Tool;
+use serde_json::Value as JsonValue;
+
+pub struct ToolGuide<'a> {
+    registered: Vec<Tool>,
+    available: &'a [Tool],
+}
+
+impl<'a> ToolGuide<'a> {
+    #[must_use]
+    pub fn new(available: &'a [Tool]) -> Self {
+        Self {
+            registered: crate::tools::register_tools(),
+            available,
+        }
+    }
+
+    pub fn step(
+        &self,
+        name: &str,
+        arguments: &[(&str, &str)],
+        purpose: &str,
+    ) -> Result<Option<String>> {
+        let step = tool_step(&self.registered, name, arguments, purpose)?;
+        Ok(self
+            .available
+            .iter()
+            .any(|tool| tool.name == name)
+            .then_some(step))
+    }
+
+    #[must_use]
+    pub fn reference(&self) -> String {
+        tool_reference(self.available)
+    }
+}
+
+pub fn tool_step(
+    tools: &[Tool],
+    name: &str,
+    arguments: &[(&str, &str)],
+    purpose: &str,
+) -> Result<String> {
+    let tool = tools
+        .iter()
+        .find(|t| t.name == name)
+        .ok_or_else(|| anyhow!("Prompt references unregistered tool `{name}`"))?;
+    let properties = tool
+        .input_schema
+        .get("properties")
+        .and_then(JsonValue::as_object)
+        .ok_or_else(|| anyhow!("Tool `{name}` does not declare any arguments"))?;
+
+    for (arg, value) in arguments {
+        let schema = properties
+            .get(*arg)
+            .ok_or_else(|| anyhow!("Tool `{name}` does not accept argument `{arg}`"))?;
+        let allowed = enum_values(schema);
+        if !allowed.is_empty() && !allowed.contains(value) {
+            return Err(anyhow!(
+                "Tool `{name}` does not accept `{arg}`=\"{value}\". Expected one of {}",
+                allowed.join(", ")
+            ));
+        }
+    }
+
+    let with_arguments = if arguments.is_empty() {
+        "with no arguments".to_string()
+    } else {
+        let rendered: Vec<String> = arguments
+            .iter()
+            .map(|(arg, value)| format!("`{arg}`=\"{value}\""))
+            .collect();
+        format!("with {}", rendered.join(", "))
+    };
+
+    Ok(format!("- Call `{name}` {with_arguments} {purpose}"))
+}
+
+pub fn tool_steps(steps: Vec<Result<Option<String>>>) -> Result<String> {
+    let steps: Vec<String> = steps
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect();
+    if steps.is_empty() {
+        return Ok("- None of the tools for this step are available to your roles".to_string());
+    }
+    Ok(steps.join("\n"))
+}
+
+fn enum_values(schema: &JsonValue) -> Vec<&str> {
+    schema
+        .get("enum")
+        .and_then(JsonValue::as_array)
+        .map(|values| values.iter().filter_map(JsonValue::as_str).collect())
+        .unwrap_or_default()
+}
+
+#[must_use]
+pub fn tool_reference(tools: &[Tool]) -> String {
+    let mut reference = String::from("## Available Admin Tools\n");
+
+    for tool in tools {
+        reference.push_str(&format!("- `{}`", tool.name));
+        if let Some(description) = tool.description.as_deref() {
+            reference.push_str(&format!(": {description}"));
+        }
+
+        let arguments = describe_arguments(tool);
+        if !arguments.is_empty() {
+            reference.push_str(&format!(" Arguments: {arguments}"));
+        }
+        reference.push('\n');
+    }
+
+    reference
+}
+
+fn describe_arguments(tool: &Tool) -> String {
+    let Some(properties) = tool
+        .input_schema
+        .get("properties")
+        .and_then(JsonValue::as_object)
+    else {
+        return String::new();
+    };
+
+    properties
+        .iter()
+        .map(|(name, schema)| {
+            let values = enum_values(schema);
+
+            if values.is_empty() {
+                format!("`{name}`")
+            } else {
+                format!("`{name}` ({})", values.join("|"))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -1,48 +1,237 @@
+mod readers;
+mod subscriptions;
+pub mod uri;
+mod watcher;
+
 use anyhow::Result;
 use rmcp::{
     model::{
-        ListResourcesResult, PaginatedRequestParam, ReadResourceRequestParam, ReadResourceResult,
+        AnnotateAble, ListResourceTemplatesResult, ListResourcesResult, PaginatedRequestParam,
+        RawResource, RawResourceTemplate, ReadResourceRequestParam, ReadResourceResult,
+        ResourceContents, ResourceTemplate, SubscribeRequestParam, UnsubscribeRequestParam,
     },
     service::RequestContext,
     ErrorData as McpError, RoleServer,
 };
 use systemprompt::database::DbPool;
 
+use crate::tools::permissions::Caller;
+
+use readers::{list_all_jobs, read_admin_resource};
+pub(crate) use subscriptions::session_id;
+use subscriptions::ResourceSubscriptions;
+pub use uri::{AdminResourceUri, ERROR_LOGS_URI};
+
+const JSON_MIME_TYPE: &str = "application/json";
+
 #[derive(Clone)]
 pub struct AdminResources {
-    _db_pool: DbPool,
+    db_pool: DbPool,
     _server_name: String,
+    subscriptions: ResourceSubscriptions,
 }
 
 impl AdminResources {
     #[must_use]
     pub fn new(db_pool: DbPool, server_name: String) -> Self {
         Self {
-            _db_pool: db_pool,
+            db_pool,
             _server_name: server_name,
+            subscriptions: ResourceSubscriptions::default(),
         }
     }
 
+    pub fn start_change_watcher(&self) {
+        watcher::spawn_change_watcher(self.db_pool.clone(), self.subscriptions.clone());
+    }
+
     pub async fn list_resources(
         &self,
         _request: Option<PaginatedRequestParam>,
         _ctx: RequestContext<RoleServer>,
+        caller: &Caller,
     ) -> Result<ListResourcesResult, McpError> {
+        let jobs = list_all_jobs(&self.db_pool)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+        let error_logs = RawResource {
+            title: Some("Recent error logs".to_string()),
+            description: Some(
+                "Latest ERROR level log entries. Subscribe to be notified of new errors."
+                    .to_string(),
+            ),
+            mime_type: Some(JSON_MIME_TYPE.to_string()),
+            ..RawResource::new(ERROR_LOGS_URI, "error_logs")
+        }
+        .no_annotation();
+
+        let resources = std::iter::once(error_logs)
+            .chain(jobs.iter().map(|job| {
+                let uri = AdminResourceUri::Job(job.job_name.clone()).to_string();
+                RawResource {
+                    title: Some(format!("Scheduler job: {}", job.job_name)),
+                    description: Some(format!("Schedule {} with run history", job.schedule)),
+                    mime_type: Some(JSON_MIME_TYPE.to_string()),
+                    ..RawResource::new(uri, job.job_name.clone())
+                }
+                .no_annotation()
+            }))
+            .filter(|resource| readable(caller, &resource.uri))
+            .collect();
+
         Ok(ListResourcesResult {
             next_cursor: None,
-            resources: Vec::new(),
+            resources,
             meta: None,
         })
     }
 
+    pub async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _ctx: RequestContext<RoleServer>,
+        caller: &Caller,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        Ok(ListResourceTemplatesResult {
+            next_cursor: None,
+            resource_templates: resource_templates()
+                .into_iter()
+                .filter(|template| readable(caller, &template.uri_template))
+                .collect(),
+            meta: None,
+        })
+    }
+
+    pub async fn subscribe(
+        &self,
+        request: SubscribeRequestParam,
+        ctx: RequestContext<RoleServer>,
+        caller: &Caller,
+    ) -> Result<(), McpError> {
+        let uri = parse_subscribable(&request.uri)?;
+        authorize_read(caller, &uri)?;
+        self.subscriptions.subscribe(&request.uri, &ctx).await?;
+
+        tracing::info!(uri = %uri, "Resource subscription added");
+        Ok(())
+    }
+
+    pub async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParam,
+        ctx: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        self.subscriptions.unsubscribe(&request.uri, &ctx).await;
+
+        tracing::info!(uri = %request.uri, "Resource subscription removed");
+        Ok(())
+    }
+
     pub async fn read_resource(
         &self,
-        _request: ReadResourceRequestParam,
+        request: ReadResourceRequestParam,
         _ctx: RequestContext<RoleServer>,
+        caller: &Caller,
     ) -> Result<ReadResourceResult, McpError> {
-        Err(McpError::invalid_params(
-            "No resources currently available".to_string(),
-            None,
-        ))
+        let uri = AdminResourceUri::parse(&request.uri).ok_or_else(|| {
+            McpError::invalid_params(format!("Unsupported resource URI: {}", request.uri), None)
+        })?;
+        authorize_read(caller, &uri)?;
+
+        tracing::debug!(uri = %uri, "Reading admin resource");
+
+        let value = read_admin_resource(&self.db_pool, &uri)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?
+            .ok_or_else(|| {
+                McpError::resource_not_found(format!("Resource not found: {uri}"), None)
+            })?;
+
+        let text = serde_json::to_string_pretty(&value)
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::TextResourceContents {
+                uri: request.uri,
+                mime_type: Some(JSON_MIME_TYPE.to_string()),
+                text,
+                meta: None,
+            }],
+        })
     }
 }
+
+fn readable(caller: &Caller, uri: &str) -> bool {
+    AdminResourceUri::parse(uri).is_some_and(|uri| caller.has_permission(uri.read_permission()))
+}
+
+fn authorize_read(caller: &Caller, uri: &AdminResourceUri) -> Result<(), McpError> {
+    caller
+        .require_permission(&format!("reading {uri}"), uri.read_permission())
+        .map_err(|message| {
+            tracing::warn!(uri = %uri, user_id = %caller.user_id, "Resource access denied");
+            McpError::invalid_request(message, None)
+        })
+}
+
+fn parse_subscribable(uri: &str) -> Result<AdminResourceUri, McpError> {
+    AdminResourceUri::parse(uri)
+        .filter(AdminResourceUri::is_subscribable)
+        .ok_or_else(|| {
+            McpError::invalid_params(
+                format!(
+                    "Resource does not support subscriptions: {uri}. Subscribable: {ERROR_LOGS_URI}, admin://jobs/{{name}}"
+                ),
+                None,
+            )
+        })
+}
+
+#[must_use]
+pub fn resource_templates() -> Vec<ResourceTemplate> {
+    [
+        (
+            "admin://users/{id}",
+            "user",
+            "User",
+            "A single user with roles and session count",
+        ),
+        (
+            "admin://conversations/{context_id}",
+            "conversation",
+            "Conversation",
+            "Full message history for a conversation context",
+        ),
+        (
+            "admin://logs/{id}",
+            "log",
+            "Log Entry",
+            "A single log row with module and context",
+        ),
+        (
+            "admin://jobs/{name}",
+            "job",
+            "Scheduler Job",
+            "A scheduler job with its last run status",
+        ),
+        (
+            "admin://files/{uuid}",
+            "file",
+            "File",
+            "File metadata including path and public URL",
+        ),
+    ]
+    .into_iter()
+    .map(|(uri_template, name, title, description)| {
+        RawResourceTemplate {
+            uri_template: uri_template.to_string(),
+            name: name.to_string(),
+            title: Some(title.to_string()),
+            description: Some(description.to_string()),
+            mime_type: Some(JSON_MIME_TYPE.to_string()),
+        }
+        .no_annotation()
+    })
+    .collect()
+}
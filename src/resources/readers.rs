@@ -0,0 +1,122 @@
+use anyhow::Result;
+use serde_json::{json, Value as JsonValue};
+use sqlx::types::Uuid;
+use systemprompt::database::DbPool;
+use systemprompt::files::repository::FileRepository;
+use systemprompt::scheduler::models::ScheduledJob;
+use systemprompt::scheduler::repository::SchedulerRepository;
+
+use crate::tools::conversations::messages::fetch_conversation_messages;
+use crate::tools::jobs::runner::registered_job_names;
+use crate::tools::logs::models::LogFilter;
+use crate::tools::logs::repository::LogsRepository;
+use crate::tools::users::models::{UserFilter, UserSort};
+use crate::tools::users::repository::UsersRepository;
+
+use super::uri::AdminResourceUri;
+
+const ERROR_LOGS_LIMIT: i64 = 50;
+
+pub async fn read_admin_resource(
+    pool: &DbPool,
+    uri: &AdminResourceUri,
+) -> Result<Option<JsonValue>> {
+    match uri {
+        AdminResourceUri::User(id) => read_user(pool, id).await,
+        AdminResourceUri::Conversation(context_id) => read_conversation(pool, context_id).await,
+        AdminResourceUri::ErrorLogs => read_error_logs(pool).await,
+        AdminResourceUri::Log(id) => read_log(pool, id).await,
+        AdminResourceUri::Job(name) => read_job(pool, name).await,
+        AdminResourceUri::File(uuid) => read_file(pool, uuid).await,
+    }
+}
+
+async fn read_user(pool: &DbPool, id: &str) -> Result<Option<JsonValue>> {
+    let repo = UsersRepository::new(pool.clone())?;
+    let users = repo
+        .list_users(&UserFilter::by_id(id), UserSort::default(), 1, None)
+        .await?
+        .items;
+    Ok(users.into_iter().next().map(|user| json!(user)))
+}
+
+async fn read_conversation(pool: &DbPool, context_id: &str) -> Result<Option<JsonValue>> {
+    let messages = fetch_conversation_messages(pool, context_id).await?;
+    if messages.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(json!({
+        "context_id": context_id,
+        "message_count": messages.len(),
+        "messages": messages,
+    })))
+}
+
+async fn read_error_logs(pool: &DbPool) -> Result<Option<JsonValue>> {
+    let repo = LogsRepository::new(pool.clone())?;
+    let logs = repo
+        .fetch_recent_logs(&LogFilter::with_level("ERROR"), ERROR_LOGS_LIMIT, None)
+        .await?
+        .items;
+
+    Ok(Some(json!({
+        "level": "ERROR",
+        "count": logs.len(),
+        "logs": logs,
+    })))
+}
+
+async fn read_log(pool: &DbPool, id: &str) -> Result<Option<JsonValue>> {
+    let repo = LogsRepository::new(pool.clone())?;
+    let entry = repo.fetch_log_by_id(id).await?;
+    Ok(entry.map(|log| json!(log)))
+}
+
+pub async fn list_all_jobs(pool: &DbPool) -> Result<Vec<ScheduledJob>> {
+    let repo = SchedulerRepository::new(pool)?;
+    let mut jobs = Vec::new();
+    for name in registered_job_names() {
+        if let Some(job) = repo.find_job(&name).await? {
+            jobs.push(job);
+        }
+    }
+    Ok(jobs)
+}
+
+async fn read_job(pool: &DbPool, name: &str) -> Result<Option<JsonValue>> {
+    let repo = SchedulerRepository::new(pool)?;
+    let job = repo.find_job(name).await?;
+
+    Ok(job.map(|job| {
+        json!({
+            "job_name": job.job_name,
+            "schedule": job.schedule,
+            "enabled": job.enabled,
+            "last_run": job.last_run.map(|dt| dt.to_rfc3339()),
+            "last_status": job.last_status,
+            "run_count": job.run_count,
+            "last_error": job.last_error,
+        })
+    }))
+}
+
+async fn read_file(pool: &DbPool, uuid_str: &str) -> Result<Option<JsonValue>> {
+    let Ok(uuid) = Uuid::parse_str(uuid_str) else {
+        return Ok(None);
+    };
+    let file_repo = FileRepository::new(pool)?;
+    let file = file_repo.find_by_id(&uuid).await?;
+
+    Ok(file.map(|f| {
+        json!({
+            "id": f.id.to_string(),
+            "file_path": f.path,
+            "public_url": f.public_url,
+            "mime_type": f.mime_type,
+            "file_size_bytes": f.size_bytes,
+            "ai_content": f.ai_content,
+            "created_at": f.created_at.to_rfc3339()
+        })
+    }))
+}
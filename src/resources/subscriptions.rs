@@ -0,0 +1,106 @@
+use rmcp::{
+    model::ResourceUpdatedNotificationParam, service::RequestContext, ErrorData as McpError, Peer,
+    RoleServer,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+const SESSION_HEADER: &str = "mcp-session-id";
+
+#[derive(Clone)]
+struct Subscriber {
+    session_id: String,
+    peer: Peer<RoleServer>,
+}
+
+#[derive(Clone, Default)]
+pub struct ResourceSubscriptions {
+    subscribers: Arc<RwLock<HashMap<String, Vec<Subscriber>>>>,
+}
+
+impl ResourceSubscriptions {
+    pub async fn subscribe(
+        &self,
+        uri: &str,
+        ctx: &RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        let session_id = session_id(ctx).ok_or_else(|| {
+            McpError::invalid_request(
+                format!("Resource subscriptions require the {SESSION_HEADER} header"),
+                None,
+            )
+        })?;
+        let mut subscribers = self.subscribers.write().await;
+        let entry = subscribers.entry(uri.to_string()).or_default();
+
+        entry.retain(|s| s.session_id != session_id && !s.peer.is_transport_closed());
+        entry.push(Subscriber {
+            session_id,
+            peer: ctx.peer.clone(),
+        });
+        Ok(())
+    }
+
+    pub async fn unsubscribe(&self, uri: &str, ctx: &RequestContext<RoleServer>) {
+        let Some(session_id) = session_id(ctx) else {
+            return;
+        };
+        let mut subscribers = self.subscribers.write().await;
+
+        if let Some(entry) = subscribers.get_mut(uri) {
+            entry.retain(|s| s.session_id != session_id);
+            if entry.is_empty() {
+                subscribers.remove(uri);
+            }
+        }
+    }
+
+    pub async fn subscribed_uris(&self) -> Vec<String> {
+        self.subscribers.read().await.keys().cloned().collect()
+    }
+
+    pub async fn notify_updated(&self, uri: &str) {
+        let peers: Vec<Subscriber> = self
+            .subscribers
+            .read()
+            .await
+            .get(uri)
+            .cloned()
+            .unwrap_or_default();
+
+        let mut closed = Vec::new();
+        for subscriber in peers {
+            let result = subscriber
+                .peer
+                .notify_resource_updated(ResourceUpdatedNotificationParam {
+                    uri: uri.to_string(),
+                })
+                .await;
+
+            if let Err(e) = result {
+                tracing::debug!(uri = %uri, session_id = %subscriber.session_id, error = %e, "Dropping resource subscriber");
+                closed.push(subscriber.session_id);
+            }
+        }
+
+        if !closed.is_empty() {
+            let mut subscribers = self.subscribers.write().await;
+            if let Some(entry) = subscribers.get_mut(uri) {
+                entry.retain(|s| !closed.contains(&s.session_id));
+                if entry.is_empty() {
+                    subscribers.remove(uri);
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn session_id(ctx: &RequestContext<RoleServer>) -> Option<String> {
+    ctx.extensions
+        .get::<axum::http::request::Parts>()
+        .and_then(|parts| parts.headers.get(SESSION_HEADER))
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty())
+        .map(String::from)
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+
+pub const ADMIN_SCHEME: &str = "admin://";
+pub const ERROR_LOGS_URI: &str = "admin://logs/errors";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdminResourceUri {
+    User(String),
+    Conversation(String),
+    ErrorLogs,
+    Log(String),
+    Job(String),
+    File(String),
+}
+
+impl AdminResourceUri {
+    #[must_use]
+    pub fn parse(uri: &str) -> Option<Self> {
+        if uri == ERROR_LOGS_URI {
+            return Some(Self::ErrorLogs);
+        }
+
+        let path = uri.strip_prefix(ADMIN_SCHEME)?;
+        let (kind, id) = path.split_once('/')?;
+
+        if id.is_empty() || id.contains('/') {
+            return None;
+        }
+
+        let id = id.to_string();
+        match kind {
+            "users" => Some(Self::User(id)),
+            "conversations" => Some(Self::Conversation(id)),
+            "logs" => Some(Self::Log(id)),
+            "jobs" => Some(Self::Job(id)),
+            "files" => Some(Self::File(id)),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::User(_) => "users",
+            Self::Conversation(_) => "conversations",
+            Self::ErrorLogs | Self::Log(_) => "logs",
+            Self::Job(_) => "jobs",
+            Self::File(_) => "files",
+        }
+    }
+
+    #[must_use]
+    pub fn id(&self) -> &str {
+        match self {
+            Self::ErrorLogs => "errors",
+            Self::User(id)
+            | Self::Conversation(id)
+            | Self::Log(id)
+            | Self::Job(id)
+            | Self::File(id) => id,
+        }
+    }
+
+    #[must_use]
+    pub fn read_permission(&self) -> &'static str {
+        match self {
+            Self::User(_) => "admin.users.read",
+            Self::Conversation(_) => "admin.conversations.read",
+            Self::ErrorLogs | Self::Log(_) => "admin.logs.read",
+            Self::Job(_) => "admin.jobs.read",
+            Self::File(_) => "admin.files.read",
+        }
+    }
+
+    #[must_use]
+    pub fn is_subscribable(&self) -> bool {
+        matches!(self, Self::ErrorLogs | Self::Job(_))
+    }
+}
+
+impl fmt::Display for AdminResourceUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{ADMIN_SCHEME}{}/{}", self.kind(), self.id())
+    }
+}
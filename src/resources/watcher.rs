@@ -0,0 +1,78 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::time::Duration;
+use systemprompt::database::DbPool;
+
+use crate::tools::logs::repository::LogsRepository;
+
+use super::readers::list_all_jobs;
+use super::subscriptions::ResourceSubscriptions;
+use super::uri::{AdminResourceUri, ERROR_LOGS_URI};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+type JobState = (Option<String>, Option<DateTime<Utc>>);
+
+pub fn spawn_change_watcher(db_pool: DbPool, subscriptions: ResourceSubscriptions) {
+    tokio::spawn(async move {
+        let mut watcher = ChangeWatcher {
+            db_pool,
+            subscriptions,
+            latest_error: None,
+            job_states: HashMap::new(),
+            primed: false,
+        };
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+        loop {
+            interval.tick().await;
+            if let Err(e) = watcher.poll().await {
+                tracing::warn!(error = %e, "Resource change watcher poll failed");
+            }
+        }
+    });
+}
+
+struct ChangeWatcher {
+    db_pool: DbPool,
+    subscriptions: ResourceSubscriptions,
+    latest_error: Option<DateTime<Utc>>,
+    job_states: HashMap<String, JobState>,
+    primed: bool,
+}
+
+impl ChangeWatcher {
+    async fn poll(&mut self) -> Result<()> {
+        if self.subscriptions.subscribed_uris().await.is_empty() {
+            self.primed = false;
+            return Ok(());
+        }
+
+        let mut changed = Vec::new();
+
+        let logs_repo = LogsRepository::new(self.db_pool.clone())?;
+        let latest_error = logs_repo.fetch_latest_error_timestamp().await?;
+        if self.primed && latest_error != self.latest_error {
+            changed.push(ERROR_LOGS_URI.to_string());
+        }
+        self.latest_error = latest_error;
+
+        for job in list_all_jobs(&self.db_pool).await? {
+            let state = (job.last_status.clone(), job.last_run);
+            let previous = self.job_states.insert(job.job_name.clone(), state.clone());
+            if self.primed && previous.is_some_and(|p| p != state) {
+                changed.push(AdminResourceUri::Job(job.job_name).to_string());
+            }
+        }
+
+        self.primed = true;
+
+        for uri in changed {
+            tracing::debug!(uri = %uri, "Resource updated");
+            self.subscriptions.notify_updated(&uri).await;
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
-use std::collections::HashMap;
 use std::sync::Arc;
 
+use chrono::Duration;
 use systemprompt::agent::services::mcp::ToolResultHandler;
 use systemprompt::agent::services::ArtifactPublishingService;
 use systemprompt::database::DbPool;
@@ -9,7 +9,15 @@ use systemprompt::system::AppContext;
 
 use crate::prompts::AdminPrompts;
 use crate::resources::AdminResources;
-use crate::services::DiscoveredRole;
+use crate::services::{
+    token_delivery_from_env, DiscoveredRole, RoleDiscoveryService, TokenDelivery, TOKEN_OUTBOX_ENV,
+};
+use crate::tools::confirmation::models::{parse_confirmation_setting, CONFIRMATION_ENV};
+use crate::tools::undo::models::{parse_undo_ttl, UNDO_TTL_ENV};
+use crate::tools::users::suspensions::spawn_expiry_sweeper;
+use crate::tools::{Caller, ToolState};
+
+use super::role_catalog::{RoleCatalog, RoleSnapshot};
 
 #[derive(Clone)]
 pub struct AdminServer {
@@ -19,9 +27,11 @@ pub struct AdminServer {
     pub(super) resources: Arc<AdminResources>,
     pub(super) tool_result_handler: Arc<ToolResultHandler>,
     pub(super) publishing_service: Arc<ArtifactPublishingService>,
-    pub(super) tool_schemas: Arc<HashMap<String, serde_json::Value>>,
     pub(super) app_context: Arc<AppContext>,
-    pub(super) discovered_roles: Arc<Vec<DiscoveredRole>>,
+    pub(super) role_catalog: RoleCatalog,
+    pub(super) token_delivery: Option<Arc<dyn TokenDelivery>>,
+    pub(super) undo_ttl: Duration,
+    pub(super) confirm_destructive: bool,
 }
 
 impl AdminServer {
@@ -29,60 +39,64 @@ impl AdminServer {
         db_pool: DbPool,
         service_id: McpServerId,
         app_context: Arc<AppContext>,
-    ) -> Self {
+    ) -> anyhow::Result<Self> {
+        let undo_ttl = parse_undo_ttl(std::env::var(UNDO_TTL_ENV).ok().as_deref())
+            .map_err(anyhow::Error::msg)?;
         let prompts = Arc::new(AdminPrompts::new(db_pool.clone(), service_id.to_string()));
         let resources = Arc::new(AdminResources::new(db_pool.clone(), service_id.to_string()));
+        resources.start_change_watcher();
+        spawn_expiry_sweeper(db_pool.clone());
         let tool_result_handler = Arc::new(ToolResultHandler::new(db_pool.clone()));
         let publishing_service = Arc::new(ArtifactPublishingService::new(db_pool.clone()));
 
-        let discovered_roles = Self::discover_roles(&app_context).await;
-        let role_names: Vec<String> = discovered_roles.iter().map(|r| r.name.clone()).collect();
-        let tool_schemas = Self::build_tool_schema_cache(&role_names);
+        let role_discovery = Self::role_discovery_service(&app_context);
+        let role_catalog = RoleCatalog::new(Self::discover_roles(&role_discovery).await);
+        role_catalog.spawn_rescan(role_discovery);
 
-        Self {
+        Ok(Self {
             db_pool,
             service_id,
             prompts,
             resources,
             tool_result_handler,
             publishing_service,
-            tool_schemas: Arc::new(tool_schemas),
             app_context,
-            discovered_roles: Arc::new(discovered_roles),
-        }
+            role_catalog,
+            token_delivery: token_delivery_from_env(
+                std::env::var(TOKEN_OUTBOX_ENV).ok().as_deref(),
+            ),
+            undo_ttl,
+            confirm_destructive: parse_confirmation_setting(
+                std::env::var(CONFIRMATION_ENV).ok().as_deref(),
+            ),
+        })
+    }
+
+    #[must_use]
+    pub fn with_token_delivery(mut self, token_delivery: Arc<dyn TokenDelivery>) -> Self {
+        self.token_delivery = Some(token_delivery);
+        self
     }
 
-    async fn discover_roles(app_context: &AppContext) -> Vec<DiscoveredRole> {
-        use crate::services::RoleDiscoveryService;
+    pub(super) fn tool_state(&self, snapshot: &RoleSnapshot, caller: Caller) -> ToolState {
+        ToolState::new(snapshot.roles.clone(), self.token_delivery.clone(), caller)
+            .with_undo_ttl(self.undo_ttl)
+            .with_confirmation(self.confirm_destructive)
+    }
 
+    fn role_discovery_service(app_context: &AppContext) -> RoleDiscoveryService {
         let extensions_path = std::path::Path::new(&app_context.config().system_path)
             .parent()
             .map(|p| p.join("extensions"))
             .unwrap_or_else(|| std::path::PathBuf::from("extensions"));
 
-        let role_service = RoleDiscoveryService::new(extensions_path);
+        RoleDiscoveryService::new(extensions_path)
+    }
+
+    async fn discover_roles(role_service: &RoleDiscoveryService) -> Vec<DiscoveredRole> {
         role_service
             .discover_all_roles()
             .await
             .unwrap_or_else(|_| crate::services::role_discovery::default_core_roles())
     }
-
-    fn build_tool_schema_cache(role_names: &[String]) -> HashMap<String, serde_json::Value> {
-        let mut schemas = HashMap::new();
-        let tools = crate::tools::register_tools_with_roles(role_names);
-
-        for tool in tools {
-            if let Some(output_schema) = tool.output_schema {
-                let schema_value =
-                    serde_json::to_value(&*output_schema).unwrap_or_else(|_| serde_json::json!({}));
-                schemas.insert(tool.name.to_string(), schema_value);
-            }
-        }
-
-        schemas
-    }
-
-    pub(super) fn get_output_schema_for_tool(&self, tool_name: &str) -> Option<serde_json::Value> {
-        self.tool_schemas.get(tool_name).cloned()
-    }
 }
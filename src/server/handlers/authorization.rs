@@ -0,0 +1,42 @@
+use rmcp::{service::RequestContext, ErrorData as McpError, RoleServer};
+use systemprompt::mcp::middleware::enforce_rbac_from_registry;
+
+use crate::server::role_catalog::RoleSnapshot;
+use crate::server::AdminServer;
+use crate::tools::permissions::Caller;
+use crate::tools::users::repository::UsersRepository;
+
+impl AdminServer {
+    pub(in crate::server) async fn authenticate(
+        &self,
+        ctx: &RequestContext<RoleServer>,
+    ) -> Result<Caller, McpError> {
+        let auth_result = enforce_rbac_from_registry(ctx, self.service_id.as_str()).await?;
+        let authenticated_ctx = auth_result.expect_authenticated(
+            "BUG: systemprompt-admin requires OAuth but auth was not enforced",
+        )?;
+
+        let snapshot = self.role_catalog.snapshot().await;
+        self.load_caller(authenticated_ctx.context.user_id().as_str(), &snapshot)
+            .await
+    }
+
+    pub(in crate::server) async fn load_caller(
+        &self,
+        user_id: &str,
+        snapshot: &RoleSnapshot,
+    ) -> Result<Caller, McpError> {
+        let user = UsersRepository::new(self.db_pool.clone())
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?
+            .find_user(user_id)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+        let roles = user
+            .filter(|user| user.status == "active")
+            .map(|user| user.roles)
+            .unwrap_or_default();
+
+        Ok(Caller::new(user_id, roles, &snapshot.roles))
+    }
+}
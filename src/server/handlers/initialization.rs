@@ -17,7 +17,9 @@ impl AdminServer {
             capabilities: ServerCapabilities::builder()
                 .enable_prompts()
                 .enable_tools()
+                .enable_tool_list_changed()
                 .enable_resources()
+                .enable_resources_subscribe()
                 .build(),
             server_info: Implementation {
                 name: format!("SystemPrompt Admin MCP Server ({})", self.service_id),
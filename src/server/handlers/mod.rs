@@ -1,2 +1,3 @@
+mod authorization;
 mod initialization;
 mod tools;
@@ -17,10 +17,12 @@ impl AdminServer {
     pub(in crate::server) async fn list_tools(
         &self,
         _request: Option<PaginatedRequestParam>,
-        _ctx: RequestContext<RoleServer>,
+        ctx: RequestContext<RoleServer>,
     ) -> Result<ListToolsResult, McpError> {
-        let role_names: Vec<String> = self.discovered_roles.iter().map(|r| r.name.clone()).collect();
-        crate::tools::list_tools_with_roles(&role_names)
+        let caller = self.authenticate(&ctx).await?;
+        self.role_catalog.listen(&ctx).await;
+        let snapshot = self.role_catalog.snapshot().await;
+        crate::tools::list_tools_for_caller(&snapshot.role_names(), &caller)
     }
 
     pub(in crate::server) async fn call_tool(
@@ -39,7 +41,22 @@ impl AdminServer {
         let mut request_context = authenticated_ctx.context.clone();
         let jwt_token = authenticated_ctx.token();
 
-        let output_schema = self.get_output_schema_for_tool(&tool_name);
+        let role_snapshot = self.role_catalog.snapshot().await;
+        let output_schema = role_snapshot.tool_schemas.get(&tool_name).cloned();
+        let arguments = request.arguments.clone().unwrap_or_default();
+
+        let caller = self
+            .load_caller(request_context.user_id().as_str(), &role_snapshot)
+            .await?;
+        if let Err(message) = caller.authorize(&tool_name, &arguments) {
+            tracing::warn!(
+                tool_name = %tool_name,
+                user_id = %caller.user_id,
+                roles = ?caller.roles,
+                "Tool call denied"
+            );
+            return Err(McpError::invalid_request(message, None));
+        }
 
         let task_result = task_helper::ensure_task_exists(
             &self.db_pool,
@@ -54,7 +71,6 @@ impl AdminServer {
         let tool_repo = ToolUsageRepository::new(&self.db_pool).map_err(|e| {
             McpError::internal_error(format!("Failed to create tool repo: {e}"), None)
         })?;
-        let arguments = request.arguments.clone().unwrap_or_default();
 
         let exec_request = ToolExecutionRequest {
             tool_name: tool_name.clone(),
@@ -80,6 +96,7 @@ impl AdminServer {
             ctx,
             &self.db_pool,
             &self.app_context,
+            &self.tool_state(&role_snapshot, caller),
             &execution_id,
         )
         .await;
@@ -1,5 +1,6 @@
 pub mod constructor;
 mod handlers;
+pub mod role_catalog;
 
 pub use constructor::AdminServer;
 
@@ -9,7 +10,7 @@ use rmcp::{
         CallToolRequestParam, CallToolResult, GetPromptRequestParam, GetPromptResult,
         InitializeRequestParam, InitializeResult, ListPromptsResult, ListResourceTemplatesResult,
         ListResourcesResult, ListToolsResult, PaginatedRequestParam, ReadResourceRequestParam,
-        ReadResourceResult, ServerInfo,
+        ReadResourceResult, ServerInfo, SubscribeRequestParam, UnsubscribeRequestParam,
     },
     service::RequestContext,
     ErrorData as McpError, RoleServer, ServerHandler,
@@ -49,7 +50,8 @@ impl ServerHandler for AdminServer {
         request: Option<PaginatedRequestParam>,
         ctx: RequestContext<RoleServer>,
     ) -> Result<ListPromptsResult, McpError> {
-        self.prompts.list_prompts(request, ctx).await
+        let caller = self.authenticate(&ctx).await?;
+        self.prompts.list_prompts(request, ctx, &caller).await
     }
 
     async fn get_prompt(
@@ -57,7 +59,10 @@ impl ServerHandler for AdminServer {
         request: GetPromptRequestParam,
         ctx: RequestContext<RoleServer>,
     ) -> Result<GetPromptResult, McpError> {
-        self.prompts.get_prompt(request, ctx).await
+        let caller = self.authenticate(&ctx).await?;
+        let snapshot = self.role_catalog.snapshot().await;
+        let tools = crate::tools::list_tools_for_caller(&snapshot.role_names(), &caller)?.tools;
+        self.prompts.get_prompt(request, ctx, &caller, &tools).await
     }
 
     async fn list_resources(
@@ -65,7 +70,8 @@ impl ServerHandler for AdminServer {
         request: Option<PaginatedRequestParam>,
         ctx: RequestContext<RoleServer>,
     ) -> Result<ListResourcesResult, McpError> {
-        self.resources.list_resources(request, ctx).await
+        let caller = self.authenticate(&ctx).await?;
+        self.resources.list_resources(request, ctx, &caller).await
     }
 
     async fn read_resource(
@@ -73,18 +79,35 @@ impl ServerHandler for AdminServer {
         request: ReadResourceRequestParam,
         ctx: RequestContext<RoleServer>,
     ) -> Result<ReadResourceResult, McpError> {
-        self.resources.read_resource(request, ctx).await
+        let caller = self.authenticate(&ctx).await?;
+        self.resources.read_resource(request, ctx, &caller).await
     }
 
     async fn list_resource_templates(
         &self,
-        _request: Option<PaginatedRequestParam>,
-        _ctx: RequestContext<RoleServer>,
+        request: Option<PaginatedRequestParam>,
+        ctx: RequestContext<RoleServer>,
     ) -> Result<ListResourceTemplatesResult, McpError> {
-        Ok(ListResourceTemplatesResult {
-            next_cursor: None,
-            resource_templates: Vec::new(),
-            meta: None,
-        })
+        let caller = self.authenticate(&ctx).await?;
+        self.resources
+            .list_resource_templates(request, ctx, &caller)
+            .await
+    }
+
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParam,
+        ctx: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        let caller = self.authenticate(&ctx).await?;
+        self.resources.subscribe(request, ctx, &caller).await
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParam,
+        ctx: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        self.resources.unsubscribe(request, ctx).await
     }
 }
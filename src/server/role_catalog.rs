@@ -0,0 +1,142 @@
+use rmcp::{service::RequestContext, Peer, RoleServer};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use crate::resources::session_id;
+use crate::services::{DiscoveredRole, RoleDiscoveryService};
+
+const RESCAN_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub struct RoleSnapshot {
+    pub roles: Arc<Vec<DiscoveredRole>>,
+    pub tool_schemas: Arc<HashMap<String, JsonValue>>,
+}
+
+impl RoleSnapshot {
+    #[must_use]
+    pub fn build(roles: Vec<DiscoveredRole>) -> Self {
+        let role_names: Vec<String> = roles.iter().map(|r| r.name.clone()).collect();
+        Self {
+            roles: Arc::new(roles),
+            tool_schemas: Arc::new(build_tool_schema_cache(&role_names)),
+        }
+    }
+
+    #[must_use]
+    pub fn role_names(&self) -> Vec<String> {
+        self.roles.iter().map(|r| r.name.clone()).collect()
+    }
+}
+
+fn build_tool_schema_cache(role_names: &[String]) -> HashMap<String, JsonValue> {
+    let mut schemas = HashMap::new();
+    let tools = crate::tools::register_tools_with_roles(role_names);
+
+    for tool in tools {
+        if let Some(output_schema) = tool.output_schema {
+            let schema_value =
+                serde_json::to_value(&*output_schema).unwrap_or_else(|_| serde_json::json!({}));
+            schemas.insert(tool.name.to_string(), schema_value);
+        }
+    }
+
+    schemas
+}
+
+#[derive(Clone)]
+pub struct RoleCatalog {
+    current: Arc<RwLock<Arc<RoleSnapshot>>>,
+    listeners: Arc<RwLock<HashMap<String, Peer<RoleServer>>>>,
+}
+
+impl RoleCatalog {
+    #[must_use]
+    pub fn new(roles: Vec<DiscoveredRole>) -> Self {
+        Self {
+            current: Arc::new(RwLock::new(Arc::new(RoleSnapshot::build(roles)))),
+            listeners: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub async fn snapshot(&self) -> Arc<RoleSnapshot> {
+        self.current.read().await.clone()
+    }
+
+    pub async fn replace(&self, roles: Vec<DiscoveredRole>) -> bool {
+        if *self.snapshot().await.roles == roles {
+            return false;
+        }
+        let snapshot = Arc::new(RoleSnapshot::build(roles));
+        *self.current.write().await = snapshot;
+        true
+    }
+
+    pub async fn listen(&self, ctx: &RequestContext<RoleServer>) {
+        let Some(session_id) = session_id(ctx) else {
+            return;
+        };
+        if self.listeners.read().await.contains_key(&session_id) {
+            return;
+        }
+
+        let mut listeners = self.listeners.write().await;
+        listeners.retain(|_, peer| !peer.is_transport_closed());
+        listeners.insert(session_id, ctx.peer.clone());
+    }
+
+    async fn notify_tool_list_changed(&self) {
+        let listeners: Vec<(String, Peer<RoleServer>)> = self
+            .listeners
+            .read()
+            .await
+            .iter()
+            .map(|(session_id, peer)| (session_id.clone(), peer.clone()))
+            .collect();
+
+        let mut closed = Vec::new();
+        for (session_id, peer) in listeners {
+            if let Err(e) = peer.notify_tool_list_changed().await {
+                tracing::debug!(session_id = %session_id, error = %e, "Dropping tool list listener");
+                closed.push(session_id);
+            }
+        }
+
+        if !closed.is_empty() {
+            let mut listeners = self.listeners.write().await;
+            for session_id in closed {
+                listeners.remove(&session_id);
+            }
+        }
+    }
+
+    pub fn spawn_rescan(&self, discovery: RoleDiscoveryService) {
+        let catalog = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(RESCAN_INTERVAL);
+            interval.tick().await;
+
+            loop {
+                interval.tick().await;
+                match discovery.discover_all_roles().await {
+                    Ok(roles) => {
+                        let count = roles.len();
+                        if catalog.replace(roles).await {
+                            tracing::info!(
+                                roles = count,
+                                "Discovered roles changed, tool list reloaded"
+                            );
+                            catalog.notify_tool_list_changed().await;
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Role rescan failed, keeping previous roles");
+                    }
+                }
+            }
+        });
+    }
+}
@@ -1,3 +1,7 @@
 pub mod role_discovery;
+pub mod token_outbox;
+pub mod user_tokens;
 
 pub use role_discovery::{DiscoveredRole, RoleDiscoveryService, RoleSource};
+pub use token_outbox::{token_delivery_from_env, OutboxDelivery, TOKEN_OUTBOX_ENV};
+pub use user_tokens::{TokenDelivery, TokenPurpose};
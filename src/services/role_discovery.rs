@@ -3,7 +3,9 @@ use serde::Serialize;
 use std::path::Path;
 use systemprompt::models::extension::{ExtensionManifest, ManifestRole};
 
-#[derive(Debug, Clone, Serialize)]
+pub const ADMIN_PERMISSIONS: &str = "admin.*";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct DiscoveredRole {
     pub name: String,
     pub display_name: String,
@@ -12,12 +14,22 @@ pub struct DiscoveredRole {
     pub permissions: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum RoleSource {
     Core,
     Extension { extension_name: String },
 }
 
+impl RoleSource {
+    #[must_use]
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Core => "core",
+            Self::Extension { extension_name } => extension_name,
+        }
+    }
+}
+
 impl DiscoveredRole {
     pub fn core(name: &str, display_name: &str, description: &str) -> Self {
         Self {
@@ -29,6 +41,12 @@ impl DiscoveredRole {
         }
     }
 
+    #[must_use]
+    pub fn with_permissions(mut self, permissions: &[&str]) -> Self {
+        self.permissions = permissions.iter().map(|p| (*p).to_string()).collect();
+        self
+    }
+
     pub fn from_manifest(role_name: &str, role: &ManifestRole, extension_name: &str) -> Self {
         Self {
             name: role_name.to_string(),
@@ -66,7 +84,8 @@ impl RoleDiscoveryService {
         vec![
             DiscoveredRole::core("anonymous", "Anonymous", "Unauthenticated user"),
             DiscoveredRole::core("user", "User", "Authenticated user"),
-            DiscoveredRole::core("admin", "Admin", "Full system administrator"),
+            DiscoveredRole::core("admin", "Admin", "Full system administrator")
+                .with_permissions(&[ADMIN_PERMISSIONS]),
         ]
     }
 
@@ -97,6 +116,7 @@ impl RoleDiscoveryService {
             }
         }
 
+        roles.sort_by(|a, b| (a.source.label(), &a.name).cmp(&(b.source.label(), &b.name)));
         Ok(roles)
     }
 
@@ -111,6 +131,7 @@ pub fn default_core_roles() -> Vec<DiscoveredRole> {
     vec![
         DiscoveredRole::core("anonymous", "Anonymous", "Unauthenticated user"),
         DiscoveredRole::core("user", "User", "Authenticated user"),
-        DiscoveredRole::core("admin", "Admin", "Full system administrator"),
+        DiscoveredRole::core("admin", "Admin", "Full system administrator")
+            .with_permissions(&[ADMIN_PERMISSIONS]),
     ]
 }
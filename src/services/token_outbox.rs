@@ -0,0 +1,64 @@
+use anyhow::{Context, Result};
+use serde_json::json;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use super::user_tokens::{TokenDelivery, TokenMessage};
+
+pub const TOKEN_OUTBOX_ENV: &str = "ADMIN_TOKEN_OUTBOX";
+
+#[must_use]
+pub fn token_delivery_from_env(value: Option<&str>) -> Option<Arc<dyn TokenDelivery>> {
+    let path = value.map(str::trim).filter(|path| !path.is_empty())?;
+    Some(Arc::new(OutboxDelivery::new(path)))
+}
+
+#[derive(Debug, Clone)]
+pub struct OutboxDelivery {
+    path: PathBuf,
+}
+
+impl OutboxDelivery {
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl TokenDelivery for OutboxDelivery {
+    fn channel(&self) -> &'static str {
+        "outbox"
+    }
+
+    fn deliver(&self, message: &TokenMessage) -> Result<()> {
+        let line = format!(
+            "{}\n",
+            json!({
+                "user_id": message.user_id,
+                "email": message.email,
+                "purpose": message.purpose.as_str(),
+                "token": message.token,
+                "expires_at": message.expires_at.to_rfc3339()
+            })
+        );
+        let mut outbox = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .mode(0o600)
+            .open(&self.path)
+            .with_context(|| format!("Cannot open token outbox {}", self.path.display()))?;
+        outbox
+            .write_all(line.as_bytes())
+            .with_context(|| format!("Cannot write token outbox {}", self.path.display()))?;
+        outbox.sync_data()?;
+        Ok(())
+    }
+}
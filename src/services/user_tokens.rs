@@ -0,0 +1,87 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenPurpose {
+    Invite,
+    PasswordReset,
+}
+
+impl TokenPurpose {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Invite => "invite",
+            Self::PasswordReset => "password_reset",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "invite" => Some(Self::Invite),
+            "password_reset" => Some(Self::PasswordReset),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn lifetime(self) -> Duration {
+        match self {
+            Self::Invite => Duration::days(7),
+            Self::PasswordReset => Duration::hours(1),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IssuedToken {
+    pub purpose: TokenPurpose,
+    pub token: String,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[must_use]
+pub fn hash_token(token: &str) -> String {
+    Sha256::digest(token.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[must_use]
+pub fn generate_token() -> String {
+    format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+#[must_use]
+pub fn issue_token(purpose: TokenPurpose, now: DateTime<Utc>) -> IssuedToken {
+    let token = generate_token();
+    IssuedToken {
+        purpose,
+        token_hash: hash_token(&token),
+        token,
+        expires_at: now + purpose.lifetime(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenMessage {
+    pub user_id: String,
+    pub email: String,
+    pub purpose: TokenPurpose,
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub trait TokenDelivery: Send + Sync {
+    fn channel(&self) -> &'static str;
+
+    fn deliver(&self, message: &TokenMessage) -> Result<()>;
+}
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+use crate::tools::logs::filter::parse_time_bound;
+
+use super::models::AuditFilter;
+
+fn string_arg(args: &JsonMap<String, JsonValue>, key: &str) -> Option<String> {
+    args.get(key)
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(String::from)
+}
+
+pub fn parse_audit_filter(
+    args: &JsonMap<String, JsonValue>,
+    now: DateTime<Utc>,
+) -> Result<AuditFilter, String> {
+    let since = string_arg(args, "since")
+        .map(|v| parse_time_bound(&v, now))
+        .transpose()?;
+    let until = string_arg(args, "until")
+        .map(|v| parse_time_bound(&v, now))
+        .transpose()?;
+
+    if let (Some(since), Some(until)) = (since, until) {
+        if since >= until {
+            return Err("'since' must be earlier than 'until'".to_string());
+        }
+    }
+
+    Ok(AuditFilter {
+        actor_id: string_arg(args, "actor_id"),
+        target_id: string_arg(args, "target_id"),
+        tool: string_arg(args, "tool"),
+        action: string_arg(args, "action"),
+        since,
+        until,
+    })
+}
@@ -0,0 +1,190 @@
+pub mod filter;
+pub mod models;
+pub mod repository;
+
+use chrono::Utc;
+use rmcp::{
+    model::{CallToolRequestParam, CallToolResult, Content},
+    service::RequestContext,
+    ErrorData as McpError, RoleServer,
+};
+use serde_json::{json, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::{ArtifactId, McpExecutionId};
+use systemprompt::models::artifacts::{
+    Column, ColumnType, ExecutionMetadata, TableArtifact, TableHints, ToolResponse,
+};
+
+use filter::parse_audit_filter;
+use repository::AuditRepository;
+
+use crate::tools::pagination::{cursor_schema, parse_cursor_arg, with_next_cursor, Cursor};
+
+const DEFAULT_LIMIT: i64 = 100;
+const MAX_LIMIT: i64 = 1000;
+
+#[must_use]
+pub fn audit_input_schema() -> JsonValue {
+    json!({
+        "type": "object",
+        "properties": {
+            "actor_id": {
+                "type": "string",
+                "description": "Only changes made by this user ID"
+            },
+            "target_id": {
+                "type": "string",
+                "description": "Only changes to this user, file or content ID"
+            },
+            "tool": {
+                "type": "string",
+                "enum": ["user", "operations"],
+                "description": "Only changes made through this tool"
+            },
+            "action": {
+                "type": "string",
+                "description": "Only this action, such as assign_role, remove_role, delete, bulk.delete, delete_file or delete_content"
+            },
+            "since": {
+                "type": "string",
+                "description": "Only changes at or after this time: RFC 3339 timestamp or relative duration such as 24h, 7d"
+            },
+            "until": {
+                "type": "string",
+                "description": "Only changes before this time: RFC 3339 timestamp or relative duration such as 24h, 7d"
+            },
+            "limit": {
+                "type": "integer",
+                "default": DEFAULT_LIMIT,
+                "minimum": 1,
+                "maximum": MAX_LIMIT,
+                "description": "Number of audit records per page"
+            },
+            "cursor": cursor_schema()
+        }
+    })
+}
+
+#[must_use]
+pub fn audit_output_schema() -> JsonValue {
+    json!({
+        "type": "object",
+        "description": "Audit records for administrative changes, newest first",
+        "properties": {
+            "items": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "id": {"type": "string"},
+                        "created_at": {"type": "string"},
+                        "actor_id": {"type": "string"},
+                        "tool": {"type": "string"},
+                        "action": {"type": "string"},
+                        "target_id": {"type": "string"},
+                        "before": {"type": ["object", "null"]},
+                        "after": {"type": ["object", "null"]},
+                        "arguments": {"type": "object"},
+                        "result": {"type": "object"}
+                    }
+                }
+            },
+            "count": {"type": "integer"},
+            "next_cursor": {"type": ["string", "null"]}
+        },
+        "x-artifact-type": "table",
+        "x-table-hints": {
+            "columns": ["created_at", "actor_id", "tool", "action", "target_id", "before", "after"],
+            "sortable_columns": ["created_at", "actor_id", "action"],
+            "default_sort": {"column": "created_at", "order": "desc"},
+            "filterable": true,
+            "page_size": 25
+        }
+    })
+}
+
+pub async fn handle_audit(
+    pool: &DbPool,
+    request: CallToolRequestParam,
+    _ctx: RequestContext<RoleServer>,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let args = request.arguments.unwrap_or_default();
+
+    let cursor = parse_cursor_arg(&args)?;
+    let limit = args
+        .get("limit")
+        .and_then(JsonValue::as_i64)
+        .unwrap_or(DEFAULT_LIMIT)
+        .clamp(1, MAX_LIMIT);
+    let filter =
+        parse_audit_filter(&args, Utc::now()).map_err(|e| McpError::invalid_params(e, None))?;
+
+    tracing::debug!(filter = ?filter, limit = limit, cursor = ?cursor, "Querying audit log");
+
+    let records = AuditRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .list(&filter, limit, cursor.as_ref())
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let rows: Vec<JsonValue> = records
+        .items
+        .iter()
+        .map(|record| {
+            json!({
+                "id": record.id,
+                "created_at": record.created_at.to_rfc3339(),
+                "actor_id": record.actor_id,
+                "tool": record.tool,
+                "action": record.action,
+                "target_id": record.target_id,
+                "before": record.before,
+                "after": record.after,
+                "arguments": record.arguments,
+                "result": record.result
+            })
+        })
+        .collect();
+
+    let columns = vec![
+        Column::new("created_at", ColumnType::Date).with_label("Time"),
+        Column::new("actor_id", ColumnType::String).with_label("Actor"),
+        Column::new("tool", ColumnType::String).with_label("Tool"),
+        Column::new("action", ColumnType::String).with_label("Action"),
+        Column::new("target_id", ColumnType::String).with_label("Target"),
+        Column::new("before", ColumnType::String).with_label("Before"),
+        Column::new("after", ColumnType::String).with_label("After"),
+    ];
+
+    let metadata = ExecutionMetadata::new().tool("audit");
+    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
+    let artifact = TableArtifact::new(columns)
+        .with_rows(rows)
+        .with_hints(TableHints::new().filterable())
+        .with_metadata(metadata.clone());
+    let tool_response = ToolResponse::new(
+        artifact_id,
+        mcp_execution_id.clone(),
+        artifact,
+        metadata.clone(),
+    );
+
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "Found {} audit records{}",
+            records.items.len(),
+            if records.next_cursor.is_some() {
+                " (more available via next_cursor)"
+            } else {
+                ""
+            }
+        ))],
+        structured_content: Some(with_next_cursor(
+            tool_response.to_json(),
+            records.next_cursor.as_ref().map(Cursor::encode),
+        )),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditScope {
+    pub actor_id: String,
+    pub tool: String,
+    pub action: String,
+    pub arguments: JsonValue,
+}
+
+impl AuditScope {
+    #[must_use]
+    pub fn new(
+        actor_id: impl Into<String>,
+        tool: impl Into<String>,
+        action: impl Into<String>,
+        arguments: &JsonMap<String, JsonValue>,
+    ) -> Self {
+        Self {
+            actor_id: actor_id.into(),
+            tool: tool.into(),
+            action: action.into(),
+            arguments: JsonValue::Object(arguments.clone()),
+        }
+    }
+
+    #[must_use]
+    pub fn entry(&self, target_id: impl Into<String>) -> AuditEntry {
+        AuditEntry {
+            actor_id: self.actor_id.clone(),
+            tool: self.tool.clone(),
+            action: self.action.clone(),
+            target_id: target_id.into(),
+            before: None,
+            after: None,
+            arguments: self.arguments.clone(),
+            result: json!({}),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub actor_id: String,
+    pub tool: String,
+    pub action: String,
+    pub target_id: String,
+    pub before: Option<JsonValue>,
+    pub after: Option<JsonValue>,
+    pub arguments: JsonValue,
+    pub result: JsonValue,
+}
+
+impl AuditEntry {
+    #[must_use]
+    pub fn with_change(mut self, before: JsonValue, after: JsonValue) -> Self {
+        self.before = Some(before);
+        self.after = Some(after);
+        self
+    }
+
+    #[must_use]
+    pub fn with_before(mut self, before: JsonValue) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    #[must_use]
+    pub fn with_after(mut self, after: JsonValue) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    #[must_use]
+    pub fn with_result(mut self, result: JsonValue) -> Self {
+        self.result = result;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditRecord {
+    pub id: String,
+    pub actor_id: String,
+    pub tool: String,
+    pub action: String,
+    pub target_id: String,
+    pub before: Option<JsonValue>,
+    pub after: Option<JsonValue>,
+    pub arguments: JsonValue,
+    pub result: JsonValue,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditFilter {
+    pub actor_id: Option<String>,
+    pub target_id: Option<String>,
+    pub tool: Option<String>,
+    pub action: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,103 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use std::sync::Arc;
+use systemprompt::database::DbPool;
+
+use crate::tools::pagination::{Cursor, Page};
+
+use super::models::{AuditEntry, AuditFilter, AuditRecord};
+
+pub async fn record_audit(conn: &mut PgConnection, entry: &AuditEntry) -> Result<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO admin_audit_log
+            (actor_id, tool, action, target_id, before, after, arguments, result)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        "#,
+        entry.actor_id,
+        entry.tool,
+        entry.action,
+        entry.target_id,
+        entry.before,
+        entry.after,
+        entry.arguments,
+        entry.result
+    )
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+pub struct AuditRepository {
+    pool: Arc<PgPool>,
+}
+
+impl AuditRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn list(
+        &self,
+        filter: &AuditFilter,
+        limit: i64,
+        cursor: Option<&Cursor>,
+    ) -> Result<Page<AuditRecord>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                id::text as "id!",
+                actor_id,
+                tool,
+                action,
+                target_id,
+                before,
+                after,
+                arguments,
+                result,
+                created_at as "created_at: DateTime<Utc>"
+            FROM admin_audit_log
+            WHERE ($1::text IS NULL OR actor_id = $1)
+              AND ($2::text IS NULL OR target_id = $2)
+              AND ($3::text IS NULL OR tool = $3)
+              AND ($4::text IS NULL OR action = $4)
+              AND ($5::timestamptz IS NULL OR created_at >= $5)
+              AND ($6::timestamptz IS NULL OR created_at < $6)
+              AND ($7::timestamptz IS NULL OR (created_at, id::text) < ($7, $8::text))
+            ORDER BY created_at DESC, id::text DESC
+            LIMIT $9
+            "#,
+            filter.actor_id.as_deref(),
+            filter.target_id.as_deref(),
+            filter.tool.as_deref(),
+            filter.action.as_deref(),
+            filter.since,
+            filter.until,
+            cursor.map(|c| c.timestamp),
+            cursor.map(|c| c.id.as_str()),
+            limit + 1
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(Page::from_rows(
+            rows,
+            limit as usize,
+            |row| Cursor::new(row.created_at, row.id.clone()),
+            |row| AuditRecord {
+                id: row.id,
+                actor_id: row.actor_id,
+                tool: row.tool,
+                action: row.action,
+                target_id: row.target_id,
+                before: row.before,
+                after: row.after,
+                arguments: row.arguments,
+                result: row.result,
+                created_at: row.created_at,
+            },
+        ))
+    }
+}
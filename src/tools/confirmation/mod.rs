@@ -0,0 +1,156 @@
+pub mod models;
+pub mod repository;
+mod sections;
+
+use chrono::Utc;
+use rmcp::{
+    model::{
+        CallToolResult, Content, CreateElicitationRequestParam, ElicitationAction,
+        ElicitationSchema,
+    },
+    ErrorData as McpError, Peer, RoleServer,
+};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::{ArtifactId, McpExecutionId};
+use systemprompt::models::artifacts::{ExecutionMetadata, ToolResponse};
+
+use crate::tools::ToolState;
+use models::{DeletionPreview, IssuedConfirmation};
+use repository::ConfirmationRepository;
+use sections::create_preview_dashboard;
+
+pub enum Confirmation {
+    Confirmed,
+    Respond(CallToolResult),
+}
+
+pub async fn confirm_deletion(
+    pool: &DbPool,
+    peer: &Peer<RoleServer>,
+    state: &ToolState,
+    args: &JsonMap<String, JsonValue>,
+    preview: &DeletionPreview,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<Confirmation, McpError> {
+    let repo = ConfirmationRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    if let Some(token) = args.get("confirmation_token").and_then(|v| v.as_str()) {
+        let consumed = repo
+            .consume(token.trim(), &state.caller.user_id, preview)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        if !consumed {
+            return Err(McpError::invalid_params(
+                format!(
+                    "confirmation_token is invalid, expired, already used or was issued for a different target than {}",
+                    preview.target_id
+                ),
+                None,
+            ));
+        }
+        return Ok(Confirmation::Confirmed);
+    }
+
+    if !state.confirm_destructive {
+        return Ok(Confirmation::Confirmed);
+    }
+
+    if peer.supports_elicitation() {
+        match elicit_confirmation(peer, preview).await {
+            Ok(true) => return Ok(Confirmation::Confirmed),
+            Ok(false) => return Ok(Confirmation::Respond(declined_result(preview))),
+            Err(e) => {
+                tracing::warn!(error = %e, target_id = %preview.target_id, "Elicitation failed, falling back to a confirmation token");
+            }
+        }
+    }
+
+    let issued = IssuedConfirmation::issue(Utc::now());
+    repo.store(&issued, &state.caller.user_id, preview)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    preview_result(preview, &issued, mcp_execution_id).map(Confirmation::Respond)
+}
+
+async fn elicit_confirmation(
+    peer: &Peer<RoleServer>,
+    preview: &DeletionPreview,
+) -> Result<bool, String> {
+    let requested_schema = ElicitationSchema::builder()
+        .required_bool_property("confirm", |schema| {
+            schema
+                .title("Delete")
+                .description("Set to true to perform the deletion")
+                .with_default(false)
+        })
+        .build()
+        .map_err(str::to_string)?;
+    let response = peer
+        .create_elicitation(CreateElicitationRequestParam {
+            message: preview.message(),
+            requested_schema,
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(response.action == ElicitationAction::Accept
+        && response
+            .content
+            .as_ref()
+            .and_then(|content| content.get("confirm"))
+            .and_then(JsonValue::as_bool)
+            == Some(true))
+}
+
+fn declined_result(preview: &DeletionPreview) -> CallToolResult {
+    let metadata = ExecutionMetadata::new().tool(preview.tool.as_str());
+    CallToolResult {
+        content: vec![Content::text(format!(
+            "Deletion of {} was not confirmed; nothing was changed.",
+            preview.summary
+        ))],
+        structured_content: Some(json!({
+            "success": false,
+            "confirmed": false,
+            "preview": preview.to_json()
+        })),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    }
+}
+
+fn preview_result(
+    preview: &DeletionPreview,
+    issued: &IssuedConfirmation,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let dashboard = create_preview_dashboard(preview)
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let metadata = ExecutionMetadata::new().tool(preview.tool.as_str());
+    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
+    let mut structured = ToolResponse::new(
+        artifact_id,
+        mcp_execution_id.clone(),
+        dashboard,
+        metadata.clone(),
+    )
+    .to_json();
+    if let Some(object) = structured.as_object_mut() {
+        object.insert("preview".to_string(), preview.to_json());
+        object.insert("confirmation".to_string(), issued.to_json());
+    }
+
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "Nothing was deleted yet. {}\n\nTo proceed, repeat the call with confirmation_token {} before {}.",
+            preview.message(),
+            issued.token,
+            issued.expires_at.to_rfc3339()
+        ))],
+        structured_content: Some(structured),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
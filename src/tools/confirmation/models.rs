@@ -0,0 +1,110 @@
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{json, Value as JsonValue};
+
+use crate::services::user_tokens::{generate_token, hash_token};
+
+pub const CONFIRMATION_ENV: &str = "ADMIN_CONFIRM_DESTRUCTIVE";
+pub const CONFIRMATION_TTL_MINUTES: i64 = 5;
+
+#[must_use]
+pub fn parse_confirmation_setting(value: Option<&str>) -> bool {
+    value.is_some_and(|value| {
+        matches!(
+            value.trim().to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes" | "on"
+        )
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeletionPreview {
+    pub tool: String,
+    pub action: String,
+    pub target_id: String,
+    pub summary: String,
+    pub fields: Vec<(String, String)>,
+}
+
+impl DeletionPreview {
+    #[must_use]
+    pub fn new(
+        tool: impl Into<String>,
+        action: impl Into<String>,
+        target_id: impl Into<String>,
+        summary: impl Into<String>,
+    ) -> Self {
+        Self {
+            tool: tool.into(),
+            action: action.into(),
+            target_id: target_id.into(),
+            summary: summary.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_field(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.fields.push((name.into(), value.into()));
+        self
+    }
+
+    #[must_use]
+    pub fn message(&self) -> String {
+        let details: Vec<String> = self
+            .fields
+            .iter()
+            .map(|(name, value)| format!("{name}: {value}"))
+            .collect();
+        format!(
+            "Confirm {} {}: {}.\n{}",
+            self.tool,
+            self.action,
+            self.summary,
+            details.join("\n")
+        )
+    }
+
+    #[must_use]
+    pub fn to_json(&self) -> JsonValue {
+        let fields: serde_json::Map<String, JsonValue> = self
+            .fields
+            .iter()
+            .map(|(name, value)| (name.clone(), json!(value)))
+            .collect();
+        json!({
+            "tool": self.tool,
+            "action": self.action,
+            "target_id": self.target_id,
+            "summary": self.summary,
+            "fields": fields
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssuedConfirmation {
+    pub token: String,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl IssuedConfirmation {
+    #[must_use]
+    pub fn issue(now: DateTime<Utc>) -> Self {
+        let token = generate_token();
+        Self {
+            token_hash: hash_token(&token),
+            token,
+            expires_at: now + Duration::minutes(CONFIRMATION_TTL_MINUTES),
+        }
+    }
+
+    #[must_use]
+    pub fn to_json(&self) -> JsonValue {
+        json!({
+            "token": self.token,
+            "expires_at": self.expires_at.to_rfc3339(),
+            "how": "repeat the same call with confirmation_token set to this token to perform the deletion"
+        })
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use std::sync::Arc;
+use systemprompt::database::DbPool;
+
+use crate::services::user_tokens::hash_token;
+
+use super::models::{DeletionPreview, IssuedConfirmation};
+
+pub struct ConfirmationRepository {
+    pool: Arc<PgPool>,
+}
+
+impl ConfirmationRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn store(
+        &self,
+        issued: &IssuedConfirmation,
+        actor_id: &str,
+        preview: &DeletionPreview,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO admin_confirmation_tokens
+                (token_hash, actor_id, tool, action, target_id, preview, expires_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            issued.token_hash,
+            actor_id,
+            preview.tool,
+            preview.action,
+            preview.target_id,
+            preview.to_json(),
+            issued.expires_at
+        )
+        .execute(&*self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn consume(
+        &self,
+        token: &str,
+        actor_id: &str,
+        preview: &DeletionPreview,
+    ) -> Result<bool> {
+        let consumed = sqlx::query!(
+            r#"
+            UPDATE admin_confirmation_tokens SET used_at = NOW()
+            WHERE token_hash = $1
+              AND actor_id = $2
+              AND tool = $3
+              AND action = $4
+              AND target_id = $5
+              AND used_at IS NULL
+              AND expires_at > NOW()
+            "#,
+            hash_token(token),
+            actor_id,
+            preview.tool,
+            preview.action,
+            preview.target_id
+        )
+        .execute(&*self.pool)
+        .await?
+        .rows_affected();
+        Ok(consumed > 0)
+    }
+}
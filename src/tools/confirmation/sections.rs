@@ -0,0 +1,52 @@
+use serde_json::json;
+use systemprompt::models::artifacts::{
+    Column, ColumnType, DashboardArtifact, DashboardHints, DashboardSection, LayoutMode,
+    LayoutWidth, SectionLayout, SectionType, TableArtifact,
+};
+
+use super::models::DeletionPreview;
+
+pub fn create_preview_dashboard(
+    preview: &DeletionPreview,
+) -> Result<DashboardArtifact, serde_json::Error> {
+    let status = DashboardSection::new("pending", "Pending Deletion", SectionType::MetricsCards)
+        .with_data(json!({
+            "cards": [{
+                "title": format!("{} {}", preview.tool, preview.action),
+                "value": preview.summary,
+                "icon": "alert-triangle",
+                "status": "warning"
+            }]
+        }))?
+        .with_layout(SectionLayout {
+            width: LayoutWidth::Full,
+            order: 1,
+        });
+
+    let table = TableArtifact::new(vec![
+        Column::new("field", ColumnType::String).with_header("Field"),
+        Column::new("value", ColumnType::String).with_header("Value"),
+    ])
+    .with_rows(
+        preview
+            .fields
+            .iter()
+            .map(|(field, value)| json!({ "field": field, "value": value }))
+            .collect(),
+    );
+    let details = DashboardSection::new("details", "What Will Be Deleted", SectionType::Table)
+        .with_data(table.to_response())?
+        .with_layout(SectionLayout {
+            width: LayoutWidth::Full,
+            order: 2,
+        });
+
+    Ok(DashboardArtifact::new("Confirm Deletion")
+        .with_description(format!(
+            "Nothing has been deleted yet. Review {} before confirming",
+            preview.summary
+        ))
+        .with_hints(DashboardHints::new().with_layout(LayoutMode::Vertical))
+        .add_section(status)
+        .add_section(details))
+}
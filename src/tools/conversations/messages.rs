@@ -0,0 +1,41 @@
+use anyhow::Result;
+use serde_json::{json, Value as JsonValue};
+use systemprompt::agent::{repository::task::TaskRepository, Part};
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::ContextId;
+
+pub async fn fetch_conversation_messages(
+    pool: &DbPool,
+    context_id: &str,
+) -> Result<Vec<JsonValue>> {
+    let task_repo = TaskRepository::new(pool.clone());
+    let context_id_obj = ContextId::new(context_id);
+
+    let tasks = task_repo.list_tasks_by_context(&context_id_obj).await?;
+
+    let mut messages = Vec::new();
+
+    for task in tasks {
+        if let Some(history) = task.history {
+            for msg in history {
+                let content = msg
+                    .parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        Part::Text(text) => Some(text.text.clone()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                messages.push(json!({
+                    "id": msg.id,
+                    "role": msg.role,
+                    "content": content,
+                }));
+            }
+        }
+    }
+
+    Ok(messages)
+}
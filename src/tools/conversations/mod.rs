@@ -1,4 +1,5 @@
-mod models;
+pub mod messages;
+pub mod models;
 pub mod repository;
 mod sections;
 
@@ -8,19 +9,21 @@ use rmcp::{
     ErrorData as McpError, RoleServer,
 };
 use serde_json::{json, Value as JsonValue};
-use systemprompt::agent::{repository::task::TaskRepository, Part};
 use systemprompt::database::DbPool;
-use systemprompt::identifiers::{ArtifactId, ContextId, McpExecutionId};
+use systemprompt::identifiers::{ArtifactId, McpExecutionId};
 use systemprompt::models::artifacts::{
     DashboardArtifact, DashboardHints, ExecutionMetadata, LayoutMode, ToolResponse,
 };
 
+use messages::fetch_conversation_messages;
 use repository::ConversationsRepository;
 use sections::{
     create_conversation_trends_section, create_conversations_table_section,
     create_summary_cards_section,
 };
 
+use crate::tools::pagination::{cursor_schema, parse_cursor_arg, with_next_cursor, Cursor};
+
 #[must_use]
 pub fn conversations_input_schema() -> JsonValue {
     json!({
@@ -40,12 +43,7 @@ pub fn conversations_input_schema() -> JsonValue {
                 "type": "string",
                 "description": "Filter by agent name. Use 'non-anonymous' to exclude anonymous agents."
             },
-            "page": {
-                "type": "integer",
-                "default": 1,
-                "minimum": 1,
-                "description": "Page number for conversation table pagination"
-            },
+            "cursor": cursor_schema(),
             "per_page": {
                 "type": "integer",
                 "default": 500,
@@ -81,20 +79,18 @@ pub async fn handle_conversations(
 
     let agent_name = args.get("agent_name").and_then(|v| v.as_str());
 
-    let page = args
-        .get("page")
-        .and_then(serde_json::Value::as_i64)
-        .unwrap_or(1) as i32;
+    let cursor = parse_cursor_arg(&args)?;
 
     let per_page = args
         .get("per_page")
         .and_then(serde_json::Value::as_i64)
-        .unwrap_or(10) as i32;
+        .unwrap_or(10)
+        .clamp(1, 500);
 
     tracing::debug!(
         time_range = %time_range,
         agent_filter = ?agent_name,
-        page = page,
+        cursor = ?cursor,
         per_page = per_page,
         "Generating conversation analytics"
     );
@@ -135,15 +131,14 @@ pub async fn handle_conversations(
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-    let offset = (page - 1) * per_page;
     let recent_conversations = repo
-        .get_recent_conversations_paginated(interval, per_page, offset, agent_name)
+        .get_recent_conversations_paginated(interval, per_page, cursor.as_ref(), agent_name)
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-    if !recent_conversations.is_empty() {
+    if !recent_conversations.items.is_empty() {
         dashboard = dashboard.add_section(
-            create_conversations_table_section(&recent_conversations)
+            create_conversations_table_section(&recent_conversations.items)
                 .map_err(|e| McpError::internal_error(e.to_string(), None))?,
         );
     }
@@ -176,13 +171,24 @@ pub async fn handle_conversations(
 
     Ok(CallToolResult {
         content: vec![Content::text(format!(
-            "Conversation Analytics ({}) - Page {}, {} per page{}",
+            "Conversation Analytics ({}) - {} conversations, {} per page{}{}",
             time_range,
-            page,
+            recent_conversations.items.len(),
             per_page,
-            agent_name.map_or(String::new(), |n| format!(", agent: {n}"))
+            agent_name.map_or(String::new(), |n| format!(", agent: {n}")),
+            if recent_conversations.next_cursor.is_some() {
+                ", more available via next_cursor"
+            } else {
+                ""
+            }
         ))],
-        structured_content: Some(tool_response.to_json()),
+        structured_content: Some(with_next_cursor(
+            tool_response.to_json(),
+            recent_conversations
+                .next_cursor
+                .as_ref()
+                .map(Cursor::encode),
+        )),
         is_error: Some(false),
         meta: metadata.to_meta(),
     })
@@ -195,38 +201,10 @@ async fn handle_conversation_details(
 ) -> Result<CallToolResult, McpError> {
     tracing::debug!(context_id = %context_id, "Retrieving messages");
 
-    let task_repo = TaskRepository::new(pool.clone());
-    let context_id_obj = ContextId::new(context_id);
-
-    let tasks = task_repo
-        .list_tasks_by_context(&context_id_obj)
+    let messages = fetch_conversation_messages(pool, context_id)
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-    let mut messages = Vec::new();
-
-    for task in tasks {
-        if let Some(history) = task.history {
-            for msg in history {
-                let content = msg
-                    .parts
-                    .iter()
-                    .filter_map(|part| match part {
-                        Part::Text(text) => Some(text.text.clone()),
-                        _ => None,
-                    })
-                    .collect::<Vec<_>>()
-                    .join("\n");
-
-                messages.push(json!({
-                    "id": msg.id,
-                    "role": msg.role,
-                    "content": content,
-                }));
-            }
-        }
-    }
-
     let artifact = json!({
         "context_id": context_id,
         "messages": messages,
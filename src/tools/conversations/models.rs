@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+
 #[derive(serde::Serialize, sqlx::FromRow)]
 pub struct ConversationSummary {
     pub total_conversations: i32,
@@ -18,6 +20,8 @@ pub struct RecentConversation {
     pub started_at_formatted: Option<String>,
     pub last_updated: String,
     pub last_updated_formatted: Option<String>,
+    #[serde(skip_serializing)]
+    pub sort_timestamp: DateTime<Utc>,
     pub duration_seconds: f64,
     pub duration_status: Option<String>,
     pub status: String,
@@ -1,10 +1,21 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use std::sync::Arc;
 use systemprompt::database::DbPool;
 
+use crate::tools::pagination::{Cursor, Page};
+
 use super::models::{ConversationSummary, ConversationTrendRow, RecentConversation};
 
+const NON_ANONYMOUS_FILTER: &str = "non-anonymous";
+
+struct ConversationScope<'a> {
+    interval: Option<&'a str>,
+    user_id: Option<&'a str>,
+    agent_filter: Option<&'a str>,
+}
+
 pub struct ConversationsRepository {
     pool: Arc<PgPool>,
 }
@@ -47,129 +58,49 @@ impl ConversationsRepository {
     pub async fn get_recent_conversations_paginated(
         &self,
         interval: &str,
-        limit: i32,
-        offset: i32,
+        limit: i64,
+        cursor: Option<&Cursor>,
         agent_filter: Option<&str>,
-    ) -> Result<Vec<RecentConversation>> {
-        match agent_filter {
-            Some("non-anonymous") => {
-                self.get_conversations_non_anonymous(interval, limit, offset)
-                    .await
-            }
-            Some(agent_name) => {
-                self.get_conversations_by_agent(interval, limit, offset, agent_name)
-                    .await
-            }
-            None => self.get_conversations_all(interval, limit, offset).await,
-        }
-    }
+    ) -> Result<Page<RecentConversation>> {
+        let scope = ConversationScope {
+            interval: Some(interval),
+            user_id: None,
+            agent_filter,
+        };
+        let rows = self.fetch_conversations(&scope, limit + 1, cursor).await?;
 
-    async fn get_conversations_all(
-        &self,
-        interval: &str,
-        limit: i32,
-        offset: i32,
-    ) -> Result<Vec<RecentConversation>> {
-        sqlx::query_as!(
-            RecentConversation,
-            r#"
-            SELECT
-                uc.context_id as "context_id!",
-                uc.name as conversation_name,
-                uc.user_id as "user_id!",
-                COALESCE(u.name, 'anonymous') as "user_name!",
-                COALESCE(at.agent_name, 'unknown') as "agent_name!",
-                uc.created_at::text as "started_at!",
-                TO_CHAR(uc.created_at, 'YYYY-MM-DD HH24:MI') as started_at_formatted,
-                uc.updated_at::text as "last_updated!",
-                TO_CHAR(uc.updated_at, 'YYYY-MM-DD HH24:MI') as last_updated_formatted,
-                COALESCE(EXTRACT(EPOCH FROM (uc.updated_at - uc.created_at))::float8, 0) as "duration_seconds!",
-                CASE
-                    WHEN EXTRACT(EPOCH FROM (uc.updated_at - uc.created_at)) < 60 THEN 'quick'
-                    WHEN EXTRACT(EPOCH FROM (uc.updated_at - uc.created_at)) < 300 THEN 'normal'
-                    ELSE 'long'
-                END as duration_status,
-                COALESCE(at.status, 'unknown') as "status!",
-                COALESCE((
-                    SELECT COUNT(*)::int4
-                    FROM task_messages tm
-                    JOIN agent_tasks at2 ON tm.task_id = at2.task_id
-                    WHERE at2.context_id = uc.context_id
-                ), 0) as "message_count!"
-            FROM user_contexts uc
-            LEFT JOIN users u ON u.id = uc.user_id
-            LEFT JOIN agent_tasks at ON at.context_id = uc.context_id
-            WHERE uc.updated_at >= NOW() - $1::TEXT::INTERVAL
-            ORDER BY uc.updated_at DESC
-            LIMIT $2 OFFSET $3
-            "#,
-            interval,
-            i64::from(limit),
-            i64::from(offset)
-        )
-        .fetch_all(&*self.pool)
-        .await
-        .map_err(Into::into)
+        Ok(Page::from_rows(
+            rows,
+            limit as usize,
+            |row| Cursor::new(row.sort_timestamp, row.context_id.clone()),
+            |row| row,
+        ))
     }
 
-    async fn get_conversations_non_anonymous(
+    pub async fn get_user_conversations(
         &self,
-        interval: &str,
-        limit: i32,
-        offset: i32,
+        user_id: &str,
+        limit: i64,
     ) -> Result<Vec<RecentConversation>> {
-        sqlx::query_as!(
-            RecentConversation,
-            r#"
-            SELECT
-                uc.context_id as "context_id!",
-                uc.name as conversation_name,
-                uc.user_id as "user_id!",
-                COALESCE(u.name, 'anonymous') as "user_name!",
-                COALESCE(at.agent_name, 'unknown') as "agent_name!",
-                uc.created_at::text as "started_at!",
-                TO_CHAR(uc.created_at, 'YYYY-MM-DD HH24:MI') as started_at_formatted,
-                uc.updated_at::text as "last_updated!",
-                TO_CHAR(uc.updated_at, 'YYYY-MM-DD HH24:MI') as last_updated_formatted,
-                COALESCE(EXTRACT(EPOCH FROM (uc.updated_at - uc.created_at))::float8, 0) as "duration_seconds!",
-                CASE
-                    WHEN EXTRACT(EPOCH FROM (uc.updated_at - uc.created_at)) < 60 THEN 'quick'
-                    WHEN EXTRACT(EPOCH FROM (uc.updated_at - uc.created_at)) < 300 THEN 'normal'
-                    ELSE 'long'
-                END as duration_status,
-                COALESCE(at.status, 'unknown') as "status!",
-                COALESCE((
-                    SELECT COUNT(*)::int4
-                    FROM task_messages tm
-                    JOIN agent_tasks at2 ON tm.task_id = at2.task_id
-                    WHERE at2.context_id = uc.context_id
-                ), 0) as "message_count!"
-            FROM user_contexts uc
-            LEFT JOIN users u ON u.id = uc.user_id
-            LEFT JOIN agent_tasks at ON at.context_id = uc.context_id
-            WHERE uc.updated_at >= NOW() - $1::TEXT::INTERVAL
-            AND at.agent_name IS NOT NULL
-            AND at.agent_name != 'anonymous'
-            AND at.agent_name != 'unknown'
-            ORDER BY uc.updated_at DESC
-            LIMIT $2 OFFSET $3
-            "#,
-            interval,
-            i64::from(limit),
-            i64::from(offset)
-        )
-        .fetch_all(&*self.pool)
-        .await
-        .map_err(Into::into)
+        let scope = ConversationScope {
+            interval: None,
+            user_id: Some(user_id),
+            agent_filter: None,
+        };
+        self.fetch_conversations(&scope, limit, None).await
     }
 
-    async fn get_conversations_by_agent(
+    async fn fetch_conversations(
         &self,
-        interval: &str,
-        limit: i32,
-        offset: i32,
-        agent_name: &str,
+        scope: &ConversationScope<'_>,
+        limit: i64,
+        cursor: Option<&Cursor>,
     ) -> Result<Vec<RecentConversation>> {
+        let non_anonymous = scope.agent_filter == Some(NON_ANONYMOUS_FILTER);
+        let agent_name = scope
+            .agent_filter
+            .filter(|agent| *agent != NON_ANONYMOUS_FILTER);
+
         sqlx::query_as!(
             RecentConversation,
             r#"
@@ -182,6 +113,7 @@ impl ConversationsRepository {
                 uc.created_at::text as "started_at!",
                 TO_CHAR(uc.created_at, 'YYYY-MM-DD HH24:MI') as started_at_formatted,
                 uc.updated_at::text as "last_updated!",
+                uc.updated_at as "sort_timestamp!: DateTime<Utc>",
                 TO_CHAR(uc.updated_at, 'YYYY-MM-DD HH24:MI') as last_updated_formatted,
                 COALESCE(EXTRACT(EPOCH FROM (uc.updated_at - uc.created_at))::float8, 0) as "duration_seconds!",
                 CASE
@@ -198,16 +130,29 @@ impl ConversationsRepository {
                 ), 0) as "message_count!"
             FROM user_contexts uc
             LEFT JOIN users u ON u.id = uc.user_id
-            LEFT JOIN agent_tasks at ON at.context_id = uc.context_id
-            WHERE uc.updated_at >= NOW() - $1::TEXT::INTERVAL
-            AND at.agent_name = $4
-            ORDER BY uc.updated_at DESC
-            LIMIT $2 OFFSET $3
+            LEFT JOIN LATERAL (
+                SELECT latest.agent_name, latest.status
+                FROM agent_tasks latest
+                WHERE latest.context_id = uc.context_id
+                  AND ($5::text IS NULL OR latest.agent_name = $5)
+                  AND (NOT $6::bool OR latest.agent_name NOT IN ('anonymous', 'unknown'))
+                ORDER BY latest.started_at DESC NULLS LAST, latest.task_id DESC
+                LIMIT 1
+            ) at ON true
+            WHERE ($1::text IS NULL OR uc.updated_at >= NOW() - $1::TEXT::INTERVAL)
+            AND ($7::text IS NULL OR uc.user_id = $7)
+            AND (($5::text IS NULL AND NOT $6::bool) OR at.agent_name IS NOT NULL)
+            AND ($3::timestamptz IS NULL OR (uc.updated_at, uc.context_id) < ($3, $4::text))
+            ORDER BY uc.updated_at DESC, uc.context_id DESC
+            LIMIT $2
             "#,
-            interval,
-            i64::from(limit),
-            i64::from(offset),
-            agent_name
+            scope.interval,
+            limit,
+            cursor.map(|c| c.timestamp),
+            cursor.map(|c| c.id.as_str()),
+            agent_name,
+            non_anonymous,
+            scope.user_id
         )
         .fetch_all(&*self.pool)
         .await
@@ -0,0 +1,125 @@
+use chrono::{DateTime, Duration, Utc};
+use systemprompt::scheduler::models::ScheduledJob;
+
+use super::models::{
+    DatabaseHealth, HealthAssessment, HealthFinding, HealthLevel, JobIssue, SystemResources,
+};
+
+const DB_LATENCY_WARN_MS: f64 = 250.0;
+const POOL_UTILISATION_WARN_PERCENT: f64 = 80.0;
+pub const MEMORY_AVAILABLE_WARN_PERCENT: f64 = 15.0;
+pub const MEMORY_AVAILABLE_CRITICAL_PERCENT: f64 = 5.0;
+pub const DISK_USED_WARN_PERCENT: f64 = 85.0;
+pub const DISK_USED_CRITICAL_PERCENT: f64 = 95.0;
+const JOB_OVERDUE_GRACE_MINUTES: i64 = 15;
+
+#[must_use]
+pub fn find_job_issues(jobs: &[ScheduledJob], now: DateTime<Utc>) -> Vec<JobIssue> {
+    let grace = Duration::minutes(JOB_OVERDUE_GRACE_MINUTES);
+
+    jobs.iter()
+        .filter(|job| job.enabled)
+        .filter_map(|job| {
+            if job.last_status.as_deref() == Some("failed") {
+                return Some(JobIssue {
+                    job_name: job.job_name.clone(),
+                    issue: "failing".to_string(),
+                    detail: job
+                        .last_error
+                        .clone()
+                        .unwrap_or_else(|| "Last run failed".to_string()),
+                });
+            }
+
+            let next_run = job.next_run?;
+            (next_run + grace < now).then(|| JobIssue {
+                job_name: job.job_name.clone(),
+                issue: "overdue".to_string(),
+                detail: format!("Expected to run at {}", next_run.to_rfc3339()),
+            })
+        })
+        .collect()
+}
+
+fn finding(level: HealthLevel, source: &str, message: String) -> HealthFinding {
+    HealthFinding {
+        level,
+        source: source.to_string(),
+        message,
+    }
+}
+
+#[must_use]
+pub fn assess(
+    database: &DatabaseHealth,
+    job_issues: &[JobIssue],
+    resources: &SystemResources,
+) -> HealthAssessment {
+    let mut findings = Vec::new();
+
+    if database.reachable {
+        if database.latency_ms > DB_LATENCY_WARN_MS {
+            findings.push(finding(
+                HealthLevel::Amber,
+                "database",
+                format!("Database latency is {:.0}ms", database.latency_ms),
+            ));
+        }
+        if database.pool_utilisation() > POOL_UTILISATION_WARN_PERCENT {
+            findings.push(finding(
+                HealthLevel::Amber,
+                "database",
+                format!(
+                    "Connection pool is {:.0}% utilised",
+                    database.pool_utilisation()
+                ),
+            ));
+        }
+    } else {
+        findings.push(finding(
+            HealthLevel::Red,
+            "database",
+            format!(
+                "Database is unreachable: {}",
+                database.error.as_deref().unwrap_or("unknown error")
+            ),
+        ));
+    }
+
+    findings.extend(job_issues.iter().map(|issue| {
+        finding(
+            HealthLevel::Amber,
+            "scheduler",
+            format!(
+                "Job '{}' is {}: {}",
+                issue.job_name, issue.issue, issue.detail
+            ),
+        )
+    }));
+
+    if let Some(available) = resources.memory_available_percent() {
+        let message = format!("Only {available:.1}% of memory is available");
+        if available < MEMORY_AVAILABLE_CRITICAL_PERCENT {
+            findings.push(finding(HealthLevel::Red, "memory", message));
+        } else if available < MEMORY_AVAILABLE_WARN_PERCENT {
+            findings.push(finding(HealthLevel::Amber, "memory", message));
+        }
+    }
+
+    if let Some(used) = resources.disk_used_percent() {
+        let message = format!("Disk is {used:.1}% full");
+        if used > DISK_USED_CRITICAL_PERCENT {
+            findings.push(finding(HealthLevel::Red, "disk", message));
+        } else if used > DISK_USED_WARN_PERCENT {
+            findings.push(finding(HealthLevel::Amber, "disk", message));
+        }
+    }
+
+    let level = findings
+        .iter()
+        .map(|f| f.level)
+        .max()
+        .unwrap_or(HealthLevel::Green);
+
+    HealthAssessment { level, findings }
+}
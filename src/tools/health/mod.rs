@@ -0,0 +1,164 @@
+pub mod assessment;
+pub mod models;
+pub mod repository;
+mod sections;
+pub mod system;
+
+use rmcp::{
+    model::{CallToolRequestParam, CallToolResult, Content},
+    service::RequestContext,
+    ErrorData as McpError, RoleServer,
+};
+use serde_json::{json, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::{ArtifactId, McpExecutionId};
+use systemprompt::models::artifacts::{
+    DashboardArtifact, DashboardHints, ExecutionMetadata, LayoutMode, ToolResponse,
+};
+use systemprompt::scheduler::models::ScheduledJob;
+use systemprompt::scheduler::repository::SchedulerRepository;
+
+use assessment::{assess, find_job_issues};
+use models::{JobIssue, TableSize};
+use repository::HealthRepository;
+use sections::{create_findings_section, create_overview_section, create_tables_section};
+use system::read_system_resources;
+
+#[must_use]
+pub fn health_input_schema() -> JsonValue {
+    json!({
+        "type": "object",
+        "properties": {
+            "table_limit": {
+                "type": "integer",
+                "minimum": 1,
+                "maximum": 50,
+                "default": 10,
+                "description": "Number of largest database tables to report"
+            }
+        }
+    })
+}
+
+#[must_use]
+pub fn health_output_schema() -> JsonValue {
+    ToolResponse::<DashboardArtifact>::schema()
+}
+
+pub async fn handle_health(
+    pool: &DbPool,
+    request: CallToolRequestParam,
+    _ctx: RequestContext<RoleServer>,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let table_limit = request
+        .arguments
+        .as_ref()
+        .and_then(|args| args.get("table_limit"))
+        .and_then(JsonValue::as_i64)
+        .unwrap_or(10)
+        .clamp(1, 50);
+
+    let repo = HealthRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let database = repo.check_database().await;
+    let resources = read_system_resources()
+        .await
+        .map_err(|e| McpError::internal_error(format!("{e:#}"), None))?;
+    let probes = if database.reachable {
+        probe_database(pool, &repo, table_limit).await
+    } else {
+        DatabaseProbes::default()
+    };
+
+    let assessment = probes.failures.iter().fold(
+        assess(&database, &probes.job_issues, &resources),
+        |assessment, (source, message)| assessment.with_probe_failure(source, message.clone()),
+    );
+
+    let dashboard = DashboardArtifact::new("System Health")
+        .with_description(format!("Overall status: {}", assessment.level.label()))
+        .with_hints(
+            DashboardHints::new()
+                .with_layout(LayoutMode::Vertical)
+                .with_refreshable(true)
+                .with_refresh_interval(30),
+        )
+        .add_section(
+            create_overview_section(&assessment, &database, &resources, probes.log_rows_estimate)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_findings_section(&assessment)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_tables_section(&probes.tables)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        );
+
+    let metadata = ExecutionMetadata::new().tool("health");
+    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
+    let tool_response = ToolResponse::new(
+        artifact_id,
+        mcp_execution_id.clone(),
+        dashboard,
+        metadata.clone(),
+    );
+
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "System health: {} ({} findings)",
+            assessment.level.label(),
+            assessment.findings.len()
+        ))],
+        structured_content: Some(tool_response.to_json()),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
+
+#[derive(Default)]
+struct DatabaseProbes {
+    tables: Vec<TableSize>,
+    log_rows_estimate: Option<i64>,
+    job_issues: Vec<JobIssue>,
+    failures: Vec<(&'static str, String)>,
+}
+
+async fn probe_database(
+    pool: &DbPool,
+    repo: &HealthRepository,
+    table_limit: i64,
+) -> DatabaseProbes {
+    let mut probes = DatabaseProbes::default();
+
+    match repo.largest_tables(table_limit).await {
+        Ok(tables) => probes.tables = tables,
+        Err(e) => probes
+            .failures
+            .push(("database", format!("Failed to read table sizes: {e}"))),
+    }
+    match repo.estimate_log_rows().await {
+        Ok(estimate) => probes.log_rows_estimate = Some(estimate),
+        Err(e) => probes
+            .failures
+            .push(("database", format!("Failed to estimate log rows: {e}"))),
+    }
+    match load_jobs(pool).await {
+        Ok(jobs) => probes.job_issues = find_job_issues(&jobs, chrono::Utc::now()),
+        Err(e) => probes
+            .failures
+            .push(("scheduler", format!("Failed to read scheduler jobs: {e}"))),
+    }
+
+    for (source, message) in &probes.failures {
+        tracing::warn!(source = %source, "{message}");
+    }
+    probes
+}
+
+async fn load_jobs(pool: &DbPool) -> anyhow::Result<Vec<ScheduledJob>> {
+    Ok(SchedulerRepository::new(pool)?.list_enabled_jobs().await?)
+}
@@ -0,0 +1,116 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+pub enum HealthLevel {
+    Green,
+    Amber,
+    Red,
+}
+
+impl HealthLevel {
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Green => "Healthy",
+            Self::Amber => "Degraded",
+            Self::Red => "Critical",
+        }
+    }
+
+    #[must_use]
+    pub fn card_status(self) -> &'static str {
+        match self {
+            Self::Green => "success",
+            Self::Amber => "warning",
+            Self::Red => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DatabaseHealth {
+    pub reachable: bool,
+    pub latency_ms: f64,
+    pub pool_size: u32,
+    pub pool_idle: u32,
+    pub pool_max: u32,
+    pub error: Option<String>,
+}
+
+impl DatabaseHealth {
+    #[must_use]
+    pub fn pool_utilisation(&self) -> f64 {
+        if self.pool_max == 0 {
+            return 0.0;
+        }
+        f64::from(self.pool_size.saturating_sub(self.pool_idle)) / f64::from(self.pool_max) * 100.0
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TableSize {
+    pub table_name: String,
+    pub total_bytes: i64,
+    pub row_estimate: i64,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct JobIssue {
+    pub job_name: String,
+    pub issue: String,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct SystemResources {
+    pub mem_total_kb: Option<u64>,
+    pub mem_available_kb: Option<u64>,
+    pub load_1m: Option<f64>,
+    pub cpu_count: Option<usize>,
+    pub disk_total_kb: Option<u64>,
+    pub disk_used_kb: Option<u64>,
+}
+
+impl SystemResources {
+    #[must_use]
+    pub fn memory_available_percent(&self) -> Option<f64> {
+        match (self.mem_total_kb, self.mem_available_kb) {
+            (Some(total), Some(available)) if total > 0 => {
+                Some(available as f64 / total as f64 * 100.0)
+            }
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn disk_used_percent(&self) -> Option<f64> {
+        match (self.disk_total_kb, self.disk_used_kb) {
+            (Some(total), Some(used)) if total > 0 => Some(used as f64 / total as f64 * 100.0),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct HealthFinding {
+    pub level: HealthLevel,
+    pub source: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct HealthAssessment {
+    pub level: HealthLevel,
+    pub findings: Vec<HealthFinding>,
+}
+
+impl HealthAssessment {
+    #[must_use]
+    pub fn with_probe_failure(mut self, source: &str, message: String) -> Self {
+        self.findings.push(HealthFinding {
+            level: HealthLevel::Amber,
+            source: source.to_string(),
+            message,
+        });
+        self.level = self.level.max(HealthLevel::Amber);
+        self
+    }
+}
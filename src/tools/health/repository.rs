@@ -0,0 +1,75 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Instant;
+use systemprompt::database::DbPool;
+
+use super::models::{DatabaseHealth, TableSize};
+
+pub struct HealthRepository {
+    pool: Arc<PgPool>,
+}
+
+impl HealthRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn check_database(&self) -> DatabaseHealth {
+        let started = Instant::now();
+        let result = sqlx::query_scalar!(r#"SELECT 1 as "one!""#)
+            .fetch_one(&*self.pool)
+            .await;
+        let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        DatabaseHealth {
+            reachable: result.is_ok(),
+            latency_ms,
+            pool_size: self.pool.size(),
+            pool_idle: self.pool.num_idle() as u32,
+            pool_max: self.pool.options().get_max_connections(),
+            error: result.err().map(|e| e.to_string()),
+        }
+    }
+
+    pub async fn largest_tables(&self, limit: i64) -> Result<Vec<TableSize>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                relname::text as "table_name!",
+                pg_total_relation_size(relid) as "total_bytes!",
+                n_live_tup as "row_estimate!"
+            FROM pg_stat_user_tables
+            ORDER BY pg_total_relation_size(relid) DESC
+            LIMIT $1
+            "#,
+            limit
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| TableSize {
+                table_name: r.table_name,
+                total_bytes: r.total_bytes,
+                row_estimate: r.row_estimate,
+            })
+            .collect())
+    }
+
+    pub async fn estimate_log_rows(&self) -> Result<i64> {
+        let estimate = sqlx::query_scalar!(
+            r#"
+            SELECT n_live_tup as "estimate!"
+            FROM pg_stat_user_tables
+            WHERE relid = 'logs'::regclass
+            "#
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        Ok(estimate)
+    }
+}
@@ -0,0 +1,168 @@
+use serde_json::json;
+use systemprompt::models::artifacts::{
+    Column, ColumnType, DashboardSection, LayoutWidth, SectionLayout, SectionType, TableArtifact,
+    TableHints,
+};
+
+use super::assessment::{
+    DISK_USED_CRITICAL_PERCENT, DISK_USED_WARN_PERCENT, MEMORY_AVAILABLE_CRITICAL_PERCENT,
+    MEMORY_AVAILABLE_WARN_PERCENT,
+};
+use super::models::{DatabaseHealth, HealthAssessment, SystemResources, TableSize};
+
+fn format_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+fn percent_status(value: Option<f64>, warn: f64, critical: f64) -> &'static str {
+    match value {
+        Some(v) if v > critical => "error",
+        Some(v) if v > warn => "warning",
+        Some(_) => "success",
+        None => "info",
+    }
+}
+
+pub fn create_overview_section(
+    assessment: &HealthAssessment,
+    database: &DatabaseHealth,
+    resources: &SystemResources,
+    log_rows_estimate: Option<i64>,
+) -> Result<DashboardSection, serde_json::Error> {
+    let memory_used = resources.memory_available_percent().map(|a| 100.0 - a);
+    let disk_used = resources.disk_used_percent();
+
+    let cards = vec![
+        json!({
+            "title": "Overall Status",
+            "value": assessment.level.label(),
+            "subtitle": assessment.findings.first().map_or("All checks passed", |f| f.message.as_str()),
+            "icon": "activity",
+            "status": assessment.level.card_status()
+        }),
+        json!({
+            "title": "Database",
+            "value": if database.reachable { format!("{:.1}ms", database.latency_ms) } else { "Unreachable".to_string() },
+            "subtitle": format!("pool {}/{} in use", database.pool_size.saturating_sub(database.pool_idle), database.pool_max),
+            "icon": "database",
+            "status": if database.reachable { "success" } else { "error" }
+        }),
+        json!({
+            "title": "Memory Used",
+            "value": memory_used.map_or_else(|| "—".to_string(), |v| format!("{v:.1}%")),
+            "subtitle": resources.mem_total_kb.map_or_else(|| "unavailable".to_string(), |kb| format!("of {}", format_bytes((kb * 1024) as i64))),
+            "icon": "cpu",
+            "status": percent_status(
+                memory_used,
+                100.0 - MEMORY_AVAILABLE_WARN_PERCENT,
+                100.0 - MEMORY_AVAILABLE_CRITICAL_PERCENT
+            )
+        }),
+        json!({
+            "title": "Disk Used",
+            "value": disk_used.map_or_else(|| "—".to_string(), |v| format!("{v:.1}%")),
+            "subtitle": resources.disk_total_kb.map_or_else(|| "unavailable".to_string(), |kb| format!("of {}", format_bytes((kb * 1024) as i64))),
+            "icon": "hard-drive",
+            "status": percent_status(disk_used, DISK_USED_WARN_PERCENT, DISK_USED_CRITICAL_PERCENT)
+        }),
+        json!({
+            "title": "Log Rows",
+            "value": log_rows_estimate.map_or_else(|| "—".to_string(), |estimate| format!("~{estimate}")),
+            "subtitle": resources.load_1m.map_or_else(String::new, |load| format!("load {load:.2} on {} cpus", resources.cpu_count.unwrap_or(1))),
+            "icon": "file-text",
+            "status": if log_rows_estimate.is_some() { "info" } else { "warning" }
+        }),
+    ];
+
+    Ok(DashboardSection::new(
+        "health_overview",
+        "System Health",
+        SectionType::MetricsCards,
+    )
+    .with_data(json!({ "cards": cards }))?
+    .with_layout(SectionLayout {
+        width: LayoutWidth::Full,
+        order: 1,
+    }))
+}
+
+pub fn create_findings_section(
+    assessment: &HealthAssessment,
+) -> Result<DashboardSection, serde_json::Error> {
+    let columns = vec![
+        Column::new("status", ColumnType::String).with_header("Status"),
+        Column::new("source", ColumnType::String).with_header("Source"),
+        Column::new("message", ColumnType::String).with_header("Finding"),
+    ];
+
+    let rows: Vec<serde_json::Value> = assessment
+        .findings
+        .iter()
+        .map(|f| {
+            json!({
+                "status": f.level.label(),
+                "source": f.source,
+                "message": f.message,
+            })
+        })
+        .collect();
+
+    let table = TableArtifact::new(columns)
+        .with_rows(rows)
+        .with_hints(TableHints::new().filterable());
+
+    Ok(
+        DashboardSection::new("health_findings", "Findings", SectionType::Table)
+            .with_data(table.to_response())?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 2,
+            }),
+    )
+}
+
+pub fn create_tables_section(tables: &[TableSize]) -> Result<DashboardSection, serde_json::Error> {
+    let columns = vec![
+        Column::new("table_name", ColumnType::String).with_header("Table"),
+        Column::new("size", ColumnType::String).with_header("Size"),
+        Column::new("total_bytes", ColumnType::Number).with_header("Bytes"),
+        Column::new("row_estimate", ColumnType::Number).with_header("Rows (est.)"),
+    ];
+
+    let rows: Vec<serde_json::Value> = tables
+        .iter()
+        .map(|t| {
+            json!({
+                "table_name": t.table_name,
+                "size": format_bytes(t.total_bytes),
+                "total_bytes": t.total_bytes,
+                "row_estimate": t.row_estimate,
+            })
+        })
+        .collect();
+
+    let table =
+        TableArtifact::new(columns)
+            .with_rows(rows)
+            .with_hints(TableHints::new().with_sortable(vec![
+                "table_name".to_string(),
+                "total_bytes".to_string(),
+                "row_estimate".to_string(),
+            ]));
+
+    Ok(
+        DashboardSection::new("largest_tables", "Largest Tables", SectionType::Table)
+            .with_data(table.to_response())?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 3,
+            }),
+    )
+}
@@ -0,0 +1,66 @@
+use anyhow::{Context, Result};
+
+use super::models::SystemResources;
+
+const DISK_MOUNT: &str = "/";
+
+pub async fn read_system_resources() -> Result<SystemResources> {
+    tokio::task::spawn_blocking(collect_system_resources)
+        .await
+        .context("System resource probe did not complete")
+}
+
+fn collect_system_resources() -> SystemResources {
+    let mut resources = SystemResources::default();
+
+    if let Ok(meminfo) = std::fs::read_to_string("/proc/meminfo") {
+        resources.mem_total_kb = parse_meminfo_field(&meminfo, "MemTotal");
+        resources.mem_available_kb = parse_meminfo_field(&meminfo, "MemAvailable");
+    }
+
+    if let Ok(loadavg) = std::fs::read_to_string("/proc/loadavg") {
+        resources.load_1m = parse_loadavg(&loadavg);
+    }
+
+    resources.cpu_count = std::thread::available_parallelism()
+        .ok()
+        .map(std::num::NonZeroUsize::get);
+
+    match rustix::fs::statvfs(DISK_MOUNT) {
+        Ok(stat) => {
+            if let Some((total, used)) = disk_usage_kb(stat.f_blocks, stat.f_bfree, stat.f_frsize) {
+                resources.disk_total_kb = Some(total);
+                resources.disk_used_kb = Some(used);
+            }
+        }
+        Err(e) => {
+            tracing::debug!(error = %e, "Unable to read disk usage");
+        }
+    }
+
+    resources
+}
+
+#[must_use]
+pub fn parse_meminfo_field(meminfo: &str, field: &str) -> Option<u64> {
+    meminfo.lines().find_map(|line| {
+        let (name, rest) = line.split_once(':')?;
+        if name.trim() != field {
+            return None;
+        }
+        rest.split_whitespace().next()?.parse().ok()
+    })
+}
+
+#[must_use]
+pub fn parse_loadavg(loadavg: &str) -> Option<f64> {
+    loadavg.split_whitespace().next()?.parse().ok()
+}
+
+#[must_use]
+pub fn disk_usage_kb(blocks: u64, free_blocks: u64, fragment_size: u64) -> Option<(u64, u64)> {
+    let used_blocks = blocks.checked_sub(free_blocks)?;
+    let total = blocks.checked_mul(fragment_size)? / 1024;
+    let used = used_blocks.checked_mul(fragment_size)? / 1024;
+    Some((total, used))
+}
@@ -1,3 +1,6 @@
+pub mod runner;
+mod sections;
+
 use rmcp::{
     model::{CallToolRequestParam, CallToolResult, Content},
     service::RequestContext,
@@ -8,33 +11,30 @@ use std::sync::Arc;
 use systemprompt::database::DbPool;
 use systemprompt::identifiers::{ArtifactId, McpExecutionId};
 use systemprompt::models::artifacts::{
-    Column, ColumnType, ExecutionMetadata, TableArtifact, TableHints, ToolResponse,
+    DashboardArtifact, DashboardHints, ExecutionMetadata, LayoutMode, ToolResponse,
 };
 use systemprompt::scheduler::models::ScheduledJob;
 use systemprompt::scheduler::repository::SchedulerRepository;
 use systemprompt::system::AppContext;
 
+use runner::{registered_job_names, run_job};
+use sections::{build_jobs_table, create_job_run_section, create_jobs_table_section};
+
 #[must_use]
 pub fn jobs_input_schema() -> JsonValue {
+    let mut execute_job = json!({
+        "type": "string",
+        "description": "Optional job name to execute (leave blank to just list jobs)"
+    });
+    let job_names = registered_job_names();
+    if !job_names.is_empty() {
+        execute_job["enum"] = json!(job_names);
+    }
+
     json!({
         "type": "object",
         "properties": {
-            "execute_job": {
-                "type": "string",
-                "description": "Optional job name to execute (leave blank to just list jobs)",
-                "enum": [
-                    "cleanup_anonymous_users",
-                    "cleanup_inactive_sessions",
-                    "database_cleanup",
-                    "publish_content",
-                    "evaluate_conversations",
-                    "ingest_content",
-                    "ingest_files",
-                    "optimize_images",
-                    "regenerate_static_content",
-                    "rebuild_static_site"
-                ]
-            }
+            "execute_job": execute_job
         }
     })
 }
@@ -43,13 +43,19 @@ pub fn jobs_input_schema() -> JsonValue {
 pub fn jobs_output_schema() -> JsonValue {
     json!({
         "type": "object",
-        "description": "Table of all scheduler jobs with current status",
-        "properties": {
-            "x-artifact-type": {"type": "string", "enum": ["table"]},
-            "columns": {"type": "array"},
-            "rows": {"type": "array"}
-        },
-        "required": ["x-artifact-type", "columns", "rows"]
+        "description": "Table of all scheduler jobs with current status, or a dashboard with the outcome of an execute_job run and the jobs table",
+        "oneOf": [
+            {
+                "type": "object",
+                "properties": {
+                    "x-artifact-type": {"type": "string", "enum": ["table"]},
+                    "columns": {"type": "array"},
+                    "rows": {"type": "array"}
+                },
+                "required": ["x-artifact-type", "columns", "rows"]
+            },
+            ToolResponse::<DashboardArtifact>::schema()
+        ]
     })
 }
 
@@ -57,7 +63,7 @@ pub async fn handle_jobs(
     pool: &DbPool,
     request: CallToolRequestParam,
     _ctx: RequestContext<RoleServer>,
-    _app_context: Arc<AppContext>,
+    app_context: Arc<AppContext>,
     mcp_execution_id: &McpExecutionId,
 ) -> Result<CallToolResult, McpError> {
     let execute_job = request
@@ -67,11 +73,7 @@ pub async fn handle_jobs(
         .and_then(|v| v.as_str());
 
     if let Some(job_name) = execute_job {
-        tracing::warn!(job_name = %job_name, "Job execution not supported in this version");
-        return Err(McpError::invalid_request(
-            format!("Job execution is not supported in this version. Job: {job_name}"),
-            None,
-        ));
+        return handle_execute_job(pool, app_context, job_name, mcp_execution_id).await;
     }
 
     let repo = SchedulerRepository::new(pool)
@@ -81,7 +83,7 @@ pub async fn handle_jobs(
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-    let table = build_jobs_table(jobs);
+    let table = build_jobs_table(&jobs);
 
     let metadata = ExecutionMetadata::new().tool("jobs");
     let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
@@ -93,51 +95,80 @@ pub async fn handle_jobs(
     );
 
     Ok(CallToolResult {
-        content: vec![Content::text(format!(
-            "Scheduler Jobs{}",
-            execute_job
-                .map(|j| format!(" (executing: {j})"))
-                .unwrap_or_default()
-        ))],
+        content: vec![Content::text("Scheduler Jobs")],
         structured_content: Some(tool_response.to_json()),
         is_error: Some(false),
         meta: metadata.to_meta(),
     })
 }
 
-fn build_jobs_table(jobs: Vec<ScheduledJob>) -> TableArtifact {
-    let columns = vec![
-        Column::new("job_name", ColumnType::String).with_header("Job Name"),
-        Column::new("schedule", ColumnType::String).with_header("Schedule"),
-        Column::new("enabled", ColumnType::Boolean).with_header("Enabled"),
-        Column::new("last_run", ColumnType::String).with_header("Last Run"),
-        Column::new("last_status", ColumnType::String).with_header("Status"),
-        Column::new("run_count", ColumnType::Number).with_header("Run Count"),
-        Column::new("last_error", ColumnType::String).with_header("Error"),
-    ];
-
-    let rows: Vec<serde_json::Value> = jobs
-        .iter()
-        .map(|job| {
-            json!({
-                "job_name": job.job_name,
-                "schedule": job.schedule,
-                "enabled": job.enabled,
-                "last_run": job.last_run.map_or_else(|| "Never".to_string(), |dt| dt.to_rfc3339()),
-                "last_status": job.last_status.as_deref().unwrap_or("—"),
-                "run_count": job.run_count,
-                "last_error": job.last_error.as_deref().unwrap_or(""),
-            })
-        })
-        .collect();
-
-    TableArtifact::new(columns).with_rows(rows).with_hints(
-        TableHints::new()
-            .with_sortable(vec![
-                "job_name".to_string(),
-                "last_run".to_string(),
-                "run_count".to_string(),
-            ])
-            .filterable(),
-    )
+async fn load_job_state(
+    pool: &DbPool,
+    job_name: &str,
+) -> anyhow::Result<(Option<ScheduledJob>, Vec<ScheduledJob>)> {
+    let repo = SchedulerRepository::new(pool)?;
+    let job = repo.find_job(job_name).await?;
+    let jobs = repo.list_enabled_jobs().await?;
+    Ok((job, jobs))
+}
+
+async fn handle_execute_job(
+    pool: &DbPool,
+    app_context: Arc<AppContext>,
+    job_name: &str,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let known_jobs = registered_job_names();
+    if !known_jobs.iter().any(|name| name == job_name) {
+        return Err(McpError::invalid_params(
+            format!(
+                "Unknown job: {job_name}. Registered jobs: {}",
+                known_jobs.join(", ")
+            ),
+            None,
+        ));
+    }
+
+    let run = run_job(pool, app_context, job_name)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let (job, jobs) = load_job_state(pool, job_name).await.map_err(|e| {
+        McpError::internal_error(
+            format!(
+                "Job '{job_name}' {}, but the scheduler jobs could not be reloaded: {e}",
+                run.summary()
+            ),
+            None,
+        )
+    })?;
+    let job = job.as_ref();
+
+    let dashboard = DashboardArtifact::new(format!("Job Execution: {job_name}"))
+        .with_description("Outcome of a manually triggered scheduler job")
+        .with_hints(DashboardHints::new().with_layout(LayoutMode::Vertical))
+        .add_section(
+            create_job_run_section(&run, job)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_jobs_table_section(&jobs)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        );
+
+    let metadata = ExecutionMetadata::new().tool("jobs");
+    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
+    let tool_response = ToolResponse::new(
+        artifact_id,
+        mcp_execution_id.clone(),
+        dashboard,
+        metadata.clone(),
+    );
+
+    Ok(CallToolResult {
+        content: vec![Content::text(format!("Job '{job_name}' {}", run.summary()))],
+        structured_content: Some(tool_response.to_json()),
+        is_error: Some(!run.succeeded),
+        meta: metadata.to_meta(),
+    })
 }
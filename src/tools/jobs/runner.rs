@@ -0,0 +1,119 @@
+use anyhow::{anyhow, Result};
+use std::sync::Arc;
+use std::time::Instant;
+use systemprompt::database::DbPool;
+use systemprompt::scheduler::repository::SchedulerRepository;
+use systemprompt::scheduler::services::{JobContext, JobRegistry};
+use systemprompt::system::AppContext;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobRun {
+    pub job_name: String,
+    pub succeeded: bool,
+    pub duration_ms: u128,
+    pub message: Option<String>,
+    pub error: Option<String>,
+    pub record_error: Option<String>,
+}
+
+impl JobRun {
+    #[must_use]
+    pub fn from_outcome(
+        job_name: &str,
+        outcome: Result<Option<String>, String>,
+        duration_ms: u128,
+    ) -> Self {
+        let (succeeded, message, error) = match outcome {
+            Ok(message) => (true, message, None),
+            Err(error) => (false, None, Some(error)),
+        };
+        Self {
+            job_name: job_name.to_string(),
+            succeeded,
+            duration_ms,
+            message,
+            error,
+            record_error: None,
+        }
+    }
+
+    #[must_use]
+    pub fn status(&self) -> &'static str {
+        if self.succeeded {
+            "success"
+        } else {
+            "failed"
+        }
+    }
+
+    #[must_use]
+    pub fn with_record_result(mut self, recorded: Result<()>) -> Self {
+        self.record_error = recorded.err().map(|e| e.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let outcome = match (&self.error, self.succeeded) {
+            (Some(error), _) => format!("failed after {}ms: {error}", self.duration_ms),
+            (None, true) => format!("succeeded in {}ms", self.duration_ms),
+            (None, false) => format!("failed after {}ms", self.duration_ms),
+        };
+        match &self.record_error {
+            Some(error) => format!("{outcome} (the run could not be recorded: {error})"),
+            None => outcome,
+        }
+    }
+}
+
+#[must_use]
+pub fn registered_job_names() -> Vec<String> {
+    let mut names: Vec<String> = JobRegistry::all()
+        .map(|job| job.name().to_string())
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+async fn record_run(repo: &SchedulerRepository, run: &JobRun) -> Result<()> {
+    repo.update_job_execution(&run.job_name, run.status(), run.error.as_deref(), None)
+        .await?;
+    repo.increment_run_count(&run.job_name).await?;
+    Ok(())
+}
+
+pub async fn run_job(
+    pool: &DbPool,
+    app_context: Arc<AppContext>,
+    job_name: &str,
+) -> Result<JobRun> {
+    let job = JobRegistry::all()
+        .find(|job| job.name() == job_name)
+        .ok_or_else(|| anyhow!("Job is not registered with the scheduler: {job_name}"))?;
+
+    let repo = SchedulerRepository::new(pool)?;
+    let job_context = JobContext::new(Arc::new(pool.clone()), app_context);
+
+    tracing::info!(job_name = %job_name, "Executing scheduler job manually");
+
+    let started = Instant::now();
+    let outcome = job
+        .execute(&job_context)
+        .await
+        .map(|result| result.message)
+        .map_err(|e| e.to_string());
+    let run = JobRun::from_outcome(job_name, outcome, started.elapsed().as_millis());
+
+    if let Some(ref e) = run.error {
+        tracing::warn!(job_name = %job_name, error = %e, duration_ms = %run.duration_ms, "Scheduler job failed");
+    } else {
+        tracing::info!(job_name = %job_name, duration_ms = %run.duration_ms, "Scheduler job completed");
+    }
+
+    let recorded = record_run(&repo, &run).await;
+    if let Err(ref e) = recorded {
+        tracing::error!(job_name = %job_name, error = %e, "Failed to record scheduler job run");
+    }
+    Ok(run.with_record_result(recorded))
+}
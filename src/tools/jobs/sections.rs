@@ -0,0 +1,105 @@
+use serde_json::json;
+use systemprompt::models::artifacts::{
+    Column, ColumnType, DashboardSection, LayoutWidth, SectionLayout, SectionType, TableArtifact,
+    TableHints,
+};
+use systemprompt::scheduler::models::ScheduledJob;
+
+use super::runner::JobRun;
+
+pub fn build_jobs_table(jobs: &[ScheduledJob]) -> TableArtifact {
+    let columns = vec![
+        Column::new("job_name", ColumnType::String).with_header("Job Name"),
+        Column::new("schedule", ColumnType::String).with_header("Schedule"),
+        Column::new("enabled", ColumnType::Boolean).with_header("Enabled"),
+        Column::new("last_run", ColumnType::String).with_header("Last Run"),
+        Column::new("last_status", ColumnType::String).with_header("Status"),
+        Column::new("run_count", ColumnType::Number).with_header("Run Count"),
+        Column::new("last_error", ColumnType::String).with_header("Error"),
+    ];
+
+    let rows: Vec<serde_json::Value> = jobs
+        .iter()
+        .map(|job| {
+            json!({
+                "job_name": job.job_name,
+                "schedule": job.schedule,
+                "enabled": job.enabled,
+                "last_run": job.last_run.map_or_else(|| "Never".to_string(), |dt| dt.to_rfc3339()),
+                "last_status": job.last_status.as_deref().unwrap_or("—"),
+                "run_count": job.run_count,
+                "last_error": job.last_error.as_deref().unwrap_or(""),
+            })
+        })
+        .collect();
+
+    TableArtifact::new(columns).with_rows(rows).with_hints(
+        TableHints::new()
+            .with_sortable(vec![
+                "job_name".to_string(),
+                "last_run".to_string(),
+                "run_count".to_string(),
+            ])
+            .filterable(),
+    )
+}
+
+pub fn create_job_run_section(
+    run: &JobRun,
+    job: Option<&ScheduledJob>,
+) -> Result<DashboardSection, serde_json::Error> {
+    let mut cards = vec![
+        json!({
+            "title": "Outcome",
+            "value": if run.succeeded { "Success" } else { "Failed" },
+            "subtitle": run.error.as_deref().or(run.message.as_deref()).unwrap_or(&run.job_name),
+            "icon": if run.succeeded { "check-circle" } else { "x-circle" },
+            "status": if run.succeeded { "success" } else { "error" }
+        }),
+        json!({
+            "title": "Duration",
+            "value": format!("{}ms", run.duration_ms),
+            "subtitle": "wall clock time",
+            "icon": "clock",
+            "status": "info"
+        }),
+        json!({
+            "title": "Run Count",
+            "value": job.map_or_else(|| "—".to_string(), |j| j.run_count.to_string()),
+            "subtitle": "total executions",
+            "icon": "repeat",
+            "status": "info"
+        }),
+    ];
+    if let Some(error) = &run.record_error {
+        cards.push(json!({
+            "title": "Run History",
+            "value": "Not Recorded",
+            "subtitle": error,
+            "icon": "alert-triangle",
+            "status": "warning"
+        }));
+    }
+
+    Ok(
+        DashboardSection::new("job_run", "Job Execution", SectionType::MetricsCards)
+            .with_data(json!({ "cards": cards }))?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 1,
+            }),
+    )
+}
+
+pub fn create_jobs_table_section(
+    jobs: &[ScheduledJob],
+) -> Result<DashboardSection, serde_json::Error> {
+    Ok(
+        DashboardSection::new("scheduler_jobs", "Scheduler Jobs", SectionType::Table)
+            .with_data(build_jobs_table(jobs).to_response())?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 2,
+            }),
+    )
+}
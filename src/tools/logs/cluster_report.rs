@@ -0,0 +1,136 @@
+use chrono::{DateTime, Duration, Utc};
+use rmcp::{
+    model::{CallToolResult, Content},
+    ErrorData as McpError,
+};
+use serde_json::Value as JsonValue;
+use systemprompt::identifiers::{ArtifactId, McpExecutionId};
+use systemprompt::models::artifacts::{
+    DashboardArtifact, DashboardHints, ExecutionMetadata, LayoutMode, ToolResponse,
+};
+
+use super::clusters::{cluster_events, hour_buckets};
+use super::models::{LogEvent, LogFilter};
+use super::repository::LogsRepository;
+use super::sections::{
+    create_cluster_chart_section, create_clusters_table_section, create_stats_section,
+};
+
+const CLUSTER_DEFAULT_WINDOW_HOURS: i64 = 24;
+const CLUSTER_MAX_WINDOW_HOURS: i64 = 168;
+const CLUSTER_MAX_EVENTS: i64 = 10_000;
+
+pub async fn handle_clusters(
+    repo: &LogsRepository,
+    mut filter: LogFilter,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let (since, until) = cluster_window(&filter)?;
+    filter.since = Some(since);
+    filter.until = Some(until);
+
+    tracing::debug!(filter = ?filter, "Clustering log messages");
+
+    let (events, truncated) = fetch_cluster_events(repo, &filter).await?;
+    let truncation_note = if truncated {
+        format!(
+            ". Truncated to the {CLUSTER_MAX_EVENTS} most recent events; narrow the window or filters to cluster everything"
+        )
+    } else {
+        String::new()
+    };
+
+    let stats = repo
+        .fetch_log_stats(&filter)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let clusters = cluster_events(&events, since, until);
+    let buckets = hour_buckets(since, until);
+
+    let dashboard = DashboardArtifact::new("Error Clusters")
+        .with_description(format!(
+            "Recurring problems grouped by fingerprint for {}{truncation_note}",
+            filter.describe()
+        ))
+        .with_hints(
+            DashboardHints::new()
+                .with_layout(LayoutMode::Vertical)
+                .with_refreshable(true)
+                .with_refresh_interval(60),
+        )
+        .add_section(
+            create_stats_section(&stats)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_cluster_chart_section(&clusters, since, buckets)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_clusters_table_section(&clusters)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        );
+
+    let metadata = ExecutionMetadata::new().tool("logs");
+    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
+    let tool_response = ToolResponse::new(
+        artifact_id,
+        mcp_execution_id.clone(),
+        dashboard,
+        metadata.clone(),
+    );
+
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "{} error clusters from {} events between {} and {}{truncation_note}",
+            clusters.len(),
+            events.len(),
+            since.to_rfc3339(),
+            until.to_rfc3339()
+        ))],
+        structured_content: Some(with_truncated(tool_response.to_json(), truncated)),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
+
+fn cluster_window(filter: &LogFilter) -> Result<(DateTime<Utc>, DateTime<Utc>), McpError> {
+    let until = filter.until.unwrap_or_else(Utc::now);
+    let since = filter
+        .since
+        .unwrap_or(until - Duration::hours(CLUSTER_DEFAULT_WINDOW_HOURS));
+    if until - since > Duration::hours(CLUSTER_MAX_WINDOW_HOURS) {
+        return Err(McpError::invalid_params(
+            format!("Clusters mode supports windows of up to {CLUSTER_MAX_WINDOW_HOURS} hours"),
+            None,
+        ));
+    }
+    Ok((since, until))
+}
+
+async fn fetch_cluster_events(
+    repo: &LogsRepository,
+    filter: &LogFilter,
+) -> Result<(Vec<LogEvent>, bool), McpError> {
+    let mut events = repo
+        .fetch_problem_events(filter, CLUSTER_MAX_EVENTS + 1)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let truncated = events.len() as i64 > CLUSTER_MAX_EVENTS;
+    if truncated {
+        events.pop();
+        tracing::warn!(
+            limit = CLUSTER_MAX_EVENTS,
+            "Log clustering truncated to most recent events"
+        );
+    }
+    Ok((events, truncated))
+}
+
+fn with_truncated(mut structured: JsonValue, truncated: bool) -> JsonValue {
+    if let Some(object) = structured.as_object_mut() {
+        object.insert("truncated".to_string(), JsonValue::Bool(truncated));
+    }
+    structured
+}
@@ -0,0 +1,162 @@
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{BTreeSet, HashMap};
+
+use super::models::{ErrorCluster, LogEvent};
+
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const PATH_TRIM: &[char] = &['"', '\'', '(', ')', '[', ']', '{', '}', ',', ';', ':'];
+
+fn is_uuid_at(chars: &[char], start: usize) -> bool {
+    const GROUPS: [usize; 5] = [8, 4, 4, 4, 12];
+    let mut index = start;
+    for (i, len) in GROUPS.iter().enumerate() {
+        if i > 0 {
+            if chars.get(index) != Some(&'-') {
+                return false;
+            }
+            index += 1;
+        }
+        for _ in 0..*len {
+            match chars.get(index) {
+                Some(c) if c.is_ascii_hexdigit() => index += 1,
+                _ => return false,
+            }
+        }
+    }
+    !chars.get(index).is_some_and(char::is_ascii_alphanumeric)
+}
+
+fn normalise_token(token: &str) -> String {
+    let core = token.trim_matches(PATH_TRIM);
+    if core.len() > 1 && (core.contains('/') || core.contains('\\')) && !core.contains("://") {
+        return token.replacen(core, "<path>", 1);
+    }
+
+    let chars: Vec<char> = token.chars().collect();
+    let mut output = String::with_capacity(token.len());
+    let mut index = 0;
+    while index < chars.len() {
+        let at_boundary = index == 0 || !chars[index - 1].is_ascii_alphanumeric();
+        if at_boundary && is_uuid_at(&chars, index) {
+            output.push_str("<uuid>");
+            index += 36;
+        } else if chars[index].is_ascii_digit() {
+            while index < chars.len() && (chars[index].is_ascii_digit() || chars[index] == '.') {
+                index += 1;
+            }
+            output.push_str("<n>");
+        } else {
+            output.push(chars[index]);
+            index += 1;
+        }
+    }
+    output
+}
+
+#[must_use]
+pub fn fingerprint(message: &str) -> String {
+    message
+        .split_whitespace()
+        .map(normalise_token)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[must_use]
+pub fn sparkline(values: &[u32]) -> String {
+    let max = values.iter().copied().max().unwrap_or(0);
+    values
+        .iter()
+        .map(|&value| {
+            if max == 0 || value == 0 {
+                ' '
+            } else {
+                let level = (value as usize * (SPARK_LEVELS.len() - 1)) / max as usize;
+                SPARK_LEVELS[level]
+            }
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn hour_buckets(since: DateTime<Utc>, until: DateTime<Utc>) -> usize {
+    let minutes = (until - since).num_minutes().max(1);
+    ((minutes + 59) / 60) as usize
+}
+
+#[must_use]
+pub fn cluster_events(
+    events: &[LogEvent],
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> Vec<ErrorCluster> {
+    let buckets = hour_buckets(since, until);
+    let midpoint = since + (until - since) / 2;
+    let mut clusters: HashMap<String, ErrorCluster> = HashMap::new();
+    let mut modules: HashMap<String, BTreeSet<String>> = HashMap::new();
+
+    for event in events {
+        if event.timestamp < since || event.timestamp >= until {
+            continue;
+        }
+
+        let key = fingerprint(&event.message);
+        let cluster = clusters.entry(key.clone()).or_insert_with(|| ErrorCluster {
+            fingerprint: key.clone(),
+            sample_message: event.message.clone(),
+            level: event.level.to_uppercase(),
+            count: 0,
+            first_seen: event.timestamp,
+            last_seen: event.timestamp,
+            modules: Vec::new(),
+            hourly: vec![0; buckets],
+            growth: 0,
+        });
+
+        cluster.count += 1;
+        cluster.first_seen = cluster.first_seen.min(event.timestamp);
+        cluster.last_seen = cluster.last_seen.max(event.timestamp);
+        if event.level.eq_ignore_ascii_case("ERROR") {
+            cluster.level = "ERROR".to_string();
+        }
+
+        let bucket = ((event.timestamp - since).num_minutes() / 60) as usize;
+        if let Some(slot) = cluster.hourly.get_mut(bucket.min(buckets - 1)) {
+            *slot += 1;
+        }
+        cluster.growth += if event.timestamp >= midpoint { 1 } else { -1 };
+
+        modules.entry(key).or_default().insert(event.module.clone());
+    }
+
+    let mut result: Vec<ErrorCluster> = clusters
+        .into_iter()
+        .map(|(key, mut cluster)| {
+            cluster.modules = modules
+                .remove(&key)
+                .unwrap_or_default()
+                .into_iter()
+                .collect();
+            cluster
+        })
+        .collect();
+
+    result.sort_by(|a, b| {
+        b.growth
+            .cmp(&a.growth)
+            .then(b.count.cmp(&a.count))
+            .then(b.last_seen.cmp(&a.last_seen))
+    });
+    result
+}
+
+#[must_use]
+pub fn bucket_labels(since: DateTime<Utc>, buckets: usize) -> Vec<String> {
+    (0..buckets)
+        .map(|i| {
+            (since + Duration::hours(i as i64))
+                .format("%m-%d %H:00")
+                .to_string()
+        })
+        .collect()
+}
@@ -0,0 +1,86 @@
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{Map, Value as JsonValue};
+
+use super::models::{LogFilter, ModuleMatch, SearchMode};
+
+pub fn parse_relative_duration(value: &str) -> Option<Duration> {
+    let (amount, to_duration): (&str, fn(i64) -> Option<Duration>) =
+        if let Some(amount) = value.strip_suffix('m') {
+            (amount, Duration::try_minutes)
+        } else if let Some(amount) = value.strip_suffix('h') {
+            (amount, Duration::try_hours)
+        } else if let Some(amount) = value.strip_suffix('d') {
+            (amount, Duration::try_days)
+        } else {
+            return None;
+        };
+    amount
+        .parse()
+        .ok()
+        .filter(|amount| *amount >= 0)
+        .and_then(to_duration)
+}
+
+pub fn parse_time_bound(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+
+    let duration = parse_relative_duration(value).ok_or_else(|| {
+        format!("Invalid time bound '{value}': expected RFC 3339 or e.g. 15m, 1h, 7d")
+    })?;
+
+    now.checked_sub_signed(duration)
+        .ok_or_else(|| format!("Invalid time bound '{value}': too far in the past"))
+}
+
+fn string_arg(args: &Map<String, JsonValue>, key: &str) -> Option<String> {
+    args.get(key)
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(String::from)
+}
+
+pub fn parse_log_filter(
+    args: &Map<String, JsonValue>,
+    now: DateTime<Utc>,
+) -> Result<LogFilter, String> {
+    let since = string_arg(args, "since")
+        .map(|v| parse_time_bound(&v, now))
+        .transpose()?;
+    let until = string_arg(args, "until")
+        .map(|v| parse_time_bound(&v, now))
+        .transpose()?;
+
+    if let (Some(since), Some(until)) = (since, until) {
+        if since >= until {
+            return Err("'since' must be earlier than 'until'".to_string());
+        }
+    }
+
+    let module_match = match args.get("module_match").and_then(JsonValue::as_str) {
+        None | Some("exact") => ModuleMatch::Exact,
+        Some("prefix") => ModuleMatch::Prefix,
+        Some(other) => return Err(format!("Invalid module_match: {other}")),
+    };
+
+    let search_mode = match args.get("search_mode").and_then(JsonValue::as_str) {
+        None | Some("substring") => SearchMode::Substring,
+        Some("full_text") => SearchMode::FullText,
+        Some(other) => return Err(format!("Invalid search_mode: {other}")),
+    };
+
+    Ok(LogFilter {
+        level: string_arg(args, "level"),
+        since,
+        until,
+        module: string_arg(args, "module"),
+        module_match,
+        user_id: string_arg(args, "user_id"),
+        session_id: string_arg(args, "session_id"),
+        context_id: string_arg(args, "context_id"),
+        search: string_arg(args, "search"),
+        search_mode,
+    })
+}
@@ -1,5 +1,8 @@
-mod models;
+pub mod clusters;
+pub mod filter;
+pub mod models;
 pub mod repository;
+mod cluster_report;
 mod sections;
 
 use rmcp::{
@@ -14,28 +17,80 @@ use systemprompt::models::artifacts::{
     DashboardArtifact, DashboardHints, ExecutionMetadata, LayoutMode, ToolResponse,
 };
 
+use cluster_report::handle_clusters;
+use filter::parse_log_filter;
+use models::LogFilter;
 use repository::LogsRepository;
 use sections::{create_logs_table_section, create_stats_section};
 
+use crate::tools::pagination::{cursor_schema, parse_cursor_arg, with_next_cursor, Cursor};
+
+const DEFAULT_LIMIT: i64 = 100;
+const MAX_LIMIT: i64 = 1000;
+
 #[must_use]
 pub fn logs_input_schema() -> JsonValue {
     json!({
         "type": "object",
         "properties": {
-            "page": {
-                "type": "integer",
-                "default": 0,
-                "description": "Page number for pagination (0-indexed)"
+            "mode": {
+                "type": "string",
+                "enum": ["entries", "clusters"],
+                "default": "entries",
+                "description": "entries lists matching log rows; clusters groups ERROR/WARN messages into fingerprints with hourly trends (defaults to the last 24 hours, up to 7 days)"
             },
+            "cursor": cursor_schema(),
             "limit": {
                 "type": "integer",
-                "default": 1000,
-                "description": "Number of log entries per page (default 1000)"
+                "default": DEFAULT_LIMIT,
+                "minimum": 1,
+                "maximum": MAX_LIMIT,
+                "description": "Number of log entries per page"
             },
             "level": {
                 "type": "string",
                 "description": "Filter logs by level (INFO, WARN, ERROR, DEBUG)",
                 "enum": ["INFO", "WARN", "ERROR", "DEBUG"]
+            },
+            "since": {
+                "type": "string",
+                "description": "Only include logs at or after this time: RFC 3339 timestamp or relative duration such as 15m, 1h, 7d"
+            },
+            "until": {
+                "type": "string",
+                "description": "Only include logs before this time: RFC 3339 timestamp or relative duration such as 15m, 1h, 7d"
+            },
+            "module": {
+                "type": "string",
+                "description": "Filter logs by module name"
+            },
+            "module_match": {
+                "type": "string",
+                "enum": ["exact", "prefix"],
+                "default": "exact",
+                "description": "Match the module exactly or as a prefix"
+            },
+            "user_id": {
+                "type": "string",
+                "description": "Filter logs by user ID"
+            },
+            "session_id": {
+                "type": "string",
+                "description": "Filter logs by session ID"
+            },
+            "context_id": {
+                "type": "string",
+                "description": "Filter logs by conversation context ID"
+            },
+            "search": {
+                "type": "string",
+                "description": "Text to search for in log messages"
+            },
+            "search_mode": {
+                "type": "string",
+                "enum": ["substring", "full_text"],
+                "default": "substring",
+                "description": "Case-insensitive substring match or PostgreSQL full-text search"
             }
         }
     })
@@ -54,23 +109,33 @@ pub async fn handle_logs(
 ) -> Result<CallToolResult, McpError> {
     let args = request.arguments.unwrap_or_default();
 
-    let page = args
-        .get("page")
-        .and_then(serde_json::Value::as_i64)
-        .unwrap_or(0) as i32;
+    let cursor = parse_cursor_arg(&args)?;
     let limit = args
         .get("limit")
         .and_then(serde_json::Value::as_i64)
-        .unwrap_or(1000) as i32;
-    let level = args.get("level").and_then(|v| v.as_str()).map(String::from);
-
-    tracing::debug!(page = page, limit = limit, level = ?level, "Fetching logs");
+        .unwrap_or(DEFAULT_LIMIT)
+        .clamp(1, MAX_LIMIT);
+    let filter = parse_log_filter(&args, chrono::Utc::now())
+        .map_err(|e| McpError::invalid_params(e, None))?;
 
     let repo = LogsRepository::new(pool.clone())
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
+    match args.get("mode").and_then(|v| v.as_str()) {
+        None | Some("entries") => {}
+        Some("clusters") => return handle_clusters(&repo, filter, mcp_execution_id).await,
+        Some(other) => {
+            return Err(McpError::invalid_params(
+                format!("Invalid mode: {other}. Expected entries or clusters"),
+                None,
+            ))
+        }
+    }
+
+    tracing::debug!(cursor = ?cursor, limit = limit, filter = ?filter, "Fetching logs");
+
     let mut dashboard = DashboardArtifact::new("System Logs")
-        .with_description("Latest system logs with pagination and server-side level filtering")
+        .with_description(format!("System logs matching {}", filter.describe()))
         .with_hints(
             DashboardHints::new()
                 .with_layout(LayoutMode::Vertical)
@@ -79,12 +144,12 @@ pub async fn handle_logs(
         );
 
     let logs = repo
-        .fetch_recent_logs(page, limit, level.as_deref())
+        .fetch_recent_logs(&filter, limit, cursor.as_ref())
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
     let stats = repo
-        .fetch_log_stats()
+        .fetch_log_stats(&filter)
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
@@ -92,7 +157,7 @@ pub async fn handle_logs(
         create_stats_section(&stats).map_err(|e| McpError::internal_error(e.to_string(), None))?,
     );
     dashboard = dashboard.add_section(
-        create_logs_table_section(&logs, page)
+        create_logs_table_section(&logs.items, cursor.is_some())
             .map_err(|e| McpError::internal_error(e.to_string(), None))?,
     );
 
@@ -107,12 +172,19 @@ pub async fn handle_logs(
 
     Ok(CallToolResult {
         content: vec![Content::text(format!(
-            "System Logs (Page {} of {}, {} per page)",
-            page,
-            (stats.total_logs as f64 / f64::from(limit)).ceil() as i32,
-            limit
+            "System Logs ({} of {} matching entries{})",
+            logs.items.len(),
+            stats.total_logs,
+            if logs.next_cursor.is_some() {
+                ", more available via next_cursor"
+            } else {
+                ""
+            }
         ))],
-        structured_content: Some(tool_response.to_json()),
+        structured_content: Some(with_next_cursor(
+            tool_response.to_json(),
+            logs.next_cursor.as_ref().map(Cursor::encode),
+        )),
         is_error: Some(false),
         meta: metadata.to_meta(),
     })
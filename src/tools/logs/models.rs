@@ -1,4 +1,6 @@
-#[derive(Debug)]
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, serde::Serialize)]
 #[allow(dead_code)]
 pub struct LogEntry {
     pub id: String,
@@ -11,7 +13,7 @@ pub struct LogEntry {
     pub context_id: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize)]
 #[allow(dead_code)]
 pub struct LogStats {
     pub total_logs: i64,
@@ -22,3 +24,100 @@ pub struct LogStats {
     pub unique_users: i64,
     pub last_log_time: Option<String>,
 }
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModuleMatch {
+    #[default]
+    Exact,
+    Prefix,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchMode {
+    #[default]
+    Substring,
+    FullText,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LogFilter {
+    pub level: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub module: Option<String>,
+    pub module_match: ModuleMatch,
+    pub user_id: Option<String>,
+    pub session_id: Option<String>,
+    pub context_id: Option<String>,
+    pub search: Option<String>,
+    pub search_mode: SearchMode,
+}
+
+impl LogFilter {
+    #[must_use]
+    pub fn with_level(level: &str) -> Self {
+        Self {
+            level: Some(level.to_string()),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(level) = &self.level {
+            parts.push(format!("level={level}"));
+        }
+        if let Some(since) = &self.since {
+            parts.push(format!("since={}", since.to_rfc3339()));
+        }
+        if let Some(until) = &self.until {
+            parts.push(format!("until={}", until.to_rfc3339()));
+        }
+        if let Some(module) = &self.module {
+            match self.module_match {
+                ModuleMatch::Exact => parts.push(format!("module={module}")),
+                ModuleMatch::Prefix => parts.push(format!("module={module}*")),
+            }
+        }
+        if let Some(user_id) = &self.user_id {
+            parts.push(format!("user_id={user_id}"));
+        }
+        if let Some(session_id) = &self.session_id {
+            parts.push(format!("session_id={session_id}"));
+        }
+        if let Some(context_id) = &self.context_id {
+            parts.push(format!("context_id={context_id}"));
+        }
+        if let Some(search) = &self.search {
+            parts.push(format!("search=\"{search}\""));
+        }
+
+        if parts.is_empty() {
+            "all logs".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogEvent {
+    pub timestamp: DateTime<Utc>,
+    pub level: String,
+    pub module: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ErrorCluster {
+    pub fingerprint: String,
+    pub sample_message: String,
+    pub level: String,
+    pub count: u32,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    pub modules: Vec<String>,
+    pub hourly: Vec<u32>,
+    pub growth: i64,
+}
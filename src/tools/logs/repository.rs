@@ -1,9 +1,12 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use std::sync::Arc;
 use systemprompt::database::DbPool;
 
-use super::models::{LogEntry, LogStats};
+use crate::tools::pagination::{Cursor, Page};
+
+use super::models::{LogEntry, LogEvent, LogFilter, LogStats, ModuleMatch, SearchMode};
 
 pub struct LogsRepository {
     pool: Arc<PgPool>,
@@ -17,17 +20,16 @@ impl LogsRepository {
 
     pub async fn fetch_recent_logs(
         &self,
-        page: i32,
-        limit: i32,
-        level: Option<&str>,
-    ) -> Result<Vec<LogEntry>> {
-        let offset = page * limit;
-
+        filter: &LogFilter,
+        limit: i64,
+        cursor: Option<&Cursor>,
+    ) -> Result<Page<LogEntry>> {
         let rows = sqlx::query!(
             r#"
             SELECT
                 id,
-                timestamp::text as timestamp,
+                timestamp as "sort_timestamp: DateTime<Utc>",
+                timestamp::text as "timestamp!",
                 level,
                 module,
                 message,
@@ -36,34 +38,154 @@ impl LogsRepository {
                 context_id
             FROM logs
             WHERE ($1::text IS NULL OR UPPER(level) = UPPER($1))
-            ORDER BY timestamp DESC
-            LIMIT $2 OFFSET $3
+              AND ($2::timestamptz IS NULL OR timestamp >= $2)
+              AND ($3::timestamptz IS NULL OR timestamp < $3)
+              AND ($4::text IS NULL OR (CASE WHEN $5::bool THEN starts_with(module, $4) ELSE module = $4 END))
+              AND ($6::text IS NULL OR user_id = $6)
+              AND ($7::text IS NULL OR session_id = $7)
+              AND ($8::text IS NULL OR context_id = $8)
+              AND ($9::text IS NULL OR (CASE
+                    WHEN $10::bool THEN to_tsvector('simple', message) @@ websearch_to_tsquery('simple', $9)
+                    ELSE strpos(LOWER(message), LOWER($9)) > 0
+                  END))
+              AND ($11::timestamptz IS NULL OR (timestamp, id) < ($11, $12::text))
+            ORDER BY timestamp DESC, id DESC
+            LIMIT $13
             "#,
-            level,
-            i64::from(limit),
-            i64::from(offset)
+            filter.level.as_deref(),
+            filter.since,
+            filter.until,
+            filter.module.as_deref(),
+            filter.module_match == ModuleMatch::Prefix,
+            filter.user_id.as_deref(),
+            filter.session_id.as_deref(),
+            filter.context_id.as_deref(),
+            filter.search.as_deref(),
+            filter.search_mode == SearchMode::FullText,
+            cursor.map(|c| c.timestamp),
+            cursor.map(|c| c.id.as_str()),
+            limit + 1
         )
         .fetch_all(&*self.pool)
         .await?;
 
-        let entries = rows
-            .into_iter()
-            .map(|row| LogEntry {
+        Ok(Page::from_rows(
+            rows,
+            limit as usize,
+            |row| Cursor::new(row.sort_timestamp, row.id.clone()),
+            |row| LogEntry {
                 id: row.id,
-                timestamp: row.timestamp.unwrap_or_default(),
+                timestamp: row.timestamp,
                 level: row.level,
                 module: row.module,
                 message: row.message,
                 user_id: row.user_id,
                 session_id: row.session_id,
                 context_id: row.context_id,
-            })
-            .collect();
+            },
+        ))
+    }
 
-        Ok(entries)
+    pub async fn fetch_log_by_id(&self, id: &str) -> Result<Option<LogEntry>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                id,
+                timestamp::text as "timestamp!",
+                level,
+                module,
+                message,
+                user_id,
+                session_id,
+                context_id
+            FROM logs
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        Ok(row.map(|row| LogEntry {
+            id: row.id,
+            timestamp: row.timestamp,
+            level: row.level,
+            module: row.module,
+            message: row.message,
+            user_id: row.user_id,
+            session_id: row.session_id,
+            context_id: row.context_id,
+        }))
     }
 
-    pub async fn fetch_log_stats(&self) -> Result<LogStats> {
+    pub async fn fetch_latest_error_timestamp(&self) -> Result<Option<DateTime<Utc>>> {
+        let latest = sqlx::query_scalar!(
+            r#"
+            SELECT MAX(timestamp) as "latest: DateTime<Utc>"
+            FROM logs
+            WHERE level = 'ERROR'
+            "#
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        Ok(latest)
+    }
+
+    pub async fn fetch_problem_events(
+        &self,
+        filter: &LogFilter,
+        max_rows: i64,
+    ) -> Result<Vec<LogEvent>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                timestamp as "timestamp: DateTime<Utc>",
+                level,
+                module,
+                message
+            FROM logs
+            WHERE (CASE WHEN $1::text IS NULL THEN UPPER(level) IN ('ERROR', 'WARN') ELSE UPPER(level) = UPPER($1) END)
+              AND ($2::timestamptz IS NULL OR timestamp >= $2)
+              AND ($3::timestamptz IS NULL OR timestamp < $3)
+              AND ($4::text IS NULL OR (CASE WHEN $5::bool THEN starts_with(module, $4) ELSE module = $4 END))
+              AND ($6::text IS NULL OR user_id = $6)
+              AND ($7::text IS NULL OR session_id = $7)
+              AND ($8::text IS NULL OR context_id = $8)
+              AND ($9::text IS NULL OR (CASE
+                    WHEN $10::bool THEN to_tsvector('simple', message) @@ websearch_to_tsquery('simple', $9)
+                    ELSE strpos(LOWER(message), LOWER($9)) > 0
+                  END))
+            ORDER BY timestamp DESC
+            LIMIT $11
+            "#,
+            filter.level.as_deref(),
+            filter.since,
+            filter.until,
+            filter.module.as_deref(),
+            filter.module_match == ModuleMatch::Prefix,
+            filter.user_id.as_deref(),
+            filter.session_id.as_deref(),
+            filter.context_id.as_deref(),
+            filter.search.as_deref(),
+            filter.search_mode == SearchMode::FullText,
+            max_rows
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| LogEvent {
+                timestamp: row.timestamp,
+                level: row.level,
+                module: row.module,
+                message: row.message,
+            })
+            .collect())
+    }
+
+    pub async fn fetch_log_stats(&self, filter: &LogFilter) -> Result<LogStats> {
         let row = sqlx::query!(
             r#"
             SELECT
@@ -75,7 +197,28 @@ impl LogsRepository {
                 COUNT(DISTINCT user_id) as unique_users,
                 MAX(timestamp)::text as last_log_time
             FROM logs
-            "#
+            WHERE ($1::text IS NULL OR UPPER(level) = UPPER($1))
+              AND ($2::timestamptz IS NULL OR timestamp >= $2)
+              AND ($3::timestamptz IS NULL OR timestamp < $3)
+              AND ($4::text IS NULL OR (CASE WHEN $5::bool THEN starts_with(module, $4) ELSE module = $4 END))
+              AND ($6::text IS NULL OR user_id = $6)
+              AND ($7::text IS NULL OR session_id = $7)
+              AND ($8::text IS NULL OR context_id = $8)
+              AND ($9::text IS NULL OR (CASE
+                    WHEN $10::bool THEN to_tsvector('simple', message) @@ websearch_to_tsquery('simple', $9)
+                    ELSE strpos(LOWER(message), LOWER($9)) > 0
+                  END))
+            "#,
+            filter.level.as_deref(),
+            filter.since,
+            filter.until,
+            filter.module.as_deref(),
+            filter.module_match == ModuleMatch::Prefix,
+            filter.user_id.as_deref(),
+            filter.session_id.as_deref(),
+            filter.context_id.as_deref(),
+            filter.search.as_deref(),
+            filter.search_mode == SearchMode::FullText,
         )
         .fetch_one(&*self.pool)
         .await?;
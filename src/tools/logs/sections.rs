@@ -1,17 +1,22 @@
+use chrono::{DateTime, Utc};
 use serde_json::json;
 use systemprompt::models::artifacts::{
-    Column, ColumnType, DashboardSection, LayoutWidth, SectionLayout, SectionType, TableArtifact,
-    TableHints,
+    ChartDataset, ChartSectionData, Column, ColumnType, DashboardSection, LayoutWidth,
+    SectionLayout, SectionType, TableArtifact, TableHints,
 };
 
-use super::models::{LogEntry, LogStats};
+use super::clusters::{bucket_labels, sparkline};
+use super::models::{ErrorCluster, LogEntry, LogStats};
+
+const CHART_CLUSTER_LIMIT: usize = 5;
+const CHART_LABEL_LENGTH: usize = 60;
 
 pub fn create_stats_section(stats: &LogStats) -> Result<DashboardSection, serde_json::Error> {
     let cards = vec![
         json!({
             "title": "Total Logs",
             "value": stats.total_logs.to_string(),
-            "subtitle": "all logs in system",
+            "subtitle": "logs matching filters",
             "icon": "logs",
             "status": "info"
         }),
@@ -50,7 +55,7 @@ pub fn create_stats_section(stats: &LogStats) -> Result<DashboardSection, serde_
 
 pub fn create_logs_table_section(
     logs: &[LogEntry],
-    page: i32,
+    is_continuation: bool,
 ) -> Result<DashboardSection, serde_json::Error> {
     let table = TableArtifact::new(vec![
         Column::new("timestamp", ColumnType::String).with_header("Time"),
@@ -87,7 +92,11 @@ pub fn create_logs_table_section(
 
     Ok(DashboardSection::new(
         "recent_logs",
-        format!("Recent Logs (Page {})", page + 1),
+        if is_continuation {
+            "Recent Logs (continued)"
+        } else {
+            "Recent Logs"
+        },
         SectionType::Table,
     )
     .with_data(table.to_response())?
@@ -97,6 +106,92 @@ pub fn create_logs_table_section(
     }))
 }
 
+pub fn create_cluster_chart_section(
+    clusters: &[ErrorCluster],
+    since: DateTime<Utc>,
+    buckets: usize,
+) -> Result<DashboardSection, serde_json::Error> {
+    let datasets: Vec<ChartDataset> = clusters
+        .iter()
+        .take(CHART_CLUSTER_LIMIT)
+        .map(|cluster| {
+            let label: String = cluster
+                .fingerprint
+                .chars()
+                .take(CHART_LABEL_LENGTH)
+                .collect();
+            let data: Vec<f64> = cluster.hourly.iter().map(|&c| f64::from(c)).collect();
+            ChartDataset::new(label, data)
+        })
+        .collect();
+
+    let chart_data = ChartSectionData::new("line", bucket_labels(since, buckets), datasets);
+
+    Ok(DashboardSection::new(
+        "error_cluster_trends",
+        "Fastest Growing Errors (hourly)",
+        SectionType::Chart,
+    )
+    .with_data(json!(chart_data))?
+    .with_layout(SectionLayout {
+        width: LayoutWidth::Full,
+        order: 2,
+    }))
+}
+
+pub fn create_clusters_table_section(
+    clusters: &[ErrorCluster],
+) -> Result<DashboardSection, serde_json::Error> {
+    let table = TableArtifact::new(vec![
+        Column::new("fingerprint", ColumnType::String).with_header("Fingerprint"),
+        Column::new("level", ColumnType::String).with_header("Level"),
+        Column::new("count", ColumnType::Integer).with_header("Count"),
+        Column::new("growth", ColumnType::Integer).with_header("Growth"),
+        Column::new("trend", ColumnType::String).with_header("Hourly Trend"),
+        Column::new("first_seen", ColumnType::String).with_header("First Seen"),
+        Column::new("last_seen", ColumnType::String).with_header("Last Seen"),
+        Column::new("modules", ColumnType::String).with_header("Modules"),
+        Column::new("sample_message", ColumnType::String).with_header("Example"),
+    ])
+    .with_rows(
+        clusters
+            .iter()
+            .map(|cluster| {
+                json!({
+                    "fingerprint": cluster.fingerprint,
+                    "level": cluster.level,
+                    "count": cluster.count,
+                    "growth": cluster.growth,
+                    "trend": sparkline(&cluster.hourly),
+                    "first_seen": cluster.first_seen.format("%b %d, %Y %H:%M:%S").to_string(),
+                    "last_seen": cluster.last_seen.format("%b %d, %Y %H:%M:%S").to_string(),
+                    "modules": cluster.modules.join(", "),
+                    "sample_message": cluster.sample_message,
+                })
+            })
+            .collect(),
+    )
+    .with_hints(
+        TableHints::new()
+            .with_sortable(vec![
+                "count".to_string(),
+                "growth".to_string(),
+                "first_seen".to_string(),
+                "last_seen".to_string(),
+            ])
+            .filterable(),
+    );
+
+    Ok(
+        DashboardSection::new("error_clusters", "Error Clusters", SectionType::Table)
+            .with_data(table.to_response())?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 3,
+            }),
+    )
+}
+
 fn format_timestamp_as_readable(timestamp: &str) -> String {
     chrono::DateTime::parse_from_rfc3339(timestamp).map_or_else(
         |_| timestamp.to_string(),
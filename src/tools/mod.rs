@@ -8,21 +8,36 @@ use systemprompt::database::DbPool;
 use systemprompt::identifiers::McpExecutionId;
 use systemprompt::system::AppContext;
 
+pub mod audit;
+pub mod confirmation;
 pub mod content;
 pub mod conversations;
+pub mod health;
 pub mod jobs;
 pub mod logs;
 pub mod operations;
+pub mod pagination;
+pub mod permissions;
+pub mod roles;
+pub mod sessions;
+pub mod state;
 pub mod traffic;
+pub mod undo;
 pub mod users;
 
+pub use audit::{audit_input_schema, audit_output_schema, handle_audit};
 pub use content::{content_input_schema, content_output_schema, handle_content};
 pub use conversations::{
     conversations_input_schema, conversations_output_schema, handle_conversations,
 };
+pub use health::{handle_health, health_input_schema, health_output_schema};
 pub use jobs::{handle_jobs, jobs_input_schema, jobs_output_schema};
 pub use logs::{handle_logs, logs_input_schema, logs_output_schema};
 pub use operations::{handle_operations, operations_input_schema, operations_output_schema};
+pub use permissions::Caller;
+pub use roles::{handle_roles, roles_input_schema, roles_output_schema};
+pub use sessions::{handle_sessions, sessions_input_schema, sessions_output_schema};
+pub use state::ToolState;
 pub use traffic::{handle_traffic, traffic_input_schema, traffic_output_schema};
 pub use users::{handle_users, users_input_schema, users_input_schema_with_roles, users_output_schema};
 
@@ -41,10 +56,19 @@ pub fn register_tools_with_roles(role_names: &[String]) -> Vec<Tool> {
 
     vec![
         create_tool("user", "User Management",
-            "Manage users, sessions, roles, and user activity.",
+            "Manage users: list, search and inspect users with their sessions and activity; create and invite users; assign or remove roles; suspend, reactivate, delete and restore; and apply bulk operations with a dry-run preview.",
             user_schema, users_output_schema()),
+        create_tool("roles", "Role Inventory",
+            "Every discovered role with its source (core or extension), permissions and the number of users holding it. Flags orphaned roles held by users but declared in no manifest, and declared roles nobody holds.",
+            roles_input_schema(), roles_output_schema()),
+        create_tool("audit", "Audit Trail",
+            "Audit records of administrative changes: role changes, user deletes and bulk operations, file and content deletes, session ends and traffic flags. Each record has the actor, tool, action, target, before/after snapshot, arguments and result. Filter by actor_id, target_id, tool, action and time range.",
+            audit_input_schema(), audit_output_schema()),
+        create_tool("sessions", "Session Management",
+            "Inspect and manage user sessions: list active or ended sessions filtered by user and traffic type, show one session with its timeline of analytics events and logs, mark one session or all of a user's sessions ended (issued access tokens are not revoked), and mark a session as bot, scanner or human.",
+            sessions_input_schema(), sessions_output_schema()),
         create_tool("traffic", "Traffic Analytics",
-            "Website traffic metrics: sessions, requests, unique visitors, device breakdown, geolocation, and client analysis.",
+            "Website traffic metrics: sessions, requests, unique visitors, device breakdown, geolocation, and client analysis. Use a 7d/30d/90d time_range or custom start/end dates in a timezone, and compare_to the previous period or previous year for deltas on every summary card. Includes zero-filled hourly, daily or weekly charts of sessions, requests, unique users and AI cost, plus top landing and exit pages and common two- and three-step navigation paths. Set include to 'bots' for a report of the excluded bot and scanner traffic by user agent, country and endpoint.",
             traffic_input_schema(), traffic_output_schema()),
         create_tool("content", "Content Analytics",
             "Content performance metrics: top articles, category performance, engagement scores, and content trends.",
@@ -53,13 +77,16 @@ pub fn register_tools_with_roles(role_names: &[String]) -> Vec<Tool> {
             "Conversation metrics and details. Call with context_id to get full message history, or without to get analytics: total conversations, messages, success rates, breakdown by agent and status.",
             conversations_input_schema(), conversations_output_schema()),
         create_tool("logs", "System Logs",
-            "System logs and error analysis: recent errors, error trends, and detailed error information.",
+            "System logs and error analysis. Filter by level, time window, module, user, session, context or message text. Use mode=clusters to group recurring errors into fingerprints with hourly trends.",
             logs_input_schema(), logs_output_schema()),
         create_tool("jobs", "Scheduler Jobs",
             "List all scheduler jobs and execute them manually. Call without parameters to list jobs, or with execute_job to trigger a job.",
             jobs_input_schema(), jobs_output_schema()),
+        create_tool("health", "System Health",
+            "System health check: database connectivity and latency, connection pool utilisation, largest tables, log volume, failing or overdue scheduler jobs, and host memory and disk usage, summarised as a green/amber/red status.",
+            health_input_schema(), health_output_schema()),
         create_tool("operations", "Administrative Operations",
-            "Administrative operations for files and content. Actions: list_files (list all files), delete_file (delete file by UUID), delete_content (delete content by UUID).",
+            "Administrative operations for files and content. Actions: list_files (list all files), delete_file (delete file by UUID), delete_content (delete content by UUID), validate_skills, validate_agents and validate_config (check configuration), undo (reverse a role change, status change, user delete, file delete or content delete with the undo_token it returned, before the token expires).",
             operations_input_schema(), operations_output_schema()),
     ]
 }
@@ -70,16 +97,21 @@ pub async fn handle_tool_call(
     ctx: RequestContext<RoleServer>,
     db_pool: &DbPool,
     app_context: &Arc<AppContext>,
+    state: &ToolState,
     mcp_execution_id: &McpExecutionId,
 ) -> Result<CallToolResult, McpError> {
     match name {
-        "user" => handle_users(db_pool, request, ctx, mcp_execution_id).await,
+        "user" => handle_users(db_pool, request, ctx, state, mcp_execution_id).await,
+        "roles" => handle_roles(db_pool, request, ctx, state, mcp_execution_id).await,
+        "audit" => handle_audit(db_pool, request, ctx, mcp_execution_id).await,
+        "sessions" => handle_sessions(db_pool, request, ctx, state, mcp_execution_id).await,
         "traffic" => handle_traffic(db_pool, request, ctx, mcp_execution_id).await,
         "content" => handle_content(db_pool, request, ctx, mcp_execution_id).await,
         "conversations" => handle_conversations(db_pool, request, ctx, mcp_execution_id).await,
         "logs" => handle_logs(db_pool, request, ctx, mcp_execution_id).await,
         "jobs" => handle_jobs(db_pool, request, ctx, app_context.clone(), mcp_execution_id).await,
-        "operations" => handle_operations(db_pool, request, ctx, mcp_execution_id).await,
+        "health" => handle_health(db_pool, request, ctx, mcp_execution_id).await,
+        "operations" => handle_operations(db_pool, request, ctx, state, mcp_execution_id).await,
         _ => {
             tracing::warn!(tool = %name, "Unknown tool");
             Err(McpError::method_not_found::<CallToolRequestMethod>())
@@ -99,6 +131,20 @@ pub fn list_tools_with_roles(role_names: &[String]) -> Result<ListToolsResult, M
     })
 }
 
+pub fn list_tools_for_caller(
+    role_names: &[String],
+    caller: &Caller,
+) -> Result<ListToolsResult, McpError> {
+    Ok(ListToolsResult {
+        tools: register_tools_with_roles(role_names)
+            .into_iter()
+            .filter(|tool| caller.can_use_tool(&tool.name))
+            .collect(),
+        next_cursor: None,
+        meta: None,
+    })
+}
+
 fn create_tool(
     name: &str,
     title: &str,
@@ -0,0 +1,205 @@
+use rmcp::{
+    model::{CallToolResult, Content},
+    ErrorData as McpError, Peer, RoleServer,
+};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use sqlx::types::Uuid;
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::{ArtifactId, McpExecutionId};
+use systemprompt::models::artifacts::{
+    DashboardArtifact, DashboardHints, DashboardSection, ExecutionMetadata, LayoutMode,
+    LayoutWidth, SectionLayout, SectionType, ToolResponse,
+};
+
+use crate::tools::confirmation::models::DeletionPreview;
+use crate::tools::confirmation::{confirm_deletion, Confirmation};
+use crate::tools::undo::attach_undo;
+use crate::tools::ToolState;
+
+use super::repository::{ContentListing, FileListing, OperationsRepository};
+
+fn operations_repo(pool: &DbPool) -> Result<OperationsRepository, McpError> {
+    OperationsRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))
+}
+
+pub async fn handle_delete_file(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    peer: &Peer<RoleServer>,
+    state: &ToolState,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let uuid_str = args
+        .get("uuid")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::invalid_params("uuid is required for delete_file action", None))?;
+
+    let uuid = Uuid::parse_str(uuid_str)
+        .map_err(|e| McpError::invalid_params(format!("Invalid UUID: {e}"), None))?;
+
+    let file = operations_repo(pool)?
+        .find_file(uuid)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .ok_or_else(|| {
+            McpError::invalid_params(
+                format!("File not found or already deleted: {uuid_str}"),
+                None,
+            )
+        })?;
+    let preview = file_preview(&file);
+    if let Confirmation::Respond(result) =
+        confirm_deletion(pool, peer, state, args, &preview, mcp_execution_id).await?
+    {
+        return Ok(result);
+    }
+
+    tracing::debug!(uuid = %uuid_str, "Deleting file");
+
+    let undo = state.issue_undo()?;
+    let deleted = operations_repo(pool)?
+        .delete_file(
+            uuid,
+            &state.audit_scope("operations", "delete_file", args),
+            &undo,
+        )
+        .await
+        .map_err(|e| McpError::internal_error(format!("Failed to delete file: {e}"), None))?;
+    if !deleted {
+        return Err(McpError::invalid_params(
+            format!("File not found or already deleted: {uuid_str}"),
+            None,
+        ));
+    }
+
+    tracing::info!(uuid = %uuid_str, path = %file.path, actor_id = %state.caller.user_id, "File deleted");
+
+    build_status_response("File Deleted", uuid_str, "trash-2", mcp_execution_id)
+        .map(|result| attach_undo(result, &undo))
+}
+
+pub async fn handle_delete_content(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    peer: &Peer<RoleServer>,
+    state: &ToolState,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let uuid_str = args.get("uuid").and_then(|v| v.as_str()).ok_or_else(|| {
+        McpError::invalid_params("uuid is required for delete_content action", None)
+    })?;
+
+    let content = operations_repo(pool)?
+        .find_content(uuid_str)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .ok_or_else(|| McpError::invalid_params(format!("Content not found: {uuid_str}"), None))?;
+    let preview = content_preview(&content);
+    if let Confirmation::Respond(result) =
+        confirm_deletion(pool, peer, state, args, &preview, mcp_execution_id).await?
+    {
+        return Ok(result);
+    }
+
+    tracing::debug!(uuid = %uuid_str, "Deleting content");
+
+    let undo = state.issue_undo()?;
+    let deleted = operations_repo(pool)?
+        .delete_content(
+            uuid_str,
+            &state.audit_scope("operations", "delete_content", args),
+            &undo,
+        )
+        .await
+        .map_err(|e| McpError::internal_error(format!("Failed to delete content: {e}"), None))?;
+    if !deleted {
+        return Err(McpError::invalid_params(
+            format!("Content not found: {uuid_str}"),
+            None,
+        ));
+    }
+
+    tracing::info!(uuid = %uuid_str, title = %content.title, actor_id = %state.caller.user_id, "Content deleted");
+
+    build_status_response("Content Deleted", uuid_str, "trash-2", mcp_execution_id)
+        .map(|result| attach_undo(result, &undo))
+}
+
+fn file_preview(file: &FileListing) -> DeletionPreview {
+    DeletionPreview::new(
+        "operations",
+        "delete_file",
+        file.id.to_string(),
+        format!("file {}", file.path),
+    )
+    .with_field("ID", file.id.to_string())
+    .with_field("Path", &file.path)
+    .with_field("URL", &file.public_url)
+    .with_field("Type", &file.mime_type)
+    .with_field(
+        "Size",
+        file.size_bytes
+            .map_or_else(|| "unknown".to_string(), |size| format!("{size} bytes")),
+    )
+    .with_field("Created", file.created_at.to_rfc3339())
+}
+
+fn content_preview(content: &ContentListing) -> DeletionPreview {
+    DeletionPreview::new(
+        "operations",
+        "delete_content",
+        &content.id,
+        format!("content '{}'", content.title),
+    )
+    .with_field("ID", &content.id)
+    .with_field("Title", &content.title)
+    .with_field("Slug", &content.slug)
+    .with_field("Source", content.source_id.as_deref().unwrap_or("none"))
+    .with_field(
+        "Published",
+        content.published_at.as_deref().unwrap_or("unpublished"),
+    )
+}
+
+pub(super) fn build_status_response(
+    title: &str,
+    uuid_str: &str,
+    icon: &str,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let section = DashboardSection::new("status", "Status", SectionType::MetricsCards)
+        .with_data(json!({
+            "cards": [{
+                "title": title,
+                "value": &uuid_str[..8.min(uuid_str.len())],
+                "icon": icon,
+                "status": "success"
+            }]
+        }))
+        .map_err(|e| McpError::internal_error(format!("Failed to serialize section: {e}"), None))?
+        .with_layout(SectionLayout {
+            width: LayoutWidth::Full,
+            order: 1,
+        });
+
+    let dashboard = DashboardArtifact::new(title)
+        .with_hints(DashboardHints::new().with_layout(LayoutMode::Vertical))
+        .add_section(section);
+
+    let metadata = ExecutionMetadata::new().tool("operations");
+    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
+    let tool_response = ToolResponse::new(
+        artifact_id,
+        mcp_execution_id.clone(),
+        dashboard,
+        metadata.clone(),
+    );
+
+    Ok(CallToolResult {
+        content: vec![Content::text(format!("{title}: {uuid_str}"))],
+        structured_content: Some(tool_response.to_json()),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
@@ -1,10 +1,12 @@
+mod deletes;
+pub mod repository;
 mod schema;
+mod undo;
 mod validation;
 
 pub use schema::{operations_input_schema, operations_output_schema};
 pub use validation::{handle_validate_agents, handle_validate_config, handle_validate_skills};
 
-use chrono::Utc;
 use rmcp::{
     model::{CallToolRequestParam, CallToolResult, Content},
     service::RequestContext,
@@ -12,19 +14,23 @@ use rmcp::{
 };
 use serde_json::{json, Value as JsonValue};
 use sqlx::types::Uuid;
-use systemprompt::content::repository::ContentRepository;
 use systemprompt::database::DbPool;
-use systemprompt::files::repository::FileRepository;
-use systemprompt::identifiers::{ArtifactId, ContentId, McpExecutionId};
+use systemprompt::identifiers::{ArtifactId, McpExecutionId};
 use systemprompt::models::artifacts::{
-    Column, ColumnType, DashboardArtifact, DashboardHints, DashboardSection, ExecutionMetadata,
-    LayoutMode, LayoutWidth, SectionLayout, SectionType, TableArtifact, ToolResponse,
+    Column, ColumnType, ExecutionMetadata, TableArtifact, ToolResponse,
 };
 
+use crate::tools::pagination::{parse_cursor_arg, with_next_cursor, Cursor};
+use crate::tools::ToolState;
+use deletes::{handle_delete_content, handle_delete_file};
+use repository::OperationsRepository;
+use undo::handle_undo;
+
 pub async fn handle_operations(
     pool: &DbPool,
     request: CallToolRequestParam,
-    _ctx: RequestContext<RoleServer>,
+    ctx: RequestContext<RoleServer>,
+    state: &ToolState,
     mcp_execution_id: &McpExecutionId,
 ) -> Result<CallToolResult, McpError> {
     let args = request.arguments.unwrap_or_default();
@@ -36,14 +42,19 @@ pub async fn handle_operations(
 
     match action {
         "list_files" => handle_list_files(pool, &args, mcp_execution_id).await,
-        "delete_file" => handle_delete_file(pool, &args, mcp_execution_id).await,
-        "delete_content" => handle_delete_content(pool, &args, mcp_execution_id).await,
+        "delete_file" => {
+            handle_delete_file(pool, &args, &ctx.peer, state, mcp_execution_id).await
+        }
+        "delete_content" => {
+            handle_delete_content(pool, &args, &ctx.peer, state, mcp_execution_id).await
+        }
         "validate_skills" => handle_validate_skills(&args, mcp_execution_id).await,
         "validate_agents" => handle_validate_agents(&args, mcp_execution_id).await,
         "validate_config" => handle_validate_config(&args, mcp_execution_id).await,
+        "undo" => handle_undo(pool, &args, state, mcp_execution_id).await,
         _ => Err(McpError::invalid_params(
             format!(
-                "Unknown action: {action}. Valid actions: list_files, delete_file, delete_content, validate_skills, validate_agents, validate_config"
+                "Unknown action: {action}. Valid actions: list_files, delete_file, delete_content, validate_skills, validate_agents, validate_config, undo"
             ),
             None,
         )),
@@ -58,25 +69,28 @@ async fn handle_list_files(
     let limit = args
         .get("limit")
         .and_then(serde_json::Value::as_i64)
-        .unwrap_or(100);
-    let offset = args
-        .get("offset")
-        .and_then(serde_json::Value::as_i64)
-        .unwrap_or(0);
+        .unwrap_or(100)
+        .clamp(1, 1000);
+    let cursor = parse_cursor_arg(args)?;
+    if let Some(cursor) = &cursor {
+        Uuid::parse_str(&cursor.id)
+            .map_err(|_| McpError::invalid_params("Invalid cursor for list_files", None))?;
+    }
 
-    tracing::debug!(limit = limit, offset = offset, "Listing files");
+    tracing::debug!(limit = limit, cursor = ?cursor, "Listing files");
 
-    let file_repo =
-        FileRepository::new(pool).map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let repo = OperationsRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-    let files = file_repo
-        .list_all(limit, offset)
+    let files = repo
+        .list_files(limit, cursor.as_ref())
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-    tracing::debug!(count = files.len(), "Files listed");
+    tracing::debug!(count = files.items.len(), "Files listed");
 
     let items: Vec<JsonValue> = files
+        .items
         .iter()
         .map(|f| {
             json!({
@@ -117,107 +131,19 @@ async fn handle_list_files(
 
     Ok(CallToolResult {
         content: vec![Content::text(format!(
-            "Found {} files\n\n{}",
-            files.len(),
+            "Found {} files{}\n\n{}",
+            files.items.len(),
+            if files.next_cursor.is_some() {
+                " (more available via next_cursor)"
+            } else {
+                ""
+            },
             serde_json::to_string_pretty(&items).unwrap_or_default()
         ))],
-        structured_content: Some(tool_response.to_json()),
-        is_error: Some(false),
-        meta: metadata.to_meta(),
-    })
-}
-
-async fn handle_delete_file(
-    pool: &DbPool,
-    args: &serde_json::Map<String, JsonValue>,
-    mcp_execution_id: &McpExecutionId,
-) -> Result<CallToolResult, McpError> {
-    let uuid_str = args
-        .get("uuid")
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| McpError::invalid_params("uuid is required for delete_file action", None))?;
-
-    let uuid = Uuid::parse_str(uuid_str)
-        .map_err(|e| McpError::invalid_params(format!("Invalid UUID: {e}"), None))?;
-
-    tracing::debug!(uuid = %uuid_str, "Deleting file");
-
-    let pg_pool = pool
-        .pool_arc()
-        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
-
-    let now = Utc::now();
-    sqlx::query!("UPDATE files SET deleted_at = $1 WHERE id = $2", now, uuid)
-        .execute(&*pg_pool)
-        .await
-        .map_err(|e| McpError::internal_error(format!("Failed to delete file: {e}"), None))?;
-
-    tracing::debug!(uuid = %uuid_str, "File deleted");
-
-    build_delete_response("File Deleted", uuid_str, mcp_execution_id)
-}
-
-async fn handle_delete_content(
-    pool: &DbPool,
-    args: &serde_json::Map<String, JsonValue>,
-    mcp_execution_id: &McpExecutionId,
-) -> Result<CallToolResult, McpError> {
-    let uuid_str = args.get("uuid").and_then(|v| v.as_str()).ok_or_else(|| {
-        McpError::invalid_params("uuid is required for delete_content action", None)
-    })?;
-
-    tracing::debug!(uuid = %uuid_str, "Deleting content");
-
-    let content_repo = ContentRepository::new(pool).map_err(|e| {
-        McpError::internal_error(format!("Failed to create content repo: {e}"), None)
-    })?;
-    let content_id = ContentId::new(uuid_str);
-    content_repo
-        .delete(&content_id)
-        .await
-        .map_err(|e| McpError::internal_error(format!("Failed to delete content: {e}"), None))?;
-
-    tracing::debug!(uuid = %uuid_str, "Content deleted");
-
-    build_delete_response("Content Deleted", uuid_str, mcp_execution_id)
-}
-
-fn build_delete_response(
-    title: &str,
-    uuid_str: &str,
-    mcp_execution_id: &McpExecutionId,
-) -> Result<CallToolResult, McpError> {
-    let section = DashboardSection::new("status", "Status", SectionType::MetricsCards)
-        .with_data(json!({
-            "cards": [{
-                "title": title,
-                "value": &uuid_str[..8.min(uuid_str.len())],
-                "icon": "trash-2",
-                "status": "success"
-            }]
-        }))
-        .map_err(|e| McpError::internal_error(format!("Failed to serialize section: {e}"), None))?
-        .with_layout(SectionLayout {
-            width: LayoutWidth::Full,
-            order: 1,
-        });
-
-    let dashboard = DashboardArtifact::new(title)
-        .with_hints(DashboardHints::new().with_layout(LayoutMode::Vertical))
-        .add_section(section);
-
-    let metadata = ExecutionMetadata::new().tool("operations");
-    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
-    let tool_response = ToolResponse::new(
-        artifact_id,
-        mcp_execution_id.clone(),
-        dashboard,
-        metadata.clone(),
-    );
-
-    Ok(CallToolResult {
-        content: vec![Content::text(format!("{title}: {uuid_str}"))],
-        structured_content: Some(tool_response.to_json()),
+        structured_content: Some(with_next_cursor(
+            tool_response.to_json(),
+            files.next_cursor.as_ref().map(Cursor::encode),
+        )),
         is_error: Some(false),
         meta: metadata.to_meta(),
     })
@@ -0,0 +1,192 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::types::Uuid;
+use sqlx::PgPool;
+use std::sync::Arc;
+use systemprompt::database::DbPool;
+
+use crate::tools::audit::models::AuditScope;
+use crate::tools::audit::repository::record_audit;
+use crate::tools::pagination::{Cursor, Page};
+use crate::tools::undo::content::{delete_content_on, snapshot_content_on};
+use crate::tools::undo::models::{IssuedUndo, UndoAction};
+use crate::tools::undo::repository::store_undo;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct FileListing {
+    pub id: Uuid,
+    pub path: String,
+    pub public_url: String,
+    pub mime_type: String,
+    pub size_bytes: Option<i64>,
+    pub ai_content: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+pub struct ContentListing {
+    pub id: String,
+    pub title: String,
+    pub slug: String,
+    pub source_id: Option<String>,
+    pub published_at: Option<String>,
+}
+
+pub struct OperationsRepository {
+    pool: Arc<PgPool>,
+}
+
+impl OperationsRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn list_files(
+        &self,
+        limit: i64,
+        cursor: Option<&Cursor>,
+    ) -> Result<Page<FileListing>> {
+        let after_id = cursor
+            .map(|c| Uuid::parse_str(&c.id))
+            .transpose()
+            .map_err(|_| anyhow::anyhow!("Invalid cursor for list_files"))?;
+
+        let rows = sqlx::query_as!(
+            FileListing,
+            r#"
+            SELECT id, path, public_url, mime_type, size_bytes, ai_content, created_at
+            FROM files
+            WHERE deleted_at IS NULL
+              AND ($1::timestamptz IS NULL OR (created_at, id) < ($1, $2::uuid))
+            ORDER BY created_at DESC, id DESC
+            LIMIT $3
+            "#,
+            cursor.map(|c| c.timestamp),
+            after_id,
+            limit + 1
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(Page::from_rows(
+            rows,
+            limit as usize,
+            |row| Cursor::new(row.created_at, row.id.to_string()),
+            |row| row,
+        ))
+    }
+
+    pub async fn list_user_files(&self, user_id: &str, limit: i64) -> Result<Vec<FileListing>> {
+        sqlx::query_as!(
+            FileListing,
+            r#"
+            SELECT id, path, public_url, mime_type, size_bytes, ai_content, created_at
+            FROM files
+            WHERE deleted_at IS NULL
+              AND user_id = $1
+            ORDER BY created_at DESC, id DESC
+            LIMIT $2
+            "#,
+            user_id,
+            limit
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn find_file(&self, id: Uuid) -> Result<Option<FileListing>> {
+        sqlx::query_as!(
+            FileListing,
+            r#"
+            SELECT id, path, public_url, mime_type, size_bytes, ai_content, created_at
+            FROM files
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn find_content(&self, id: &str) -> Result<Option<ContentListing>> {
+        sqlx::query_as!(
+            ContentListing,
+            r#"
+            SELECT
+                mc.id as "id!",
+                mc.title as "title!",
+                mc.slug as "slug!",
+                mc.source_id::text as source_id,
+                mc.published_at::text as published_at
+            FROM markdown_content mc
+            WHERE mc.id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn delete_file(
+        &self,
+        id: Uuid,
+        audit: &AuditScope,
+        undo: &IssuedUndo,
+    ) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        let Some(before) = sqlx::query_scalar!(
+            r#"SELECT to_jsonb(f) as "snapshot!" FROM files f WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"#,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        else {
+            return Ok(false);
+        };
+
+        let deleted_at = sqlx::query_scalar!(
+            "UPDATE files SET deleted_at = NOW() WHERE id = $1 RETURNING deleted_at",
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let entry = audit
+            .entry(id.to_string())
+            .with_change(before, serde_json::json!({ "deleted_at": deleted_at }));
+        record_audit(&mut tx, &entry).await?;
+        let restore = UndoAction::RestoreFile {
+            file_id: id.to_string(),
+        };
+        store_undo(&mut tx, undo, &entry, &restore).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    pub async fn delete_content(
+        &self,
+        id: &str,
+        audit: &AuditScope,
+        undo: &IssuedUndo,
+    ) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        let Some(snapshot) = snapshot_content_on(&mut tx, id).await? else {
+            return Ok(false);
+        };
+
+        let entry = audit.entry(id).with_before(snapshot["content"].clone());
+        record_audit(&mut tx, &entry).await?;
+        let restore = UndoAction::RestoreContent {
+            content_id: id.to_string(),
+            snapshot,
+        };
+        store_undo(&mut tx, undo, &entry, &restore).await?;
+        delete_content_on(&mut tx, id).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+}
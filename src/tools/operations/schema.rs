@@ -1,6 +1,8 @@
 use serde_json::{json, Value as JsonValue};
 use systemprompt::models::artifacts::{DashboardArtifact, ToolResponse};
 
+use crate::tools::pagination::cursor_schema;
+
 #[must_use]
 pub fn operations_input_schema() -> JsonValue {
     json!({
@@ -9,23 +11,27 @@ pub fn operations_input_schema() -> JsonValue {
         "properties": {
             "action": {
                 "type": "string",
-                "enum": ["list_files", "delete_file", "delete_content", "validate_skills", "validate_agents", "validate_config"],
-                "description": "Operation to perform: list_files, delete_file, delete_content, validate_skills, validate_agents, or validate_config"
+                "enum": ["list_files", "delete_file", "delete_content", "validate_skills", "validate_agents", "validate_config", "undo"],
+                "description": "Operation to perform: list_files, delete_file, delete_content, validate_skills, validate_agents, validate_config, or undo (reverts a file or content delete, user delete or role change using the token it returned)"
             },
             "uuid": {
                 "type": "string",
                 "description": "UUID of the resource to delete (required for delete_file and delete_content)"
             },
+            "confirmation_token": {
+                "type": "string",
+                "description": "Token from a delete_file or delete_content preview. When the server requires confirmation, the first call only returns a preview of what will be deleted; repeat the call with this token to delete"
+            },
+            "token": {
+                "type": "string",
+                "description": "Undo token returned by a destructive action (required for undo). Tokens are single-use and expire"
+            },
             "limit": {
                 "type": "integer",
                 "description": "Maximum number of files to return for list_files (default: 100)",
                 "default": 100
             },
-            "offset": {
-                "type": "integer",
-                "description": "Number of files to skip for pagination in list_files (default: 0)",
-                "default": 0
-            }
+            "cursor": cursor_schema()
         }
     })
 }
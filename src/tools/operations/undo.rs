@@ -0,0 +1,79 @@
+use chrono::Utc;
+use rmcp::{
+    model::{CallToolResult, Content},
+    ErrorData as McpError,
+};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::McpExecutionId;
+
+use crate::tools::undo::models::check_redeemable;
+use crate::tools::undo::repository::UndoRepository;
+use crate::tools::ToolState;
+
+use super::deletes::build_status_response;
+
+pub async fn handle_undo(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    state: &ToolState,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let token = args
+        .get("token")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| McpError::invalid_params("token is required for undo action", None))?;
+
+    let repo = UndoRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let record = repo
+        .find(token)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .ok_or_else(|| McpError::invalid_params("Unknown undo token", None))?;
+    check_redeemable(record.used_at, record.expires_at, Utc::now())
+        .map_err(|e| McpError::invalid_params(e, None))?;
+
+    state
+        .caller
+        .authorize_action(&record.tool, &record.action)
+        .map_err(|e| McpError::invalid_request(format!("Cannot undo: {e}"), None))?;
+
+    let restored = repo
+        .redeem(
+            token,
+            &record,
+            &state.audit_scope("operations", "undo", args),
+        )
+        .await
+        .map_err(|e| McpError::internal_error(format!("Failed to undo: {e}"), None))?;
+    if !restored {
+        return Err(McpError::invalid_request(
+            format!(
+                "Cannot undo {} {} on {}: the token was just used or the target changed since",
+                record.tool, record.action, record.target_id
+            ),
+            None,
+        ));
+    }
+
+    tracing::info!(
+        tool = %record.tool,
+        action = %record.action,
+        target_id = %record.target_id,
+        actor_id = %state.caller.user_id,
+        "Destructive operation undone"
+    );
+
+    let mut result =
+        build_status_response("Undone", &record.target_id, "rotate-ccw", mcp_execution_id)?;
+    result.content = vec![Content::text(format!(
+        "Undid {} {}: {}",
+        record.tool,
+        record.action,
+        record.undo.describe()
+    ))];
+    Ok(result)
+}
@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+use rmcp::ErrorData as McpError;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+const CURSOR_VERSION: &str = "v1";
+const SORT_CURSOR_VERSION: &str = "s1";
+const INVALID_CURSOR: &str = "Invalid cursor: pass back the next_cursor value unchanged";
+
+fn encode_parts(parts: &[&str]) -> String {
+    parts
+        .iter()
+        .map(|part| part.bytes().map(|b| format!("{b:02x}")).collect::<String>())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn decode_parts(encoded: &str, version: &str, count: usize) -> Result<Vec<String>, String> {
+    let parts = encoded
+        .split('.')
+        .map(|part| {
+            if part.len() % 2 != 0 || !part.is_ascii() {
+                return None;
+            }
+            let bytes = (0..part.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&part[i..i + 2], 16).ok())
+                .collect::<Option<Vec<u8>>>()?;
+            String::from_utf8(bytes).ok()
+        })
+        .collect::<Option<Vec<String>>>()
+        .ok_or_else(|| INVALID_CURSOR.to_string())?;
+
+    if parts.len() != count || parts[0] != version || parts[count - 1].is_empty() {
+        return Err(INVALID_CURSOR.to_string());
+    }
+    Ok(parts)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cursor {
+    pub timestamp: DateTime<Utc>,
+    pub id: String,
+}
+
+impl Cursor {
+    #[must_use]
+    pub fn new(timestamp: DateTime<Utc>, id: impl Into<String>) -> Self {
+        Self {
+            timestamp,
+            id: id.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn encode(&self) -> String {
+        encode_parts(&[
+            CURSOR_VERSION,
+            &self.timestamp.timestamp_micros().to_string(),
+            &self.id,
+        ])
+    }
+
+    pub fn decode(encoded: &str) -> Result<Self, String> {
+        let parts = decode_parts(encoded, CURSOR_VERSION, 3)?;
+        let timestamp = parts[1]
+            .parse()
+            .ok()
+            .and_then(DateTime::from_timestamp_micros)
+            .ok_or_else(|| INVALID_CURSOR.to_string())?;
+
+        Ok(Self::new(timestamp, parts[2].clone()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortCursor {
+    pub sort: String,
+    pub key: String,
+    pub id: String,
+}
+
+impl SortCursor {
+    #[must_use]
+    pub fn new(sort: impl Into<String>, key: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            sort: sort.into(),
+            key: key.into(),
+            id: id.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn encode(&self) -> String {
+        encode_parts(&[SORT_CURSOR_VERSION, &self.sort, &self.key, &self.id])
+    }
+
+    pub fn decode(encoded: &str) -> Result<Self, String> {
+        let parts = decode_parts(encoded, SORT_CURSOR_VERSION, 4)?;
+        Ok(Self::new(
+            parts[1].clone(),
+            parts[2].clone(),
+            parts[3].clone(),
+        ))
+    }
+}
+
+#[derive(Debug)]
+pub struct Page<T, C = Cursor> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<C>,
+}
+
+impl<T, C> Page<T, C> {
+    pub fn from_rows<R>(
+        rows: Vec<R>,
+        limit: usize,
+        cursor_of: impl Fn(&R) -> C,
+        map: impl FnMut(R) -> T,
+    ) -> Self {
+        let (rows, next_cursor) = split_page(rows, limit, cursor_of);
+        Self {
+            items: rows.into_iter().map(map).collect(),
+            next_cursor,
+        }
+    }
+}
+
+fn cursor_arg(args: &JsonMap<String, JsonValue>) -> Option<&str> {
+    args.get("cursor")
+        .and_then(JsonValue::as_str)
+        .filter(|v| !v.is_empty())
+}
+
+pub fn parse_cursor_arg(args: &JsonMap<String, JsonValue>) -> Result<Option<Cursor>, McpError> {
+    cursor_arg(args)
+        .map(|v| Cursor::decode(v).map_err(|e| McpError::invalid_params(e, None)))
+        .transpose()
+}
+
+pub fn parse_sort_cursor_arg(
+    args: &JsonMap<String, JsonValue>,
+    sort: &str,
+) -> Result<Option<SortCursor>, McpError> {
+    let Some(cursor) = cursor_arg(args)
+        .map(|v| SortCursor::decode(v).map_err(|e| McpError::invalid_params(e, None)))
+        .transpose()?
+    else {
+        return Ok(None);
+    };
+
+    if cursor.sort != sort {
+        return Err(McpError::invalid_params(
+            format!(
+                "Cursor was issued for sort '{}' but the request sorts by '{sort}'",
+                cursor.sort
+            ),
+            None,
+        ));
+    }
+    Ok(Some(cursor))
+}
+
+pub fn split_page<T, C>(
+    mut rows: Vec<T>,
+    limit: usize,
+    cursor_of: impl Fn(&T) -> C,
+) -> (Vec<T>, Option<C>) {
+    if rows.len() <= limit {
+        return (rows, None);
+    }
+    rows.truncate(limit);
+    let next = rows.last().map(cursor_of);
+    (rows, next)
+}
+
+#[must_use]
+pub fn with_next_cursor(mut structured: JsonValue, next: Option<String>) -> JsonValue {
+    if let Some(object) = structured.as_object_mut() {
+        object.insert(
+            "next_cursor".to_string(),
+            next.map_or(JsonValue::Null, JsonValue::String),
+        );
+    }
+    structured
+}
+
+#[must_use]
+pub fn cursor_schema() -> JsonValue {
+    serde_json::json!({
+        "type": "string",
+        "description": "Opaque cursor from a previous response's next_cursor to fetch the next page"
+    })
+}
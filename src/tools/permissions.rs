@@ -0,0 +1,201 @@
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+use crate::services::DiscoveredRole;
+
+pub const WILDCARD: &str = "*";
+
+struct ActionPermission {
+    tool: &'static str,
+    action: &'static str,
+    permission: &'static str,
+}
+
+const fn rule(
+    tool: &'static str,
+    action: &'static str,
+    permission: &'static str,
+) -> ActionPermission {
+    ActionPermission {
+        tool,
+        action,
+        permission,
+    }
+}
+
+const ACTION_PERMISSIONS: [ActionPermission; 30] = [
+    rule("user", "list", "admin.users.read"),
+    rule("user", "get", "admin.users.read"),
+    rule("user", "create", "admin.users.write"),
+    rule("user", "assign_role", "admin.users.write"),
+    rule("user", "remove_role", "admin.users.write"),
+    rule("user", "suspend", "admin.users.write"),
+    rule("user", "reactivate", "admin.users.write"),
+    rule("user", "restore", "admin.users.write"),
+    rule("user", "delete", "admin.users.delete"),
+    rule("user", "bulk", "admin.users.bulk"),
+    rule("sessions", "list", "admin.sessions.read"),
+    rule("sessions", "get", "admin.sessions.read"),
+    rule("sessions", "end", "admin.sessions.end"),
+    rule("sessions", "mark", "admin.sessions.write"),
+    rule("roles", "list", "admin.roles.read"),
+    rule("audit", "list", "admin.audit.read"),
+    rule("traffic", "report", "admin.analytics.read"),
+    rule("content", "report", "admin.analytics.read"),
+    rule("conversations", "report", "admin.conversations.read"),
+    rule("logs", "list", "admin.logs.read"),
+    rule("jobs", "list", "admin.jobs.read"),
+    rule("jobs", "execute", "admin.jobs.execute"),
+    rule("health", "report", "admin.system.read"),
+    rule("operations", "list_files", "admin.files.read"),
+    rule("operations", "delete_file", "admin.files.delete"),
+    rule("operations", "delete_content", "admin.content.delete"),
+    rule("operations", "validate_skills", "admin.config.read"),
+    rule("operations", "validate_agents", "admin.config.read"),
+    rule("operations", "validate_config", "admin.config.read"),
+    rule("operations", "undo", "admin.operations.undo"),
+];
+
+#[must_use]
+pub fn tool_action(tool: &str, args: &JsonMap<String, JsonValue>) -> String {
+    let action = args.get("action").and_then(JsonValue::as_str);
+    match tool {
+        "user" if action == Some("bulk") => args
+            .get("operation")
+            .and_then(JsonValue::as_str)
+            .map_or_else(
+                || "bulk".to_string(),
+                |operation| format!("bulk.{operation}"),
+            ),
+        "user" | "sessions" => action.unwrap_or("list").to_string(),
+        "operations" => action.unwrap_or("unspecified").to_string(),
+        "jobs" if args.get("execute_job").is_some() => "execute".to_string(),
+        "roles" | "audit" | "logs" | "jobs" => "list".to_string(),
+        _ => "report".to_string(),
+    }
+}
+
+#[must_use]
+pub fn required_permission(tool: &str, action: &str) -> Option<&'static str> {
+    ACTION_PERMISSIONS
+        .iter()
+        .find(|rule| rule.tool == tool && rule.action == action)
+        .map(|rule| rule.permission)
+}
+
+#[must_use]
+pub fn required_permissions(tool: &str, action: &str) -> Option<Vec<&'static str>> {
+    match action.split_once('.') {
+        Some((scope, operation)) => Some(vec![
+            required_permission(tool, scope)?,
+            required_permission(tool, operation)?,
+        ]),
+        None => required_permission(tool, action).map(|permission| vec![permission]),
+    }
+}
+
+#[must_use]
+pub fn tool_permissions(tool: &str) -> Vec<&'static str> {
+    let mut permissions: Vec<&'static str> = ACTION_PERMISSIONS
+        .iter()
+        .filter(|rule| rule.tool == tool)
+        .map(|rule| rule.permission)
+        .collect();
+    permissions.dedup();
+    permissions
+}
+
+#[must_use]
+pub fn permission_matches(granted: &str, required: &str) -> bool {
+    if granted == WILDCARD || granted == required {
+        return true;
+    }
+    granted
+        .strip_suffix(".*")
+        .and_then(|prefix| required.strip_prefix(prefix))
+        .is_some_and(|rest| rest.starts_with('.'))
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Caller {
+    pub user_id: String,
+    pub roles: Vec<String>,
+    pub permissions: Vec<String>,
+}
+
+impl Caller {
+    #[must_use]
+    pub fn new(
+        user_id: impl Into<String>,
+        roles: Vec<String>,
+        discovered: &[DiscoveredRole],
+    ) -> Self {
+        let mut permissions: Vec<String> = discovered
+            .iter()
+            .filter(|role| roles.contains(&role.name))
+            .flat_map(|role| role.permissions.iter().cloned())
+            .collect();
+        permissions.sort();
+        permissions.dedup();
+
+        Self {
+            user_id: user_id.into(),
+            roles,
+            permissions,
+        }
+    }
+
+    #[must_use]
+    pub fn has_permission(&self, required: &str) -> bool {
+        self.permissions
+            .iter()
+            .any(|granted| permission_matches(granted, required))
+    }
+
+    #[must_use]
+    pub fn can_use_tool(&self, tool: &str) -> bool {
+        tool_permissions(tool)
+            .iter()
+            .any(|permission| self.has_permission(permission))
+    }
+
+    pub fn authorize(&self, tool: &str, args: &JsonMap<String, JsonValue>) -> Result<(), String> {
+        self.authorize_action(tool, &tool_action(tool, args))
+    }
+
+    pub fn authorize_action(&self, tool: &str, action: &str) -> Result<(), String> {
+        let Some(permissions) = required_permissions(tool, action) else {
+            return Err(format!(
+                "Permission denied: no permission is declared for {tool} {action}"
+            ));
+        };
+        let Some(missing) = permissions
+            .iter()
+            .find(|permission| !self.has_permission(permission))
+        else {
+            return Ok(());
+        };
+        Err(format!(
+            "Permission denied: {tool} {action} requires {}, and none of the caller's roles ({}) grant {missing}",
+            permissions.join(" and "),
+            self.role_list()
+        ))
+    }
+
+    pub fn require_permission(&self, subject: &str, permission: &str) -> Result<(), String> {
+        if self.has_permission(permission) {
+            return Ok(());
+        }
+        Err(format!(
+            "Permission denied: {subject} requires {permission}, and none of the caller's roles ({}) grant it",
+            self.role_list()
+        ))
+    }
+
+    fn role_list(&self) -> String {
+        if self.roles.is_empty() {
+            "none".to_string()
+        } else {
+            self.roles.join(", ")
+        }
+    }
+}
@@ -0,0 +1,125 @@
+pub mod models;
+pub mod repository;
+mod sections;
+
+use rmcp::{
+    model::{CallToolRequestParam, CallToolResult, Content},
+    service::RequestContext,
+    ErrorData as McpError, RoleServer,
+};
+use serde_json::{json, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::{ArtifactId, McpExecutionId};
+use systemprompt::models::artifacts::{
+    DashboardArtifact, DashboardHints, ExecutionMetadata, LayoutMode, ToolResponse,
+};
+
+use models::{summarize_roles, RoleStatus};
+use repository::RolesRepository;
+use sections::{create_overview_section, create_roles_table_section};
+
+use crate::tools::ToolState;
+
+#[must_use]
+pub fn roles_input_schema() -> JsonValue {
+    json!({
+        "type": "object",
+        "properties": {
+            "status": {
+                "type": "string",
+                "enum": ["all", "held", "unheld", "orphaned"],
+                "default": "all",
+                "description": "held: declared and assigned to users; unheld: declared but assigned to nobody; orphaned: assigned to users but no longer declared by core or any extension manifest.yaml"
+            }
+        }
+    })
+}
+
+#[must_use]
+pub fn roles_output_schema() -> JsonValue {
+    ToolResponse::<DashboardArtifact>::schema()
+}
+
+pub async fn handle_roles(
+    pool: &DbPool,
+    request: CallToolRequestParam,
+    _ctx: RequestContext<RoleServer>,
+    state: &ToolState,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let status = match request
+        .arguments
+        .as_ref()
+        .and_then(|args| args.get("status"))
+        .and_then(JsonValue::as_str)
+    {
+        None | Some("all") => None,
+        Some(value) => Some(RoleStatus::parse(value).ok_or_else(|| {
+            McpError::invalid_params(
+                format!("Invalid status: {value}. Expected all, held, unheld or orphaned"),
+                None,
+            )
+        })?),
+    };
+
+    let counts = RolesRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .count_role_holders()
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let all_roles = summarize_roles(&state.discovered_roles, &counts);
+    let roles: Vec<_> = all_roles
+        .iter()
+        .filter(|role| status.is_none() || status == Some(role.status))
+        .cloned()
+        .collect();
+
+    tracing::debug!(
+        discovered = state.discovered_roles.len(),
+        listed = roles.len(),
+        "Roles summarised"
+    );
+
+    let dashboard = DashboardArtifact::new("Roles")
+        .with_description("Discovered roles with their source, permissions and holders")
+        .with_hints(DashboardHints::new().with_layout(LayoutMode::Vertical))
+        .add_section(
+            create_overview_section(&all_roles)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_roles_table_section(&roles)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        );
+
+    let metadata = ExecutionMetadata::new().tool("roles");
+    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
+    let tool_response = ToolResponse::new(
+        artifact_id,
+        mcp_execution_id.clone(),
+        dashboard,
+        metadata.clone(),
+    );
+
+    let flagged: Vec<String> = all_roles
+        .iter()
+        .filter(|role| role.status != RoleStatus::Held)
+        .map(|role| format!("{} ({})", role.name, role.status.as_str()))
+        .collect();
+
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "{} roles listed{}",
+            roles.len(),
+            if flagged.is_empty() {
+                String::new()
+            } else {
+                format!("; flagged: {}", flagged.join(", "))
+            }
+        ))],
+        structured_content: Some(tool_response.to_json()),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
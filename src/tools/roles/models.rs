@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::services::DiscoveredRole;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoleHolderCount {
+    pub role: String,
+    pub user_count: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoleStatus {
+    Held,
+    Unheld,
+    Orphaned,
+}
+
+impl RoleStatus {
+    pub const ALL: [Self; 3] = [Self::Held, Self::Unheld, Self::Orphaned];
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Held => "held",
+            Self::Unheld => "unheld",
+            Self::Orphaned => "orphaned",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str() == value)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleSummary {
+    pub name: String,
+    pub display_name: String,
+    pub description: String,
+    pub source: String,
+    pub permissions: Vec<String>,
+    pub user_count: i64,
+    pub status: RoleStatus,
+}
+
+#[must_use]
+pub fn summarize_roles(
+    discovered: &[DiscoveredRole],
+    counts: &[RoleHolderCount],
+) -> Vec<RoleSummary> {
+    let mut holders: HashMap<&str, i64> = counts
+        .iter()
+        .map(|count| (count.role.as_str(), count.user_count))
+        .collect();
+
+    let mut summaries: Vec<RoleSummary> = discovered
+        .iter()
+        .map(|role| {
+            let user_count = holders.remove(role.name.as_str()).unwrap_or(0);
+            RoleSummary {
+                name: role.name.clone(),
+                display_name: role.display_name.clone(),
+                description: role.description.clone(),
+                source: role.source.label().to_string(),
+                permissions: role.permissions.clone(),
+                user_count,
+                status: if user_count > 0 {
+                    RoleStatus::Held
+                } else {
+                    RoleStatus::Unheld
+                },
+            }
+        })
+        .collect();
+
+    let mut orphaned: Vec<RoleSummary> = holders
+        .into_iter()
+        .filter(|(_, user_count)| *user_count > 0)
+        .map(|(name, user_count)| RoleSummary {
+            name: name.to_string(),
+            display_name: name.to_string(),
+            description: "Held by users but not declared by core or any extension manifest"
+                .to_string(),
+            source: "undeclared".to_string(),
+            permissions: Vec::new(),
+            user_count,
+            status: RoleStatus::Orphaned,
+        })
+        .collect();
+    orphaned.sort_by(|a, b| b.user_count.cmp(&a.user_count).then(a.name.cmp(&b.name)));
+
+    summaries.extend(orphaned);
+    summaries
+}
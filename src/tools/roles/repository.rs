@@ -0,0 +1,42 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use std::sync::Arc;
+use systemprompt::database::DbPool;
+
+use super::models::RoleHolderCount;
+
+pub struct RolesRepository {
+    pool: Arc<PgPool>,
+}
+
+impl RolesRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn count_role_holders(&self) -> Result<Vec<RoleHolderCount>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                r.role as "role!",
+                COUNT(DISTINCT u.id)::bigint as "user_count!"
+            FROM users u
+            CROSS JOIN LATERAL unnest(u.roles) AS r(role)
+            WHERE u.status <> 'deleted'
+            GROUP BY r.role
+            ORDER BY r.role
+            "#
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| RoleHolderCount {
+                role: r.role,
+                user_count: r.user_count,
+            })
+            .collect())
+    }
+}
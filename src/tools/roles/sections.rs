@@ -0,0 +1,106 @@
+use serde_json::json;
+use systemprompt::models::artifacts::{
+    Column, ColumnType, DashboardSection, LayoutWidth, SectionLayout, SectionType, TableArtifact,
+    TableHints,
+};
+
+use super::models::{RoleStatus, RoleSummary};
+
+fn count_with_status(roles: &[RoleSummary], status: RoleStatus) -> usize {
+    roles.iter().filter(|role| role.status == status).count()
+}
+
+pub fn create_overview_section(
+    roles: &[RoleSummary],
+) -> Result<DashboardSection, serde_json::Error> {
+    let orphaned = count_with_status(roles, RoleStatus::Orphaned);
+    let unheld = count_with_status(roles, RoleStatus::Unheld);
+    let declared = roles.len() - orphaned;
+    let from_extensions = roles
+        .iter()
+        .filter(|role| role.status != RoleStatus::Orphaned && role.source != "core")
+        .count();
+
+    let cards = vec![
+        json!({
+            "title": "Declared Roles",
+            "value": declared.to_string(),
+            "subtitle": format!("{from_extensions} from extensions"),
+            "icon": "shield",
+            "status": "info"
+        }),
+        json!({
+            "title": "Orphaned",
+            "value": orphaned.to_string(),
+            "subtitle": "held by users, declared nowhere",
+            "icon": "alert-triangle",
+            "status": if orphaned > 0 { "error" } else { "success" }
+        }),
+        json!({
+            "title": "Unheld",
+            "value": unheld.to_string(),
+            "subtitle": "declared, held by no user",
+            "icon": "user-x",
+            "status": if unheld > 0 { "warning" } else { "success" }
+        }),
+    ];
+
+    Ok(
+        DashboardSection::new("roles_overview", "Overview", SectionType::MetricsCards)
+            .with_data(json!({ "cards": cards }))?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 1,
+            }),
+    )
+}
+
+pub fn create_roles_table_section(
+    roles: &[RoleSummary],
+) -> Result<DashboardSection, serde_json::Error> {
+    let table = TableArtifact::new(vec![
+        Column::new("name", ColumnType::String).with_header("Role"),
+        Column::new("display_name", ColumnType::String).with_header("Display Name"),
+        Column::new("source", ColumnType::String).with_header("Source"),
+        Column::new("permissions", ColumnType::String).with_header("Permissions"),
+        Column::new("user_count", ColumnType::Integer).with_header("Users"),
+        Column::new("status", ColumnType::String).with_header("Status"),
+    ])
+    .with_rows(
+        roles
+            .iter()
+            .map(|role| {
+                json!({
+                    "name": role.name,
+                    "display_name": role.display_name,
+                    "description": role.description,
+                    "source": role.source,
+                    "permissions": if role.permissions.is_empty() {
+                        "-".to_string()
+                    } else {
+                        role.permissions.join(", ")
+                    },
+                    "user_count": role.user_count,
+                    "status": role.status.as_str(),
+                })
+            })
+            .collect(),
+    )
+    .with_hints(
+        TableHints::new()
+            .with_sortable(vec![
+                "name".to_string(),
+                "source".to_string(),
+                "user_count".to_string(),
+                "status".to_string(),
+            ])
+            .filterable(),
+    );
+
+    Ok(DashboardSection::new("roles", "Roles", SectionType::Table)
+        .with_data(table.to_response())?
+        .with_layout(SectionLayout {
+            width: LayoutWidth::Full,
+            order: 2,
+        }))
+}
@@ -0,0 +1,135 @@
+use rmcp::{
+    model::{CallToolResult, Content},
+    ErrorData as McpError,
+};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::models::artifacts::ExecutionMetadata;
+
+use crate::tools::ToolState;
+
+use super::models::SessionFlag;
+use super::sessions_repo;
+
+fn string_arg<'a>(args: &'a JsonMap<String, JsonValue>, key: &str) -> Option<&'a str> {
+    args.get(key)
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+}
+
+pub async fn handle_end_sessions(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    state: &ToolState,
+) -> Result<CallToolResult, McpError> {
+    let session_id = string_arg(args, "session_id");
+    let user_id = string_arg(args, "user_id");
+    let target = match (session_id, user_id) {
+        (Some(id), _) => format!("session {id}"),
+        (None, Some(id)) => format!("user {id}"),
+        (None, None) => {
+            return Err(McpError::invalid_params(
+                "end requires session_id or user_id",
+                None,
+            ))
+        }
+    };
+
+    let ended = sessions_repo(pool)?
+        .end_sessions(
+            session_id,
+            user_id,
+            &state.audit_scope("sessions", "end", args),
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    tracing::info!(
+        session_id = ?session_id,
+        user_id = ?user_id,
+        ended = ended.len(),
+        actor_id = %state.caller.user_id,
+        "Sessions marked ended"
+    );
+
+    let metadata = ExecutionMetadata::new().tool("sessions");
+    Ok(CallToolResult {
+        content: vec![Content::text(if ended.is_empty() {
+            format!("No active sessions to end for {target}.")
+        } else {
+            format!(
+                "Marked {} active sessions ended for {target}. Access tokens already issued stay valid until they expire.",
+                ended.len()
+            )
+        })],
+        structured_content: Some(json!({
+            "success": true,
+            "action": "end",
+            "session_id": session_id,
+            "user_id": user_id,
+            "ended_sessions": ended
+        })),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
+
+pub async fn handle_mark_session(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    state: &ToolState,
+) -> Result<CallToolResult, McpError> {
+    let session_id = string_arg(args, "session_id")
+        .ok_or_else(|| McpError::invalid_params("session_id is required for mark", None))?;
+    let flag = string_arg(args, "flag")
+        .ok_or_else(|| McpError::invalid_params("flag is required for mark", None))
+        .and_then(|value| {
+            SessionFlag::parse(value).ok_or_else(|| {
+                McpError::invalid_params(
+                    format!("Invalid flag: {value}. Expected bot, scanner or human"),
+                    None,
+                )
+            })
+        })?;
+
+    let marked = sessions_repo(pool)?
+        .mark_session(
+            session_id,
+            flag,
+            &state.audit_scope("sessions", "mark", args),
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    if !marked {
+        return Err(McpError::invalid_params(
+            format!("Session not found: {session_id}"),
+            None,
+        ));
+    }
+
+    tracing::info!(session_id = %session_id, flag = flag.as_str(), actor_id = %state.caller.user_id, "Session marked");
+
+    let metadata = ExecutionMetadata::new().tool("sessions");
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "Marked session {session_id} as {}. Traffic analytics now treat it as {} traffic.",
+            flag.as_str(),
+            if flag == SessionFlag::Human {
+                "human"
+            } else {
+                "automated"
+            }
+        ))],
+        structured_content: Some(json!({
+            "success": true,
+            "action": "mark",
+            "session_id": session_id,
+            "flag": flag.as_str(),
+            "is_bot": flag.is_bot(),
+            "is_scanner": flag.is_scanner()
+        })),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
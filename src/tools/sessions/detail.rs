@@ -0,0 +1,82 @@
+use rmcp::{
+    model::{CallToolResult, Content},
+    ErrorData as McpError,
+};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::{ArtifactId, McpExecutionId};
+use systemprompt::models::artifacts::{
+    DashboardArtifact, DashboardHints, ExecutionMetadata, LayoutMode, ToolResponse,
+};
+
+use super::sections::{
+    create_session_cards_section, create_session_details_section, create_timeline_section,
+};
+use super::{limit_arg, sessions_repo};
+
+pub async fn handle_get_session(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let session_id = args
+        .get("session_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::invalid_params("session_id is required for get", None))?;
+
+    let repo = sessions_repo(pool)?;
+    let detail = repo
+        .find_session(session_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .ok_or_else(|| {
+            McpError::invalid_params(format!("Session not found: {session_id}"), None)
+        })?;
+    let timeline = repo
+        .timeline(session_id, limit_arg(args))
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let dashboard = DashboardArtifact::new(format!("Session: {session_id}"))
+        .with_description("Device, location, referrer and a timeline of analytics events and logs")
+        .with_hints(DashboardHints::new().with_layout(LayoutMode::Vertical))
+        .add_section(
+            create_session_cards_section(&detail)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_session_details_section(&detail)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_timeline_section(&timeline)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        );
+
+    let metadata = ExecutionMetadata::new().tool("sessions");
+    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
+    let tool_response = ToolResponse::new(
+        artifact_id,
+        mcp_execution_id.clone(),
+        dashboard,
+        metadata.clone(),
+    );
+
+    let session = &detail.summary;
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "Session {} ({}): user {}, {} from {}, {} requests, traffic {}, {} timeline entries",
+            session_id,
+            if session.active() { "active" } else { "ended" },
+            session.user_id.as_deref().unwrap_or("anonymous"),
+            session.device_type,
+            detail.location,
+            session.request_count,
+            session.traffic(),
+            timeline.len()
+        ))],
+        structured_content: Some(tool_response.to_json()),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
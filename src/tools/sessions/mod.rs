@@ -0,0 +1,161 @@
+mod actions;
+mod detail;
+pub mod models;
+pub mod repository;
+mod schema;
+mod sections;
+
+pub use schema::{sessions_input_schema, sessions_output_schema};
+
+use rmcp::{
+    model::{CallToolRequestParam, CallToolResult, Content},
+    service::RequestContext,
+    ErrorData as McpError, RoleServer,
+};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::{ArtifactId, McpExecutionId};
+use systemprompt::models::artifacts::{
+    Column, ColumnType, ExecutionMetadata, TableArtifact, TableHints, ToolResponse,
+};
+
+use actions::{handle_end_sessions, handle_mark_session};
+use detail::handle_get_session;
+use models::{parse_session_filter, SessionSummary};
+use repository::SessionsRepository;
+
+use crate::tools::pagination::{parse_cursor_arg, with_next_cursor, Cursor};
+use crate::tools::users::format_cost;
+use crate::tools::ToolState;
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 500;
+
+pub async fn handle_sessions(
+    pool: &DbPool,
+    request: CallToolRequestParam,
+    _ctx: RequestContext<RoleServer>,
+    state: &ToolState,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let args = request.arguments.unwrap_or_default();
+    let action = args
+        .get("action")
+        .and_then(|v| v.as_str())
+        .unwrap_or("list");
+
+    match action {
+        "list" => handle_list_sessions(pool, &args, mcp_execution_id).await,
+        "get" => handle_get_session(pool, &args, mcp_execution_id).await,
+        "end" => handle_end_sessions(pool, &args, state).await,
+        "mark" => handle_mark_session(pool, &args, state).await,
+        _ => Err(McpError::invalid_params(
+            format!("Unknown action: {action}. Valid actions: list, get, end, mark"),
+            None,
+        )),
+    }
+}
+
+fn sessions_repo(pool: &DbPool) -> Result<SessionsRepository, McpError> {
+    SessionsRepository::new(pool.clone()).map_err(|e| McpError::internal_error(e.to_string(), None))
+}
+
+fn limit_arg(args: &JsonMap<String, JsonValue>) -> i64 {
+    args.get("limit")
+        .and_then(JsonValue::as_i64)
+        .unwrap_or(DEFAULT_LIMIT)
+        .clamp(1, MAX_LIMIT)
+}
+
+fn session_row(session: &SessionSummary) -> JsonValue {
+    json!({
+        "session_id": session.session_id,
+        "user_id": session.user_id,
+        "started_at": session.started_at.to_rfc3339(),
+        "last_activity_at": session.last_activity_at.map(|at| at.to_rfc3339()),
+        "active": session.active(),
+        "device_type": session.device_type,
+        "browser": session.browser,
+        "country": session.country,
+        "referrer": session.referrer,
+        "request_count": session.request_count,
+        "ai_cost": format_cost(session.ai_cost_cents),
+        "traffic": session.traffic()
+    })
+}
+
+async fn handle_list_sessions(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let filter = parse_session_filter(args).map_err(|e| McpError::invalid_params(e, None))?;
+    let limit = limit_arg(args);
+    let cursor = parse_cursor_arg(args)?;
+
+    tracing::debug!(filter = ?filter, limit = limit, cursor = ?cursor, "Listing sessions");
+
+    let sessions = sessions_repo(pool)?
+        .list_sessions(&filter, limit, cursor.as_ref())
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let items: Vec<JsonValue> = sessions.items.iter().map(session_row).collect();
+
+    let columns = vec![
+        Column::new("session_id", ColumnType::String).with_label("Session"),
+        Column::new("user_id", ColumnType::String).with_label("User"),
+        Column::new("started_at", ColumnType::Date).with_label("Started"),
+        Column::new("last_activity_at", ColumnType::Date).with_label("Last Activity"),
+        Column::new("device_type", ColumnType::String).with_label("Device"),
+        Column::new("browser", ColumnType::String).with_label("Browser"),
+        Column::new("country", ColumnType::String).with_label("Country"),
+        Column::new("request_count", ColumnType::Integer).with_label("Requests"),
+        Column::new("ai_cost", ColumnType::String).with_label("AI Cost"),
+        Column::new("traffic", ColumnType::String).with_label("Traffic"),
+    ];
+
+    let metadata = ExecutionMetadata::new().tool("sessions");
+    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
+    let artifact = TableArtifact::new(columns)
+        .with_rows(items.clone())
+        .with_hints(
+            TableHints::new()
+                .with_sortable(vec![
+                    "started_at".to_string(),
+                    "last_activity_at".to_string(),
+                    "request_count".to_string(),
+                    "ai_cost".to_string(),
+                ])
+                .filterable(),
+        )
+        .with_metadata(metadata.clone());
+    let tool_response = ToolResponse::new(
+        artifact_id,
+        mcp_execution_id.clone(),
+        artifact,
+        metadata.clone(),
+    );
+
+    let listing = serde_json::to_string_pretty(&items)
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "Found {} {} sessions{}\n\n{}",
+            sessions.items.len(),
+            filter.status,
+            if sessions.next_cursor.is_some() {
+                " (more available via next_cursor)"
+            } else {
+                ""
+            },
+            listing
+        ))],
+        structured_content: Some(with_next_cursor(
+            tool_response.to_json(),
+            sessions.next_cursor.as_ref().map(Cursor::encode),
+        )),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
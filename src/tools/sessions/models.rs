@@ -0,0 +1,141 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+pub const SESSION_STATUSES: [&str; 3] = ["active", "ended", "all"];
+pub const SESSION_TRAFFIC: [&str; 4] = ["all", "human", "bot", "scanner"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionFilter {
+    pub user_id: Option<String>,
+    pub status: String,
+    pub traffic: String,
+}
+
+impl Default for SessionFilter {
+    fn default() -> Self {
+        Self {
+            user_id: None,
+            status: "active".to_string(),
+            traffic: "all".to_string(),
+        }
+    }
+}
+
+fn string_arg(args: &JsonMap<String, JsonValue>, key: &str) -> Option<String> {
+    args.get(key)
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(String::from)
+}
+
+fn one_of(value: Option<String>, allowed: &[&str], name: &str) -> Result<Option<String>, String> {
+    match value {
+        Some(value) if !allowed.contains(&value.as_str()) => Err(format!(
+            "Invalid {name}: {value}. Expected one of {}",
+            allowed.join(", ")
+        )),
+        value => Ok(value),
+    }
+}
+
+pub fn parse_session_filter(args: &JsonMap<String, JsonValue>) -> Result<SessionFilter, String> {
+    let defaults = SessionFilter::default();
+    Ok(SessionFilter {
+        user_id: string_arg(args, "user_id"),
+        status: one_of(string_arg(args, "status"), &SESSION_STATUSES, "status")?
+            .unwrap_or(defaults.status),
+        traffic: one_of(string_arg(args, "traffic"), &SESSION_TRAFFIC, "traffic")?
+            .unwrap_or(defaults.traffic),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionFlag {
+    Bot,
+    Scanner,
+    Human,
+}
+
+impl SessionFlag {
+    pub const ALL: [Self; 3] = [Self::Bot, Self::Scanner, Self::Human];
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Bot => "bot",
+            Self::Scanner => "scanner",
+            Self::Human => "human",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|flag| flag.as_str() == value)
+    }
+
+    #[must_use]
+    pub fn is_bot(self) -> bool {
+        self == Self::Bot
+    }
+
+    #[must_use]
+    pub fn is_scanner(self) -> bool {
+        self == Self::Scanner
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionSummary {
+    pub session_id: String,
+    pub user_id: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub last_activity_at: Option<DateTime<Utc>>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub device_type: String,
+    pub browser: String,
+    pub country: String,
+    pub referrer: String,
+    pub request_count: i32,
+    pub ai_cost_cents: i64,
+    pub is_bot: bool,
+    pub is_scanner: bool,
+}
+
+impl SessionSummary {
+    #[must_use]
+    pub fn traffic(&self) -> &'static str {
+        if self.is_scanner {
+            "scanner"
+        } else if self.is_bot {
+            "bot"
+        } else {
+            "human"
+        }
+    }
+
+    #[must_use]
+    pub fn active(&self) -> bool {
+        self.ended_at.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionDetail {
+    pub summary: SessionSummary,
+    pub os: String,
+    pub location: String,
+    pub landing_page: Option<String>,
+    pub utm_source: Option<String>,
+    pub utm_campaign: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineEntry {
+    pub at: DateTime<Utc>,
+    pub source: String,
+    pub kind: String,
+    pub detail: String,
+}
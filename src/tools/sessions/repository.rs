@@ -0,0 +1,245 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use sqlx::PgPool;
+use std::sync::Arc;
+use systemprompt::database::DbPool;
+
+use crate::tools::audit::models::AuditScope;
+use crate::tools::audit::repository::record_audit;
+use crate::tools::pagination::{Cursor, Page};
+
+use super::models::{SessionDetail, SessionFilter, SessionFlag, SessionSummary, TimelineEntry};
+
+pub struct SessionsRepository {
+    pool: Arc<PgPool>,
+}
+
+impl SessionsRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn list_sessions(
+        &self,
+        filter: &SessionFilter,
+        limit: i64,
+        cursor: Option<&Cursor>,
+    ) -> Result<Page<SessionSummary>> {
+        let rows = sqlx::query_as!(
+            SessionSummary,
+            r#"
+            SELECT
+                session_id as "session_id!",
+                user_id,
+                started_at as "started_at!: DateTime<Utc>",
+                last_activity_at as "last_activity_at: DateTime<Utc>",
+                ended_at as "ended_at: DateTime<Utc>",
+                COALESCE(device_type, 'unknown') as "device_type!",
+                COALESCE(browser, 'unknown') as "browser!",
+                COALESCE(country, 'Unknown') as "country!",
+                COALESCE(referrer_url, 'Direct') as "referrer!",
+                COALESCE(request_count, 0)::int4 as "request_count!",
+                COALESCE(total_ai_cost_cents, 0)::bigint as "ai_cost_cents!",
+                COALESCE(is_bot, false) as "is_bot!",
+                COALESCE(is_scanner, false) as "is_scanner!"
+            FROM user_sessions
+            WHERE ($1::text IS NULL OR user_id = $1)
+              AND (CASE $2::text
+                    WHEN 'active' THEN ended_at IS NULL
+                    WHEN 'ended' THEN ended_at IS NOT NULL
+                    ELSE true
+                  END)
+              AND (CASE $3::text
+                    WHEN 'human' THEN NOT COALESCE(is_bot, false) AND NOT COALESCE(is_scanner, false)
+                    WHEN 'bot' THEN COALESCE(is_bot, false)
+                    WHEN 'scanner' THEN COALESCE(is_scanner, false)
+                    ELSE true
+                  END)
+              AND ($4::timestamptz IS NULL OR (started_at, session_id) < ($4, $5::text))
+            ORDER BY started_at DESC, session_id DESC
+            LIMIT $6
+            "#,
+            filter.user_id.as_deref(),
+            filter.status,
+            filter.traffic,
+            cursor.map(|c| c.timestamp),
+            cursor.map(|c| c.id.as_str()),
+            limit + 1
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(Page::from_rows(
+            rows,
+            limit as usize,
+            |row| Cursor::new(row.started_at, row.session_id.clone()),
+            |row| row,
+        ))
+    }
+
+    pub async fn find_session(&self, session_id: &str) -> Result<Option<SessionDetail>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                session_id as "session_id!",
+                user_id,
+                started_at as "started_at!: DateTime<Utc>",
+                last_activity_at as "last_activity_at: DateTime<Utc>",
+                ended_at as "ended_at: DateTime<Utc>",
+                COALESCE(device_type, 'unknown') as "device_type!",
+                COALESCE(browser, 'unknown') as "browser!",
+                COALESCE(os, 'unknown') as "os!",
+                COALESCE(country, 'Unknown') as "country!",
+                CONCAT_WS(', ', city, region, country) as "location!",
+                COALESCE(referrer_url, 'Direct') as "referrer!",
+                landing_page,
+                utm_source,
+                utm_campaign,
+                user_agent,
+                COALESCE(request_count, 0)::int4 as "request_count!",
+                COALESCE(total_ai_cost_cents, 0)::bigint as "ai_cost_cents!",
+                COALESCE(is_bot, false) as "is_bot!",
+                COALESCE(is_scanner, false) as "is_scanner!"
+            FROM user_sessions
+            WHERE session_id = $1
+            "#,
+            session_id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        Ok(row.map(|r| SessionDetail {
+            summary: SessionSummary {
+                session_id: r.session_id,
+                user_id: r.user_id,
+                started_at: r.started_at,
+                last_activity_at: r.last_activity_at,
+                ended_at: r.ended_at,
+                device_type: r.device_type,
+                browser: r.browser,
+                country: r.country,
+                referrer: r.referrer,
+                request_count: r.request_count,
+                ai_cost_cents: r.ai_cost_cents,
+                is_bot: r.is_bot,
+                is_scanner: r.is_scanner,
+            },
+            os: r.os,
+            location: r.location,
+            landing_page: r.landing_page,
+            utm_source: r.utm_source,
+            utm_campaign: r.utm_campaign,
+            user_agent: r.user_agent,
+        }))
+    }
+
+    pub async fn timeline(&self, session_id: &str, limit: i64) -> Result<Vec<TimelineEntry>> {
+        sqlx::query_as!(
+            TimelineEntry,
+            r#"
+            SELECT at as "at!", source as "source!", kind as "kind!", detail as "detail!"
+            FROM (
+                SELECT
+                    ae.timestamp as at,
+                    'event' as source,
+                    ae.event_type as kind,
+                    COALESCE(ae.endpoint, '') as detail
+                FROM analytics_events ae
+                WHERE ae.session_id = $1
+                UNION ALL
+                SELECT
+                    l.timestamp as at,
+                    'log' as source,
+                    l.level as kind,
+                    l.module || ': ' || l.message as detail
+                FROM logs l
+                WHERE l.session_id = $1
+            ) timeline
+            ORDER BY at ASC
+            LIMIT $2
+            "#,
+            session_id,
+            limit
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn end_sessions(
+        &self,
+        session_id: Option<&str>,
+        user_id: Option<&str>,
+        audit: &AuditScope,
+    ) -> Result<Vec<String>> {
+        let target = session_id
+            .or(user_id)
+            .context("Ending sessions requires a session_id or user_id")?;
+        let mut tx = self.pool.begin().await?;
+        let ended = sqlx::query_scalar!(
+            r#"
+            UPDATE user_sessions SET ended_at = NOW()
+            WHERE ended_at IS NULL
+              AND ($1::text IS NULL OR session_id = $1)
+              AND ($2::text IS NULL OR user_id = $2)
+            RETURNING session_id as "session_id!"
+            "#,
+            session_id,
+            user_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        if ended.is_empty() {
+            return Ok(ended);
+        }
+        let entry = audit
+            .entry(target)
+            .with_result(json!({ "ended_sessions": ended }));
+        record_audit(&mut tx, &entry).await?;
+        tx.commit().await?;
+        Ok(ended)
+    }
+
+    pub async fn mark_session(
+        &self,
+        session_id: &str,
+        flag: SessionFlag,
+        audit: &AuditScope,
+    ) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        let Some(before) = sqlx::query!(
+            r#"
+            SELECT COALESCE(is_bot, false) as "is_bot!", COALESCE(is_scanner, false) as "is_scanner!"
+            FROM user_sessions
+            WHERE session_id = $1
+            FOR UPDATE
+            "#,
+            session_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        else {
+            return Ok(false);
+        };
+
+        sqlx::query!(
+            "UPDATE user_sessions SET is_bot = $2, is_scanner = $3 WHERE session_id = $1",
+            session_id,
+            flag.is_bot(),
+            flag.is_scanner()
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let entry = audit.entry(session_id).with_change(
+            json!({ "is_bot": before.is_bot, "is_scanner": before.is_scanner }),
+            json!({ "is_bot": flag.is_bot(), "is_scanner": flag.is_scanner() }),
+        );
+        record_audit(&mut tx, &entry).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,118 @@
+use serde_json::{json, Value as JsonValue};
+
+use crate::tools::pagination::cursor_schema;
+
+use super::models::{SessionFlag, SESSION_STATUSES, SESSION_TRAFFIC};
+
+#[must_use]
+pub fn sessions_input_schema() -> JsonValue {
+    json!({
+        "type": "object",
+        "properties": {
+            "action": {
+                "type": "string",
+                "enum": ["list", "get", "end", "mark"],
+                "default": "list",
+                "description": "list: sessions matching the filters (default: active sessions); get: one session with its device, location, referrer and a timeline of analytics events and logs (requires session_id); end: mark one session (session_id) or every active session of a user (user_id) as ended in user_sessions; this does not revoke access tokens already issued, which stay valid until they expire; mark: flag a session as bot, scanner or human to correct analytics (requires session_id and flag)"
+            },
+            "session_id": {
+                "type": "string",
+                "description": "Session ID. Required for get and mark; for end, ends only this session"
+            },
+            "user_id": {
+                "type": "string",
+                "description": "For list, only this user's sessions. For end without session_id, ends all of this user's active sessions"
+            },
+            "status": {
+                "type": "string",
+                "enum": SESSION_STATUSES,
+                "default": "active",
+                "description": "For list: active sessions have not ended"
+            },
+            "traffic": {
+                "type": "string",
+                "enum": SESSION_TRAFFIC,
+                "default": "all",
+                "description": "For list: only human sessions, or only sessions flagged as bot or scanner"
+            },
+            "flag": {
+                "type": "string",
+                "enum": SessionFlag::ALL.map(SessionFlag::as_str),
+                "description": "For mark: bot or scanner excludes the session from human traffic analytics; human clears both flags"
+            },
+            "limit": {
+                "type": "integer",
+                "default": 50,
+                "minimum": 1,
+                "maximum": 500,
+                "description": "Maximum number of sessions for list, or timeline entries for get"
+            },
+            "cursor": cursor_schema()
+        },
+        "allOf": [
+            {
+                "if": { "properties": { "action": { "enum": ["get", "mark"] } }, "required": ["action"] },
+                "then": { "required": ["session_id"] }
+            },
+            {
+                "if": { "properties": { "action": { "const": "mark" } }, "required": ["action"] },
+                "then": { "required": ["flag"] }
+            },
+            {
+                "if": { "properties": { "action": { "const": "end" } }, "required": ["action"] },
+                "then": { "anyOf": [{ "required": ["session_id"] }, { "required": ["user_id"] }] }
+            }
+        ]
+    })
+}
+
+#[must_use]
+pub fn sessions_output_schema() -> JsonValue {
+    json!({
+        "type": "object",
+        "description": "Sessions with device, location, activity and bot/scanner flags",
+        "properties": {
+            "items": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "session_id": {"type": "string"},
+                        "user_id": {"type": ["string", "null"]},
+                        "started_at": {"type": "string"},
+                        "last_activity_at": {"type": ["string", "null"]},
+                        "active": {"type": "boolean"},
+                        "device_type": {"type": "string"},
+                        "browser": {"type": "string"},
+                        "country": {"type": "string"},
+                        "referrer": {"type": "string"},
+                        "request_count": {"type": "integer"},
+                        "ai_cost": {"type": "string"},
+                        "traffic": {"type": "string"}
+                    }
+                }
+            },
+            "count": {"type": "integer"},
+            "next_cursor": {"type": ["string", "null"]}
+        },
+        "x-artifact-type": "table",
+        "x-table-hints": {
+            "columns": ["session_id", "user_id", "started_at", "last_activity_at", "device_type", "country", "request_count", "ai_cost", "traffic"],
+            "sortable_columns": ["started_at", "last_activity_at", "request_count", "ai_cost"],
+            "default_sort": {"column": "started_at", "order": "desc"},
+            "filterable": true,
+            "page_size": 25,
+            "column_types": {
+                "session_id": "string",
+                "user_id": "string",
+                "started_at": "datetime",
+                "last_activity_at": "datetime",
+                "device_type": "string",
+                "country": "string",
+                "request_count": "integer",
+                "ai_cost": "string",
+                "traffic": "string"
+            }
+        }
+    })
+}
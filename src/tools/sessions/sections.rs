@@ -0,0 +1,124 @@
+use serde_json::json;
+use systemprompt::models::artifacts::{
+    Column, ColumnType, DashboardSection, LayoutWidth, SectionLayout, SectionType, TableArtifact,
+};
+
+use crate::tools::users::{format_cost, format_duration};
+
+use super::models::{SessionDetail, TimelineEntry};
+
+pub fn create_session_cards_section(
+    detail: &SessionDetail,
+) -> Result<DashboardSection, serde_json::Error> {
+    let session = &detail.summary;
+    let duration = session
+        .ended_at
+        .or(session.last_activity_at)
+        .map_or(0.0, |end| (end - session.started_at).num_seconds() as f64);
+
+    let cards = vec![
+        json!({
+            "title": "Status",
+            "value": if session.active() { "active" } else { "ended" },
+            "subtitle": format!("started {} · {}", session.started_at.to_rfc3339(), format_duration(duration)),
+            "icon": "activity",
+            "status": if session.active() { "success" } else { "info" }
+        }),
+        json!({
+            "title": "User",
+            "value": session.user_id.as_deref().unwrap_or("anonymous"),
+            "subtitle": format!("{} · {} · {}", session.device_type, session.browser, detail.os),
+            "icon": "user",
+            "status": "info"
+        }),
+        json!({
+            "title": "Requests",
+            "value": session.request_count.to_string(),
+            "subtitle": format!("AI cost {}", format_cost(session.ai_cost_cents)),
+            "icon": "bar-chart-2",
+            "status": "info"
+        }),
+        json!({
+            "title": "Traffic",
+            "value": session.traffic(),
+            "subtitle": "change with action 'mark'",
+            "icon": "shield",
+            "status": if session.traffic() == "human" { "success" } else { "warning" }
+        }),
+    ];
+
+    Ok(
+        DashboardSection::new("session_summary", "Session", SectionType::MetricsCards)
+            .with_data(json!({ "cards": cards }))?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 1,
+            }),
+    )
+}
+
+pub fn create_session_details_section(
+    detail: &SessionDetail,
+) -> Result<DashboardSection, serde_json::Error> {
+    let session = &detail.summary;
+    let fields = [
+        ("Location", Some(detail.location.as_str())),
+        ("Referrer", Some(session.referrer.as_str())),
+        ("Landing page", detail.landing_page.as_deref()),
+        ("UTM source", detail.utm_source.as_deref()),
+        ("UTM campaign", detail.utm_campaign.as_deref()),
+        ("User agent", detail.user_agent.as_deref()),
+    ];
+    let table = TableArtifact::new(vec![
+        Column::new("field", ColumnType::String).with_header("Field"),
+        Column::new("value", ColumnType::String).with_header("Value"),
+    ])
+    .with_rows(
+        fields
+            .iter()
+            .map(|(field, value)| json!({ "field": field, "value": value }))
+            .collect(),
+    );
+
+    Ok(
+        DashboardSection::new("session_details", "Details", SectionType::Table)
+            .with_data(table.to_response())?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 2,
+            }),
+    )
+}
+
+pub fn create_timeline_section(
+    timeline: &[TimelineEntry],
+) -> Result<DashboardSection, serde_json::Error> {
+    let table = TableArtifact::new(vec![
+        Column::new("at", ColumnType::Date).with_header("Time"),
+        Column::new("source", ColumnType::String).with_header("Source"),
+        Column::new("kind", ColumnType::String).with_header("Type"),
+        Column::new("detail", ColumnType::String).with_header("Detail"),
+    ])
+    .with_rows(
+        timeline
+            .iter()
+            .map(|entry| {
+                json!({
+                    "at": entry.at.to_rfc3339(),
+                    "source": entry.source,
+                    "kind": entry.kind,
+                    "detail": entry.detail,
+                })
+            })
+            .collect(),
+    );
+
+    Ok(
+        DashboardSection::new("session_timeline", "Timeline", SectionType::Table)
+            .with_data(table.to_response())?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 3,
+            }),
+    )
+}
@@ -0,0 +1,69 @@
+use chrono::{Duration, Utc};
+use rmcp::ErrorData as McpError;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::sync::Arc;
+
+use crate::services::{DiscoveredRole, TokenDelivery};
+use crate::tools::audit::models::AuditScope;
+use crate::tools::permissions::Caller;
+use crate::tools::undo::models::{IssuedUndo, DEFAULT_UNDO_TTL_MINUTES};
+
+#[derive(Clone)]
+pub struct ToolState {
+    pub discovered_roles: Arc<Vec<DiscoveredRole>>,
+    pub token_delivery: Option<Arc<dyn TokenDelivery>>,
+    pub caller: Caller,
+    pub undo_ttl: Duration,
+    pub confirm_destructive: bool,
+}
+
+impl ToolState {
+    #[must_use]
+    pub fn new(
+        discovered_roles: Arc<Vec<DiscoveredRole>>,
+        token_delivery: Option<Arc<dyn TokenDelivery>>,
+        caller: Caller,
+    ) -> Self {
+        Self {
+            discovered_roles,
+            token_delivery,
+            caller,
+            undo_ttl: Duration::minutes(DEFAULT_UNDO_TTL_MINUTES),
+            confirm_destructive: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_undo_ttl(mut self, undo_ttl: Duration) -> Self {
+        self.undo_ttl = undo_ttl;
+        self
+    }
+
+    #[must_use]
+    pub fn with_confirmation(mut self, confirm_destructive: bool) -> Self {
+        self.confirm_destructive = confirm_destructive;
+        self
+    }
+
+    #[must_use]
+    pub fn role_names(&self) -> Vec<String> {
+        self.discovered_roles
+            .iter()
+            .map(|role| role.name.clone())
+            .collect()
+    }
+
+    #[must_use]
+    pub fn audit_scope(
+        &self,
+        tool: &str,
+        action: &str,
+        arguments: &JsonMap<String, JsonValue>,
+    ) -> AuditScope {
+        AuditScope::new(&self.caller.user_id, tool, action, arguments)
+    }
+
+    pub fn issue_undo(&self) -> Result<IssuedUndo, McpError> {
+        IssuedUndo::issue(Utc::now(), self.undo_ttl).map_err(|e| McpError::internal_error(e, None))
+    }
+}
@@ -0,0 +1,152 @@
+use serde_json::{json, Value as JsonValue};
+use systemprompt::models::artifacts::{
+    Column, ColumnType, DashboardSection, LayoutWidth, SectionLayout, SectionType, TableArtifact,
+    TableHints,
+};
+
+use super::bots::BotDimension;
+use super::models::{BotBreakdown, BotEndpoint, BotTrafficSummary};
+
+pub fn create_bot_summary_section(
+    summary: &BotTrafficSummary,
+) -> Result<DashboardSection, serde_json::Error> {
+    let share = summary.automated_share();
+    let cards = vec![
+        json!({
+            "title": "Automated Request Share",
+            "value": format!("{share:.1}%"),
+            "subtitle": format!(
+                "{} of {} requests",
+                summary.automated_requests(),
+                summary.total_requests
+            ),
+            "icon": "shield",
+            "status": if share >= 50.0 { "error" } else if share >= 20.0 { "warning" } else { "info" }
+        }),
+        json!({
+            "title": "Bot Sessions",
+            "value": summary.bot_sessions.to_string(),
+            "subtitle": format!("{} requests", summary.bot_requests),
+            "icon": "cpu",
+            "status": "info"
+        }),
+        json!({
+            "title": "Scanner Sessions",
+            "value": summary.scanner_sessions.to_string(),
+            "subtitle": format!("{} requests", summary.scanner_requests),
+            "icon": "alert-triangle",
+            "status": if summary.scanner_sessions > 0 { "warning" } else { "success" }
+        }),
+    ];
+
+    Ok(DashboardSection::new(
+        "bot_summary",
+        "Bot & Scanner Traffic",
+        SectionType::MetricsCards,
+    )
+    .with_data(json!({ "cards": cards }))?
+    .with_layout(SectionLayout {
+        width: LayoutWidth::Full,
+        order: 13,
+    }))
+}
+
+pub fn create_bot_breakdown_section(
+    dimension: BotDimension,
+    rows: &[BotBreakdown],
+) -> Result<DashboardSection, serde_json::Error> {
+    let (id, title, header, order) = match dimension {
+        BotDimension::UserAgent => (
+            "bot_user_agents",
+            "Bot Traffic by User Agent",
+            "User Agent",
+            14,
+        ),
+        BotDimension::Country => ("bot_countries", "Bot Traffic by Country", "Country", 15),
+    };
+
+    let rows: Vec<JsonValue> = rows
+        .iter()
+        .map(|row| {
+            json!({
+                "label": row.label,
+                "bot_sessions": row.bot_sessions,
+                "scanner_sessions": row.scanner_sessions,
+                "requests": row.requests,
+                "percentage": format!("{:.1}%", row.percentage),
+            })
+        })
+        .collect();
+
+    let table = TableArtifact::new(vec![
+        Column::new("label", ColumnType::String).with_header(header),
+        Column::new("bot_sessions", ColumnType::Integer).with_header("Bot Sessions"),
+        Column::new("scanner_sessions", ColumnType::Integer).with_header("Scanner Sessions"),
+        Column::new("requests", ColumnType::Integer).with_header("Requests"),
+        Column::new("percentage", ColumnType::String).with_header("% of Automated"),
+    ])
+    .with_rows(rows)
+    .with_hints(
+        TableHints::new()
+            .with_sortable(vec![
+                "bot_sessions".to_string(),
+                "scanner_sessions".to_string(),
+                "requests".to_string(),
+            ])
+            .filterable(),
+    );
+
+    Ok(DashboardSection::new(id, title, SectionType::Table)
+        .with_data(table.to_response())?
+        .with_layout(SectionLayout {
+            width: LayoutWidth::Full,
+            order,
+        }))
+}
+
+pub fn create_bot_endpoints_section(
+    endpoints: &[BotEndpoint],
+) -> Result<DashboardSection, serde_json::Error> {
+    let rows: Vec<JsonValue> = endpoints
+        .iter()
+        .map(|endpoint| {
+            json!({
+                "endpoint": endpoint.endpoint,
+                "hits": endpoint.hits,
+                "sessions": endpoint.sessions,
+                "bot_hits": endpoint.bot_hits,
+                "scanner_hits": endpoint.scanner_hits,
+            })
+        })
+        .collect();
+
+    let table = TableArtifact::new(vec![
+        Column::new("endpoint", ColumnType::String).with_header("Endpoint"),
+        Column::new("hits", ColumnType::Integer).with_header("Hits"),
+        Column::new("sessions", ColumnType::Integer).with_header("Sessions"),
+        Column::new("bot_hits", ColumnType::Integer).with_header("Bot Hits"),
+        Column::new("scanner_hits", ColumnType::Integer).with_header("Scanner Hits"),
+    ])
+    .with_rows(rows)
+    .with_hints(
+        TableHints::new()
+            .with_sortable(vec![
+                "hits".to_string(),
+                "sessions".to_string(),
+                "bot_hits".to_string(),
+                "scanner_hits".to_string(),
+            ])
+            .filterable(),
+    );
+
+    Ok(DashboardSection::new(
+        "bot_endpoints",
+        "Most-Hit Endpoints by Bots & Scanners",
+        SectionType::Table,
+    )
+    .with_data(table.to_response())?
+    .with_layout(SectionLayout {
+        width: LayoutWidth::Full,
+        order: 16,
+    }))
+}
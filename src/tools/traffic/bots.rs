@@ -0,0 +1,118 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use std::sync::Arc;
+use systemprompt::database::DbPool;
+
+use super::models::{BotBreakdown, BotEndpoint, BotTrafficSummary};
+use super::windows::TimeWindow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BotDimension {
+    UserAgent,
+    Country,
+}
+
+impl BotDimension {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::UserAgent => "user_agent",
+            Self::Country => "country",
+        }
+    }
+}
+
+pub struct BotTrafficRepository {
+    pool: Arc<PgPool>,
+}
+
+impl BotTrafficRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn get_bot_summary(&self, window: &TimeWindow) -> Result<BotTrafficSummary> {
+        sqlx::query_as!(
+            BotTrafficSummary,
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE COALESCE(is_bot, false) AND NOT COALESCE(is_scanner, false)) as "bot_sessions!",
+                COUNT(*) FILTER (WHERE COALESCE(is_scanner, false)) as "scanner_sessions!",
+                COALESCE(SUM(request_count) FILTER (WHERE COALESCE(is_bot, false) AND NOT COALESCE(is_scanner, false)), 0)::bigint as "bot_requests!",
+                COALESCE(SUM(request_count) FILTER (WHERE COALESCE(is_scanner, false)), 0)::bigint as "scanner_requests!",
+                COALESCE(SUM(request_count), 0)::bigint as "total_requests!"
+            FROM user_sessions
+            WHERE started_at >= $1 AND started_at < $2
+            "#,
+            window.start,
+            window.end
+        )
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn get_bot_breakdown(
+        &self,
+        window: &TimeWindow,
+        dimension: BotDimension,
+        limit: i64,
+    ) -> Result<Vec<BotBreakdown>> {
+        sqlx::query_as!(
+            BotBreakdown,
+            r#"
+            SELECT
+                COALESCE(CASE $3::text WHEN 'user_agent' THEN user_agent ELSE country END, 'Unknown') as "label!",
+                COUNT(*) FILTER (WHERE NOT COALESCE(is_scanner, false)) as "bot_sessions!",
+                COUNT(*) FILTER (WHERE COALESCE(is_scanner, false)) as "scanner_sessions!",
+                COALESCE(SUM(request_count), 0)::bigint as "requests!",
+                COALESCE(SUM(request_count)::float / NULLIF(SUM(SUM(request_count)) OVER (), 0) * 100, 0)::float8 as "percentage!"
+            FROM user_sessions
+            WHERE started_at >= $1 AND started_at < $2
+              AND (COALESCE(is_bot, false) OR COALESCE(is_scanner, false))
+            GROUP BY 1
+            ORDER BY 4 DESC, 1
+            LIMIT $4
+            "#,
+            window.start,
+            window.end,
+            dimension.as_str(),
+            limit
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn get_bot_endpoints(
+        &self,
+        window: &TimeWindow,
+        limit: i64,
+    ) -> Result<Vec<BotEndpoint>> {
+        sqlx::query_as!(
+            BotEndpoint,
+            r#"
+            SELECT
+                COALESCE(ae.endpoint, 'unknown') as "endpoint!",
+                COUNT(*) as "hits!",
+                COUNT(DISTINCT ae.session_id) as "sessions!",
+                COUNT(*) FILTER (WHERE NOT COALESCE(us.is_scanner, false)) as "bot_hits!",
+                COUNT(*) FILTER (WHERE COALESCE(us.is_scanner, false)) as "scanner_hits!"
+            FROM analytics_events ae
+            JOIN user_sessions us ON ae.session_id = us.session_id
+            WHERE ae.timestamp >= $1 AND ae.timestamp < $2
+              AND (COALESCE(us.is_bot, false) OR COALESCE(us.is_scanner, false))
+            GROUP BY 1
+            ORDER BY 2 DESC, 1
+            LIMIT $3
+            "#,
+            window.start,
+            window.end,
+            limit
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(Into::into)
+    }
+}
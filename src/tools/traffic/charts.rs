@@ -0,0 +1,59 @@
+use serde_json::json;
+use systemprompt::models::artifacts::{
+    ChartDataset, ChartSectionData, DashboardSection, LayoutWidth, SectionLayout, SectionType,
+};
+
+use super::models::TrafficPoint;
+use super::range::Granularity;
+
+fn dataset(label: &str, points: &[TrafficPoint], value: fn(&TrafficPoint) -> f64) -> ChartDataset {
+    ChartDataset::new(label.to_string(), points.iter().map(value).collect())
+}
+
+fn line_chart(
+    id: &str,
+    title: &str,
+    labels: Vec<String>,
+    datasets: Vec<ChartDataset>,
+) -> Result<DashboardSection, serde_json::Error> {
+    let chart_data = ChartSectionData::new("line", labels, datasets);
+    DashboardSection::new(id, title, SectionType::Chart).with_data(json!(chart_data))
+}
+
+pub fn create_traffic_chart_sections(
+    points: &[TrafficPoint],
+    granularity: Granularity,
+) -> Result<Vec<DashboardSection>, serde_json::Error> {
+    let labels: Vec<String> = points.iter().map(|point| point.bucket.clone()).collect();
+    let per = granularity.as_str();
+
+    let traffic = line_chart(
+        "traffic_series",
+        &format!("Traffic per {per}"),
+        labels.clone(),
+        vec![
+            dataset("Sessions", points, |point| point.sessions as f64),
+            dataset("Requests", points, |point| point.requests as f64),
+            dataset("Unique Users", points, |point| point.unique_users as f64),
+        ],
+    )?
+    .with_layout(SectionLayout {
+        width: LayoutWidth::Full,
+        order: 2,
+    });
+
+    let cost = line_chart(
+        "ai_cost_series",
+        &format!("AI cost per {per} ($)"),
+        labels,
+        vec![dataset("AI Cost", points, |point| {
+            point.ai_cost_cents as f64 / 1_000_000.0
+        })],
+    )?
+    .with_layout(SectionLayout {
+        width: LayoutWidth::Full,
+        order: 3,
+    });
+
+    Ok(vec![traffic, cost])
+}
@@ -1,45 +1,52 @@
-mod models;
+mod bot_report;
+pub mod bots;
+mod charts;
+pub mod models;
+pub mod navigation;
+mod pages;
+pub mod range;
 pub mod repository;
+mod schema;
 mod sections;
+pub mod series;
+mod summary;
+pub mod windows;
 
+pub use schema::{traffic_input_schema, traffic_output_schema};
+
+use chrono::Utc;
 use rmcp::{
     model::{CallToolRequestParam, CallToolResult, Content},
     service::RequestContext,
     ErrorData as McpError, RoleServer,
 };
-use serde_json::{json, Value as JsonValue};
 use systemprompt::database::DbPool;
 use systemprompt::identifiers::{ArtifactId, McpExecutionId};
 use systemprompt::models::artifacts::{
     DashboardArtifact, DashboardHints, ExecutionMetadata, LayoutMode, ToolResponse,
 };
 
+use bot_report::{
+    create_bot_breakdown_section, create_bot_endpoints_section, create_bot_summary_section,
+};
+use bots::{BotDimension, BotTrafficRepository};
+use charts::create_traffic_chart_sections;
+use navigation::{NavigationRepository, PageBoundary};
+use pages::{create_boundary_pages_section, create_navigation_paths_section};
+use range::{parse_traffic_range, TrafficRange};
 use repository::TrafficRepository;
 use sections::{
     create_browser_breakdown_section, create_device_breakdown_section,
     create_geographic_breakdown_section, create_os_breakdown_section, create_top_referrers_section,
-    create_traffic_summary_section,
 };
+use series::SeriesRepository;
+use summary::summary_section;
+use windows::{TimeWindow, WindowRepository};
 
-#[must_use]
-pub fn traffic_input_schema() -> JsonValue {
-    json!({
-        "type": "object",
-        "properties": {
-            "time_range": {
-                "type": "string",
-                "enum": ["7d", "30d", "90d"],
-                "default": "30d",
-                "description": "Time range for metrics: 7d, 30d, or 90d"
-            }
-        }
-    })
-}
-
-#[must_use]
-pub fn traffic_output_schema() -> JsonValue {
-    ToolResponse::<DashboardArtifact>::schema()
-}
+const PAGE_LIMIT: i64 = 20;
+const PATH_LIMIT: i64 = 15;
+const BOT_BREAKDOWN_LIMIT: i64 = 20;
+const BOT_ENDPOINT_LIMIT: i64 = 25;
 
 pub async fn handle_traffic(
     pool: &DbPool,
@@ -48,46 +55,170 @@ pub async fn handle_traffic(
     mcp_execution_id: &McpExecutionId,
 ) -> Result<CallToolResult, McpError> {
     let args = request.arguments.unwrap_or_default();
+    let range = parse_traffic_range(&args).map_err(|e| McpError::invalid_params(e, None))?;
 
-    let time_range = args
-        .get("time_range")
-        .and_then(|v| v.as_str())
-        .unwrap_or("30d");
-
-    tracing::debug!(time_range = %time_range, "Generating traffic analytics");
+    tracing::debug!(range = ?range, "Generating traffic analytics");
 
-    let days = match time_range {
-        "7d" => 7,
-        "30d" => 30,
-        "90d" => 90,
-        _ => 30,
-    };
+    let bounds = WindowRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .resolve(&range)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .ok_or_else(|| {
+            McpError::invalid_params(format!("Unknown timezone: {}", range.timezone), None)
+        })?;
+    let window = TimeWindow::from_bounds(range.period, bounds, Utc::now())
+        .map_err(|e| McpError::invalid_params(e, None))?;
+    window
+        .ensure_bucket_limit(range.granularity)
+        .map_err(|e| McpError::invalid_params(e, None))?;
 
     let repo = TrafficRepository::new(pool.clone())
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-    let mut dashboard = DashboardArtifact::new("Website Traffic Analytics")
-        .with_description(format!("Traffic metrics for the last {days} days"))
+    let dashboard = DashboardArtifact::new("Website Traffic Analytics")
+        .with_description(format!("Traffic metrics for {}", range.describe()))
         .with_hints(
             DashboardHints::new()
                 .with_layout(LayoutMode::Vertical)
                 .with_refreshable(true)
                 .with_refresh_interval(60)
                 .with_drill_down(true),
-        );
+        )
+        .add_section(summary_section(&repo, &range, &window).await?);
+    let dashboard = add_chart_sections(dashboard, pool, &range, &window).await?;
+    let dashboard = add_breakdown_sections(dashboard, &repo, &window).await?;
+    let dashboard = add_navigation_sections(dashboard, pool, &window).await?;
+    let dashboard = if range.include_bots {
+        add_bot_sections(dashboard, pool, &window).await?
+    } else {
+        dashboard
+    };
+
+    let metadata = ExecutionMetadata::new().tool("traffic");
+    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
+    let tool_response = ToolResponse::new(
+        artifact_id,
+        mcp_execution_id.clone(),
+        dashboard,
+        metadata.clone(),
+    );
+
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "Website Traffic Analytics ({}: {} to {})",
+            range.describe(),
+            window.start.to_rfc3339(),
+            window.end.to_rfc3339()
+        ))],
+        structured_content: Some(tool_response.to_json()),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
 
-    let traffic_summary = repo
-        .get_traffic_summary(days)
+async fn add_chart_sections(
+    dashboard: DashboardArtifact,
+    pool: &DbPool,
+    range: &TrafficRange,
+    window: &TimeWindow,
+) -> Result<DashboardArtifact, McpError> {
+    let points = SeriesRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .get_traffic_series(window, range.granularity, &range.timezone)
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
+    Ok(create_traffic_chart_sections(&points, range.granularity)
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .into_iter()
+        .fold(dashboard, |dashboard, section| {
+            dashboard.add_section(section)
+        }))
+}
+
+async fn add_navigation_sections(
+    mut dashboard: DashboardArtifact,
+    pool: &DbPool,
+    window: &TimeWindow,
+) -> Result<DashboardArtifact, McpError> {
+    let repo = NavigationRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    for boundary in [PageBoundary::Landing, PageBoundary::Exit] {
+        let pages = repo
+            .get_boundary_pages(window, boundary, PAGE_LIMIT)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        if !pages.is_empty() {
+            dashboard = dashboard.add_section(
+                create_boundary_pages_section(boundary, &pages)
+                    .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+            );
+        }
+    }
+
+    for steps in [2, 3] {
+        let paths = repo
+            .get_navigation_paths(window, steps, PATH_LIMIT)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        if !paths.is_empty() {
+            dashboard = dashboard.add_section(
+                create_navigation_paths_section(steps, &paths)
+                    .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+            );
+        }
+    }
+
+    Ok(dashboard)
+}
+
+async fn add_bot_sections(
+    mut dashboard: DashboardArtifact,
+    pool: &DbPool,
+    window: &TimeWindow,
+) -> Result<DashboardArtifact, McpError> {
+    let repo = BotTrafficRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let summary = repo
+        .get_bot_summary(window)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
     dashboard = dashboard.add_section(
-        create_traffic_summary_section(&traffic_summary)
+        create_bot_summary_section(&summary)
             .map_err(|e| McpError::internal_error(e.to_string(), None))?,
     );
 
+    for dimension in [BotDimension::UserAgent, BotDimension::Country] {
+        let rows = repo
+            .get_bot_breakdown(window, dimension, BOT_BREAKDOWN_LIMIT)
+            .await
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        dashboard = dashboard.add_section(
+            create_bot_breakdown_section(dimension, &rows)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        );
+    }
+
+    let endpoints = repo
+        .get_bot_endpoints(window, BOT_ENDPOINT_LIMIT)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    Ok(dashboard.add_section(
+        create_bot_endpoints_section(&endpoints)
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+    ))
+}
+
+async fn add_breakdown_sections(
+    mut dashboard: DashboardArtifact,
+    repo: &TrafficRepository,
+    window: &TimeWindow,
+) -> Result<DashboardArtifact, McpError> {
     let top_referrers = repo
-        .get_normalized_referrers(days)
+        .get_normalized_referrers(window)
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
@@ -99,57 +230,40 @@ pub async fn handle_traffic(
     }
 
     let device_breakdown = repo
-        .get_device_breakdown_with_trends(days)
+        .get_device_breakdown_with_trends(window)
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
     let geographic_breakdown = repo
-        .get_geographic_breakdown(days)
+        .get_geographic_breakdown(window)
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
     let browser_breakdown = repo
-        .get_browser_breakdown(days)
+        .get_browser_breakdown(window)
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
     let os_breakdown = repo
-        .get_os_breakdown(days)
+        .get_os_breakdown(window)
         .await
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-    dashboard = dashboard.add_section(
-        create_device_breakdown_section(&device_breakdown)
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?,
-    );
-    dashboard = dashboard.add_section(
-        create_geographic_breakdown_section(&geographic_breakdown)
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?,
-    );
-    dashboard = dashboard.add_section(
-        create_browser_breakdown_section(&browser_breakdown)
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?,
-    );
-    dashboard = dashboard.add_section(
-        create_os_breakdown_section(&os_breakdown)
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?,
-    );
-
-    let metadata = ExecutionMetadata::new().tool("traffic");
-    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
-    let tool_response = ToolResponse::new(
-        artifact_id,
-        mcp_execution_id.clone(),
-        dashboard,
-        metadata.clone(),
-    );
-
-    Ok(CallToolResult {
-        content: vec![Content::text(format!(
-            "Website Traffic Analytics ({time_range})"
-        ))],
-        structured_content: Some(tool_response.to_json()),
-        is_error: Some(false),
-        meta: metadata.to_meta(),
-    })
+    Ok(dashboard
+        .add_section(
+            create_device_breakdown_section(&device_breakdown)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_geographic_breakdown_section(&geographic_breakdown)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_browser_breakdown_section(&browser_breakdown)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_os_breakdown_section(&os_breakdown)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        ))
 }
@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+
 #[derive(serde::Serialize)]
 pub struct TrafficSummary {
     pub total_sessions: i32,
@@ -8,6 +10,18 @@ pub struct TrafficSummary {
     pub total_cost_cents: i32,
 }
 
+impl TrafficSummary {
+    #[must_use]
+    pub fn total_cost_dollars(&self) -> f64 {
+        f64::from(self.total_cost_cents) / 1_000_000.0
+    }
+}
+
+#[must_use]
+pub fn percent_change(current: f64, previous: f64) -> Option<f64> {
+    (previous.abs() > f64::EPSILON).then(|| (current - previous) / previous * 100.0)
+}
+
 #[derive(serde::Serialize)]
 pub struct DeviceBreakdownWithTrends {
     pub device_type: String,
@@ -56,3 +70,84 @@ pub struct Referrer {
     pub avg_pages_per_session: f64,
     pub avg_duration_sec: f64,
 }
+
+#[derive(serde::Serialize)]
+pub struct UserSessionActivity {
+    pub session_id: String,
+    pub started_at: DateTime<Utc>,
+    pub last_activity_at: Option<DateTime<Utc>>,
+    pub device_type: String,
+    pub country: String,
+    pub referrer: String,
+    pub duration_seconds: f64,
+    pub request_count: i32,
+    pub ai_cost_cents: i64,
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TrafficPoint {
+    pub bucket: String,
+    pub sessions: i64,
+    pub requests: i64,
+    pub unique_users: i64,
+    pub ai_cost_cents: i64,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PageEntry {
+    pub page: String,
+    pub sessions: i64,
+    pub percentage: f64,
+    pub single_page_sessions: i64,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct NavigationPath {
+    pub path: String,
+    pub sessions: i64,
+    pub occurrences: i64,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BotTrafficSummary {
+    pub bot_sessions: i64,
+    pub scanner_sessions: i64,
+    pub bot_requests: i64,
+    pub scanner_requests: i64,
+    pub total_requests: i64,
+}
+
+impl BotTrafficSummary {
+    #[must_use]
+    pub fn automated_requests(&self) -> i64 {
+        self.bot_requests + self.scanner_requests
+    }
+
+    #[must_use]
+    pub fn automated_share(&self) -> f64 {
+        if self.total_requests == 0 {
+            0.0
+        } else {
+            self.automated_requests() as f64 / self.total_requests as f64 * 100.0
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BotBreakdown {
+    pub label: String,
+    pub bot_sessions: i64,
+    pub scanner_sessions: i64,
+    pub requests: i64,
+    pub percentage: f64,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BotEndpoint {
+    pub endpoint: String,
+    pub hits: i64,
+    pub sessions: i64,
+    pub bot_hits: i64,
+    pub scanner_hits: i64,
+}
@@ -0,0 +1,126 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use std::sync::Arc;
+use systemprompt::database::DbPool;
+
+use super::models::{NavigationPath, PageEntry};
+use super::windows::TimeWindow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageBoundary {
+    Landing,
+    Exit,
+}
+
+pub struct NavigationRepository {
+    pool: Arc<PgPool>,
+}
+
+impl NavigationRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn get_boundary_pages(
+        &self,
+        window: &TimeWindow,
+        boundary: PageBoundary,
+        limit: i64,
+    ) -> Result<Vec<PageEntry>> {
+        sqlx::query_as!(
+            PageEntry,
+            r#"
+            WITH ranked AS (
+                SELECT
+                    regexp_replace(ae.endpoint, '^[A-Z]+ ', '') as page,
+                    ROW_NUMBER() OVER (
+                        PARTITION BY ae.session_id
+                        ORDER BY CASE WHEN $3 THEN ae.timestamp END ASC, ae.timestamp DESC
+                    ) as position,
+                    COUNT(*) OVER (PARTITION BY ae.session_id) as session_views
+                FROM analytics_events ae
+                JOIN user_sessions us ON ae.session_id = us.session_id
+                WHERE CASE WHEN $3 THEN us.started_at ELSE us.ended_at END >= $1
+                  AND CASE WHEN $3 THEN us.started_at ELSE us.ended_at END < $2
+                  AND ae.event_type = 'page_view'
+                  AND ae.endpoint IS NOT NULL
+                  AND us.is_bot = false
+                  AND us.is_scanner = false
+            )
+            SELECT
+                page as "page!",
+                COUNT(*) as "sessions!",
+                COALESCE(COUNT(*)::float / NULLIF(SUM(COUNT(*)) OVER (), 0) * 100, 0)::float8 as "percentage!",
+                COUNT(*) FILTER (WHERE session_views = 1) as "single_page_sessions!"
+            FROM ranked
+            WHERE position = 1
+            GROUP BY page
+            ORDER BY 2 DESC, page
+            LIMIT $4
+            "#,
+            window.start,
+            window.end,
+            boundary == PageBoundary::Landing,
+            limit
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn get_navigation_paths(
+        &self,
+        window: &TimeWindow,
+        steps: i32,
+        limit: i64,
+    ) -> Result<Vec<NavigationPath>> {
+        sqlx::query_as!(
+            NavigationPath,
+            r#"
+            WITH views AS (
+                SELECT
+                    ae.session_id,
+                    ae.timestamp,
+                    regexp_replace(ae.endpoint, '^[A-Z]+ ', '') as page,
+                    LAG(ae.endpoint) OVER (PARTITION BY ae.session_id ORDER BY ae.timestamp) as previous_endpoint,
+                    ae.endpoint
+                FROM analytics_events ae
+                JOIN user_sessions us ON ae.session_id = us.session_id
+                WHERE ae.timestamp >= $1 AND ae.timestamp < $2
+                  AND ae.event_type = 'page_view'
+                  AND ae.endpoint IS NOT NULL
+                  AND us.is_bot = false
+                  AND us.is_scanner = false
+            ),
+            steps AS (
+                SELECT
+                    session_id,
+                    page,
+                    LEAD(page, 1) OVER w as next_page,
+                    LEAD(page, 2) OVER w as third_page
+                FROM views
+                WHERE previous_endpoint IS DISTINCT FROM endpoint
+                WINDOW w AS (PARTITION BY session_id ORDER BY timestamp)
+            )
+            SELECT
+                CONCAT_WS(' → ', page, next_page, CASE WHEN $3 >= 3 THEN third_page END) as "path!",
+                COUNT(DISTINCT session_id) as "sessions!",
+                COUNT(*) as "occurrences!"
+            FROM steps
+            WHERE next_page IS NOT NULL
+              AND ($3 < 3 OR third_page IS NOT NULL)
+            GROUP BY 1
+            ORDER BY 2 DESC, 3 DESC, 1
+            LIMIT $4
+            "#,
+            window.start,
+            window.end,
+            steps,
+            limit
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(Into::into)
+    }
+}
@@ -0,0 +1,92 @@
+use serde_json::{json, Value as JsonValue};
+use systemprompt::models::artifacts::{
+    Column, ColumnType, DashboardSection, LayoutWidth, SectionLayout, SectionType, TableArtifact,
+    TableHints,
+};
+
+use super::models::{NavigationPath, PageEntry};
+use super::navigation::PageBoundary;
+
+pub fn create_boundary_pages_section(
+    boundary: PageBoundary,
+    pages: &[PageEntry],
+) -> Result<DashboardSection, serde_json::Error> {
+    let (id, title, single_page_header, order) = match boundary {
+        PageBoundary::Landing => ("landing_pages", "Top Landing Pages", "Bounce Rate", 9),
+        PageBoundary::Exit => ("exit_pages", "Top Exit Pages", "Single-Page Share", 10),
+    };
+
+    let rows: Vec<JsonValue> = pages
+        .iter()
+        .map(|page| {
+            json!({
+                "page": page.page,
+                "sessions": page.sessions,
+                "percentage": format!("{:.1}%", page.percentage),
+                "single_page": format!(
+                    "{:.1}%",
+                    page.single_page_sessions as f64 / page.sessions.max(1) as f64 * 100.0
+                ),
+            })
+        })
+        .collect();
+
+    let table = TableArtifact::new(vec![
+        Column::new("page", ColumnType::String).with_header("Page"),
+        Column::new("sessions", ColumnType::Integer).with_header("Sessions"),
+        Column::new("percentage", ColumnType::String).with_header("%"),
+        Column::new("single_page", ColumnType::String).with_header(single_page_header),
+    ])
+    .with_rows(rows)
+    .with_hints(
+        TableHints::new()
+            .with_sortable(vec!["sessions".to_string()])
+            .filterable(),
+    );
+
+    Ok(DashboardSection::new(id, title, SectionType::Table)
+        .with_data(table.to_response())?
+        .with_layout(SectionLayout {
+            width: LayoutWidth::Full,
+            order,
+        }))
+}
+
+pub fn create_navigation_paths_section(
+    steps: i32,
+    paths: &[NavigationPath],
+) -> Result<DashboardSection, serde_json::Error> {
+    let rows: Vec<JsonValue> = paths
+        .iter()
+        .map(|path| {
+            json!({
+                "path": path.path,
+                "sessions": path.sessions,
+                "occurrences": path.occurrences,
+            })
+        })
+        .collect();
+
+    let table = TableArtifact::new(vec![
+        Column::new("path", ColumnType::String).with_header("Path"),
+        Column::new("sessions", ColumnType::Integer).with_header("Sessions"),
+        Column::new("occurrences", ColumnType::Integer).with_header("Occurrences"),
+    ])
+    .with_rows(rows)
+    .with_hints(
+        TableHints::new()
+            .with_sortable(vec!["sessions".to_string(), "occurrences".to_string()])
+            .filterable(),
+    );
+
+    Ok(DashboardSection::new(
+        &format!("paths_{steps}_step"),
+        &format!("Common {steps}-Step Paths"),
+        SectionType::Table,
+    )
+    .with_data(table.to_response())?
+    .with_layout(SectionLayout {
+        width: LayoutWidth::Full,
+        order: if steps < 3 { 11 } else { 12 },
+    }))
+}
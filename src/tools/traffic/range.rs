@@ -0,0 +1,220 @@
+use chrono::{Duration, NaiveDate};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+pub const DEFAULT_DAYS: i32 = 30;
+pub const DEFAULT_TIMEZONE: &str = "UTC";
+pub const PRESET_RANGES: [&str; 3] = ["7d", "30d", "90d"];
+pub const COMPARISONS: [&str; 2] = ["previous_period", "previous_year"];
+pub const GRANULARITIES: [&str; 3] = ["hour", "day", "week"];
+pub const INCLUDES: [&str; 1] = ["bots"];
+pub const MAX_SERIES_BUCKETS: i64 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Granularity {
+    Hour,
+    #[default]
+    Day,
+    Week,
+}
+
+impl Granularity {
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "hour" => Some(Self::Hour),
+            "day" => Some(Self::Day),
+            "week" => Some(Self::Week),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Week => "week",
+        }
+    }
+
+    #[must_use]
+    pub fn step(self) -> Duration {
+        match self {
+            Self::Hour => Duration::hours(1),
+            Self::Day => Duration::days(1),
+            Self::Week => Duration::weeks(1),
+        }
+    }
+
+    #[must_use]
+    pub fn label_format(self) -> &'static str {
+        match self {
+            Self::Hour => "YYYY-MM-DD HH24:00",
+            Self::Day | Self::Week => "YYYY-MM-DD",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    PreviousPeriod,
+    PreviousYear,
+}
+
+impl Comparison {
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "previous_period" => Some(Self::PreviousPeriod),
+            "previous_year" => Some(Self::PreviousYear),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::PreviousPeriod => "previous period",
+            Self::PreviousYear => "previous year",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrafficPeriod {
+    LastDays(i32),
+    Dates {
+        start: NaiveDate,
+        end: Option<NaiveDate>,
+    },
+}
+
+impl TrafficPeriod {
+    #[must_use]
+    pub fn describe(&self) -> String {
+        match self {
+            Self::LastDays(days) => format!("the last {days} days"),
+            Self::Dates {
+                start,
+                end: Some(end),
+            } => format!("{start} to {end}"),
+            Self::Dates { start, end: None } => format!("{start} to now"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrafficRange {
+    pub period: TrafficPeriod,
+    pub timezone: String,
+    pub compare_to: Option<Comparison>,
+    pub granularity: Granularity,
+    pub include_bots: bool,
+}
+
+impl Default for TrafficRange {
+    fn default() -> Self {
+        Self {
+            period: TrafficPeriod::LastDays(DEFAULT_DAYS),
+            timezone: DEFAULT_TIMEZONE.to_string(),
+            compare_to: None,
+            granularity: Granularity::default(),
+            include_bots: false,
+        }
+    }
+}
+
+impl TrafficRange {
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let base = format!("{} ({})", self.period.describe(), self.timezone);
+        match self.compare_to {
+            Some(comparison) => format!("{base} compared to the {}", comparison.label()),
+            None => base,
+        }
+    }
+}
+
+fn string_arg<'a>(args: &'a JsonMap<String, JsonValue>, key: &str) -> Option<&'a str> {
+    args.get(key)
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+}
+
+fn date_arg(args: &JsonMap<String, JsonValue>, key: &str) -> Result<Option<NaiveDate>, String> {
+    string_arg(args, key)
+        .map(|value| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map_err(|_| format!("Invalid {key} '{value}': expected an ISO date (YYYY-MM-DD)"))
+        })
+        .transpose()
+}
+
+fn parse_period(args: &JsonMap<String, JsonValue>) -> Result<TrafficPeriod, String> {
+    match (date_arg(args, "start")?, date_arg(args, "end")?) {
+        (Some(start), Some(end)) if start > end => {
+            Err("'start' must not be later than 'end'".to_string())
+        }
+        (Some(start), end) => Ok(TrafficPeriod::Dates { start, end }),
+        (None, Some(_)) => Err("'end' requires 'start'".to_string()),
+        (None, None) => match string_arg(args, "time_range") {
+            None => Ok(TrafficPeriod::LastDays(DEFAULT_DAYS)),
+            Some(range) => range
+                .strip_suffix('d')
+                .filter(|_| PRESET_RANGES.contains(&range))
+                .and_then(|days| days.parse().ok())
+                .map(TrafficPeriod::LastDays)
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid time_range: {range}. Expected one of {}",
+                        PRESET_RANGES.join(", ")
+                    )
+                }),
+        },
+    }
+}
+
+pub fn parse_traffic_range(args: &JsonMap<String, JsonValue>) -> Result<TrafficRange, String> {
+    let compare_to = string_arg(args, "compare_to")
+        .map(|value| {
+            Comparison::parse(value).ok_or_else(|| {
+                format!(
+                    "Invalid compare_to: {value}. Expected one of {}",
+                    COMPARISONS.join(", ")
+                )
+            })
+        })
+        .transpose()?;
+    let granularity = string_arg(args, "granularity")
+        .map(|value| {
+            Granularity::parse(value).ok_or_else(|| {
+                format!(
+                    "Invalid granularity: {value}. Expected one of {}",
+                    GRANULARITIES.join(", ")
+                )
+            })
+        })
+        .transpose()?
+        .unwrap_or_default();
+    let include_bots = match string_arg(args, "include") {
+        None => false,
+        Some("bots") => true,
+        Some(value) => {
+            return Err(format!(
+                "Invalid include: {value}. Expected one of {}",
+                INCLUDES.join(", ")
+            ))
+        }
+    };
+
+    Ok(TrafficRange {
+        period: parse_period(args)?,
+        timezone: string_arg(args, "timezone")
+            .unwrap_or(DEFAULT_TIMEZONE)
+            .to_string(),
+        compare_to,
+        granularity,
+        include_bots,
+    })
+}
@@ -5,8 +5,9 @@ use systemprompt::database::DbPool;
 
 use super::models::{
     BrowserBreakdown, DeviceBreakdownWithTrends, GeographicBreakdown, OsBreakdown, Referrer,
-    TrafficSummary,
+    TrafficSummary, UserSessionActivity,
 };
+use super::windows::TimeWindow;
 
 pub struct TrafficRepository {
     pool: Arc<PgPool>,
@@ -18,7 +19,7 @@ impl TrafficRepository {
         Ok(Self { pool })
     }
 
-    pub async fn get_traffic_summary(&self, days: i32) -> Result<TrafficSummary> {
+    pub async fn get_traffic_summary(&self, window: &TimeWindow) -> Result<TrafficSummary> {
         let row = sqlx::query!(
             r#"
             SELECT
@@ -29,12 +30,13 @@ impl TrafficRepository {
                 AVG(request_count)::float8 as avg_requests_per_session,
                 COALESCE(SUM(total_ai_cost_cents), 0)::bigint as total_cost_cents
             FROM user_sessions
-            WHERE started_at >= NOW() - ($1 || ' days')::INTERVAL
+            WHERE started_at >= $1 AND started_at < $2
               AND is_bot = false
               AND is_scanner = false
               AND request_count > 0
             "#,
-            days.to_string()
+            window.start,
+            window.end
         )
         .fetch_one(&*self.pool)
         .await?;
@@ -51,7 +53,7 @@ impl TrafficRepository {
 
     pub async fn get_device_breakdown_with_trends(
         &self,
-        days: i32,
+        window: &TimeWindow,
     ) -> Result<Vec<DeviceBreakdownWithTrends>> {
         let rows = sqlx::query!(
             r#"
@@ -59,18 +61,19 @@ impl TrafficRepository {
                 COALESCE(device_type, 'unknown') as device_type,
                 COUNT(*) as sessions,
                 (COUNT(*)::float / NULLIF(SUM(COUNT(*)) OVER(), 0) * 100)::float8 as percentage,
-                COUNT(*) FILTER (WHERE started_at >= NOW() - INTERVAL '1 day') as traffic_1d,
-                COUNT(*) FILTER (WHERE started_at >= NOW() - INTERVAL '7 days') as traffic_7d,
-                COUNT(*) FILTER (WHERE started_at >= NOW() - INTERVAL '30 days') as traffic_30d
+                COUNT(*) FILTER (WHERE started_at >= $2 - INTERVAL '1 day') as traffic_1d,
+                COUNT(*) FILTER (WHERE started_at >= $2 - INTERVAL '7 days') as traffic_7d,
+                COUNT(*) FILTER (WHERE started_at >= $2 - INTERVAL '30 days') as traffic_30d
             FROM user_sessions
-            WHERE started_at >= NOW() - ($1 || ' days')::INTERVAL
+            WHERE started_at >= $1 AND started_at < $2
               AND is_bot = false
               AND is_scanner = false
               AND request_count > 0
             GROUP BY device_type
             ORDER BY sessions DESC
             "#,
-            days.to_string()
+            window.start,
+            window.end
         )
         .fetch_all(&*self.pool)
         .await?;
@@ -88,18 +91,21 @@ impl TrafficRepository {
             .collect())
     }
 
-    pub async fn get_geographic_breakdown(&self, days: i32) -> Result<Vec<GeographicBreakdown>> {
+    pub async fn get_geographic_breakdown(
+        &self,
+        window: &TimeWindow,
+    ) -> Result<Vec<GeographicBreakdown>> {
         let rows = sqlx::query!(
             r#"
             SELECT
                 COALESCE(country, 'Unknown') as country,
                 COUNT(*) as sessions,
                 (COUNT(*)::float / NULLIF(SUM(COUNT(*)) OVER(), 0) * 100)::float8 as percentage,
-                COUNT(*) FILTER (WHERE started_at >= NOW() - INTERVAL '1 day') as traffic_1d,
-                COUNT(*) FILTER (WHERE started_at >= NOW() - INTERVAL '7 days') as traffic_7d,
-                COUNT(*) FILTER (WHERE started_at >= NOW() - INTERVAL '30 days') as traffic_30d
+                COUNT(*) FILTER (WHERE started_at >= $2 - INTERVAL '1 day') as traffic_1d,
+                COUNT(*) FILTER (WHERE started_at >= $2 - INTERVAL '7 days') as traffic_7d,
+                COUNT(*) FILTER (WHERE started_at >= $2 - INTERVAL '30 days') as traffic_30d
             FROM user_sessions
-            WHERE started_at >= NOW() - ($1 || ' days')::INTERVAL
+            WHERE started_at >= $1 AND started_at < $2
               AND is_bot = false
               AND is_scanner = false
               AND request_count > 0
@@ -107,7 +113,8 @@ impl TrafficRepository {
             ORDER BY sessions DESC
             LIMIT 20
             "#,
-            days.to_string()
+            window.start,
+            window.end
         )
         .fetch_all(&*self.pool)
         .await?;
@@ -125,18 +132,21 @@ impl TrafficRepository {
             .collect())
     }
 
-    pub async fn get_browser_breakdown(&self, days: i32) -> Result<Vec<BrowserBreakdown>> {
+    pub async fn get_browser_breakdown(
+        &self,
+        window: &TimeWindow,
+    ) -> Result<Vec<BrowserBreakdown>> {
         let rows = sqlx::query!(
             r#"
             SELECT
                 COALESCE(browser, 'Unknown') as browser,
                 COUNT(*) as sessions,
                 (COUNT(*)::float / NULLIF(SUM(COUNT(*)) OVER(), 0) * 100)::float8 as percentage,
-                COUNT(*) FILTER (WHERE started_at >= NOW() - INTERVAL '1 day') as traffic_1d,
-                COUNT(*) FILTER (WHERE started_at >= NOW() - INTERVAL '7 days') as traffic_7d,
-                COUNT(*) FILTER (WHERE started_at >= NOW() - INTERVAL '30 days') as traffic_30d
+                COUNT(*) FILTER (WHERE started_at >= $2 - INTERVAL '1 day') as traffic_1d,
+                COUNT(*) FILTER (WHERE started_at >= $2 - INTERVAL '7 days') as traffic_7d,
+                COUNT(*) FILTER (WHERE started_at >= $2 - INTERVAL '30 days') as traffic_30d
             FROM user_sessions
-            WHERE started_at >= NOW() - ($1 || ' days')::INTERVAL
+            WHERE started_at >= $1 AND started_at < $2
               AND is_bot = false
               AND is_scanner = false
               AND request_count > 0
@@ -144,7 +154,8 @@ impl TrafficRepository {
             ORDER BY sessions DESC
             LIMIT 10
             "#,
-            days.to_string()
+            window.start,
+            window.end
         )
         .fetch_all(&*self.pool)
         .await?;
@@ -162,18 +173,18 @@ impl TrafficRepository {
             .collect())
     }
 
-    pub async fn get_os_breakdown(&self, days: i32) -> Result<Vec<OsBreakdown>> {
+    pub async fn get_os_breakdown(&self, window: &TimeWindow) -> Result<Vec<OsBreakdown>> {
         let rows = sqlx::query!(
             r#"
             SELECT
                 COALESCE(os, 'Unknown') as os,
                 COUNT(*) as sessions,
                 (COUNT(*)::float / NULLIF(SUM(COUNT(*)) OVER(), 0) * 100)::float8 as percentage,
-                COUNT(*) FILTER (WHERE started_at >= NOW() - INTERVAL '1 day') as traffic_1d,
-                COUNT(*) FILTER (WHERE started_at >= NOW() - INTERVAL '7 days') as traffic_7d,
-                COUNT(*) FILTER (WHERE started_at >= NOW() - INTERVAL '30 days') as traffic_30d
+                COUNT(*) FILTER (WHERE started_at >= $2 - INTERVAL '1 day') as traffic_1d,
+                COUNT(*) FILTER (WHERE started_at >= $2 - INTERVAL '7 days') as traffic_7d,
+                COUNT(*) FILTER (WHERE started_at >= $2 - INTERVAL '30 days') as traffic_30d
             FROM user_sessions
-            WHERE started_at >= NOW() - ($1 || ' days')::INTERVAL
+            WHERE started_at >= $1 AND started_at < $2
               AND is_bot = false
               AND is_scanner = false
               AND request_count > 0
@@ -181,7 +192,8 @@ impl TrafficRepository {
             ORDER BY sessions DESC
             LIMIT 10
             "#,
-            days.to_string()
+            window.start,
+            window.end
         )
         .fetch_all(&*self.pool)
         .await?;
@@ -199,7 +211,7 @@ impl TrafficRepository {
             .collect())
     }
 
-    pub async fn get_normalized_referrers(&self, days: i32) -> Result<Vec<Referrer>> {
+    pub async fn get_normalized_referrers(&self, window: &TimeWindow) -> Result<Vec<Referrer>> {
         let rows = sqlx::query!(
             r#"
             SELECT
@@ -209,7 +221,7 @@ impl TrafficRepository {
                 AVG(request_count)::float8 as avg_pages_per_session,
                 AVG(EXTRACT(EPOCH FROM (last_activity_at - started_at)))::float8 as avg_duration_sec
             FROM user_sessions
-            WHERE started_at >= NOW() - ($1 || ' days')::INTERVAL
+            WHERE started_at >= $1 AND started_at < $2
               AND is_bot = false
               AND is_scanner = false
               AND request_count > 0
@@ -217,7 +229,8 @@ impl TrafficRepository {
             ORDER BY sessions DESC
             LIMIT 20
             "#,
-            days.to_string()
+            window.start,
+            window.end
         )
         .fetch_all(&*self.pool)
         .await?;
@@ -233,4 +246,50 @@ impl TrafficRepository {
             })
             .collect())
     }
+
+    pub async fn get_user_sessions(
+        &self,
+        user_id: &str,
+        limit: i64,
+    ) -> Result<Vec<UserSessionActivity>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                session_id as "session_id!",
+                started_at as "started_at!",
+                last_activity_at,
+                COALESCE(device_type, 'unknown') as "device_type!",
+                COALESCE(country, 'Unknown') as "country!",
+                COALESCE(referrer_url, 'Direct') as "referrer!",
+                COALESCE(EXTRACT(EPOCH FROM (last_activity_at - started_at))::float8, 0) as "duration_seconds!",
+                COALESCE(request_count, 0)::int4 as "request_count!",
+                COALESCE(total_ai_cost_cents, 0)::bigint as "ai_cost_cents!",
+                (ended_at IS NULL) as "active!"
+            FROM user_sessions
+            WHERE user_id = $1
+            ORDER BY started_at DESC, session_id DESC
+            LIMIT $2
+            "#,
+            user_id,
+            limit
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| UserSessionActivity {
+                session_id: r.session_id,
+                started_at: r.started_at,
+                last_activity_at: r.last_activity_at,
+                device_type: r.device_type,
+                country: r.country,
+                referrer: r.referrer,
+                duration_seconds: r.duration_seconds,
+                request_count: r.request_count,
+                ai_cost_cents: r.ai_cost_cents,
+                active: r.active,
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,55 @@
+use serde_json::{json, Value as JsonValue};
+use systemprompt::models::artifacts::{DashboardArtifact, ToolResponse};
+
+use super::range::{COMPARISONS, GRANULARITIES, INCLUDES, MAX_SERIES_BUCKETS, PRESET_RANGES};
+
+#[must_use]
+pub fn traffic_input_schema() -> JsonValue {
+    json!({
+        "type": "object",
+        "properties": {
+            "time_range": {
+                "type": "string",
+                "enum": PRESET_RANGES,
+                "default": "30d",
+                "description": "Rolling time range for metrics: 7d, 30d, or 90d. Ignored when start is given"
+            },
+            "start": {
+                "type": "string",
+                "format": "date",
+                "description": "First day of a custom range (ISO date, YYYY-MM-DD), interpreted in timezone"
+            },
+            "end": {
+                "type": "string",
+                "format": "date",
+                "description": "Last day of a custom range, inclusive (ISO date). Defaults to now; requires start"
+            },
+            "timezone": {
+                "type": "string",
+                "default": "UTC",
+                "description": "IANA timezone for start and end, e.g. Europe/Berlin"
+            },
+            "compare_to": {
+                "type": "string",
+                "enum": COMPARISONS,
+                "description": "Compare the summary with the previous period of equal length or the same range a year earlier"
+            },
+            "granularity": {
+                "type": "string",
+                "enum": GRANULARITIES,
+                "default": "day",
+                "description": format!("Bucket size for the sessions, requests, unique users and AI cost charts. A range may span at most {MAX_SERIES_BUCKETS} buckets")
+            },
+            "include": {
+                "type": "string",
+                "enum": INCLUDES,
+                "description": "Set to 'bots' to add a report of the bot and scanner traffic excluded everywhere else: share of requests and breakdowns by user agent, country and endpoint"
+            }
+        }
+    })
+}
+
+#[must_use]
+pub fn traffic_output_schema() -> JsonValue {
+    ToolResponse::<DashboardArtifact>::schema()
+}
@@ -6,63 +6,8 @@ use systemprompt::models::artifacts::{
 
 use super::models::{
     BrowserBreakdown, DeviceBreakdownWithTrends, GeographicBreakdown, OsBreakdown, Referrer,
-    TrafficSummary,
 };
 
-pub fn create_traffic_summary_section(
-    summary: &TrafficSummary,
-) -> Result<DashboardSection, serde_json::Error> {
-    let cards = vec![
-        json!({
-            "title": "Total Sessions",
-            "value": summary.total_sessions.to_string(),
-            "icon": "sessions",
-            "status": "success"
-        }),
-        json!({
-            "title": "Total Requests",
-            "value": summary.total_requests.to_string(),
-            "icon": "activity",
-            "status": "success"
-        }),
-        json!({
-            "title": "Unique Users",
-            "value": summary.unique_users.to_string(),
-            "icon": "users",
-            "status": "success"
-        }),
-        json!({
-            "title": "Avg Session Duration",
-            "value": format!("{:.1}s", summary.avg_session_duration_secs),
-            "icon": "clock",
-            "status": "info"
-        }),
-        json!({
-            "title": "Avg Requests/Session",
-            "value": format!("{:.1}", summary.avg_requests_per_session),
-            "icon": "trending-up",
-            "status": "info"
-        }),
-        json!({
-            "title": "Total AI Cost",
-            "value": format!("${:.4}", f64::from(summary.total_cost_cents) / 1_000_000.0),
-            "icon": "dollar-sign",
-            "status": "warning"
-        }),
-    ];
-
-    Ok(DashboardSection::new(
-        "traffic_summary",
-        "Traffic Summary",
-        SectionType::MetricsCards,
-    )
-    .with_data(json!({ "cards": cards }))?
-    .with_layout(SectionLayout {
-        width: LayoutWidth::Full,
-        order: 1,
-    }))
-}
-
 pub fn create_device_breakdown_section(
     devices: &[DeviceBreakdownWithTrends],
 ) -> Result<DashboardSection, serde_json::Error> {
@@ -105,7 +50,7 @@ pub fn create_device_breakdown_section(
             .with_data(table.to_response())?
             .with_layout(SectionLayout {
                 width: LayoutWidth::Full,
-                order: 2,
+                order: 4,
             }),
     )
 }
@@ -155,7 +100,7 @@ pub fn create_geographic_breakdown_section(
     .with_data(table.to_response())?
     .with_layout(SectionLayout {
         width: LayoutWidth::Full,
-        order: 3,
+        order: 5,
     }))
 }
 
@@ -201,7 +146,7 @@ pub fn create_browser_breakdown_section(
             .with_data(table.to_response())?
             .with_layout(SectionLayout {
                 width: LayoutWidth::Full,
-                order: 4,
+                order: 6,
             }),
     )
 }
@@ -251,7 +196,7 @@ pub fn create_os_breakdown_section(
     .with_data(table.to_response())?
     .with_layout(SectionLayout {
         width: LayoutWidth::Full,
-        order: 5,
+        order: 7,
     }))
 }
 
@@ -290,7 +235,7 @@ pub fn create_top_referrers_section(
             .with_data(table.to_response())?
             .with_layout(SectionLayout {
                 width: LayoutWidth::Full,
-                order: 6,
+                order: 8,
             }),
     )
 }
@@ -0,0 +1,74 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use std::sync::Arc;
+use systemprompt::database::DbPool;
+
+use super::models::TrafficPoint;
+use super::range::Granularity;
+use super::windows::TimeWindow;
+
+pub struct SeriesRepository {
+    pool: Arc<PgPool>,
+}
+
+impl SeriesRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn get_traffic_series(
+        &self,
+        window: &TimeWindow,
+        granularity: Granularity,
+        timezone: &str,
+    ) -> Result<Vec<TrafficPoint>> {
+        window
+            .ensure_bucket_limit(granularity)
+            .map_err(anyhow::Error::msg)?;
+
+        sqlx::query_as!(
+            TrafficPoint,
+            r#"
+            WITH buckets AS (
+                SELECT generate_series(
+                    date_trunc($3, $1 AT TIME ZONE $4),
+                    date_trunc($3, ($2 - INTERVAL '1 microsecond') AT TIME ZONE $4),
+                    ('1 ' || $3)::interval
+                ) as bucket
+            ),
+            traffic AS (
+                SELECT
+                    date_trunc($3, started_at AT TIME ZONE $4) as bucket,
+                    COUNT(*) as sessions,
+                    SUM(request_count) as requests,
+                    COUNT(DISTINCT user_id) as unique_users,
+                    SUM(total_ai_cost_cents) as ai_cost_cents
+                FROM user_sessions
+                WHERE started_at >= $1 AND started_at < $2
+                  AND is_bot = false
+                  AND is_scanner = false
+                  AND request_count > 0
+                GROUP BY 1
+            )
+            SELECT
+                to_char(b.bucket, $5) as "bucket!",
+                COALESCE(t.sessions, 0)::bigint as "sessions!",
+                COALESCE(t.requests, 0)::bigint as "requests!",
+                COALESCE(t.unique_users, 0)::bigint as "unique_users!",
+                COALESCE(t.ai_cost_cents, 0)::bigint as "ai_cost_cents!"
+            FROM buckets b
+            LEFT JOIN traffic t ON t.bucket = b.bucket
+            ORDER BY b.bucket
+            "#,
+            window.start,
+            window.end,
+            granularity.as_str(),
+            timezone,
+            granularity.label_format()
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(Into::into)
+    }
+}
@@ -0,0 +1,143 @@
+use rmcp::ErrorData as McpError;
+use serde_json::{json, Value as JsonValue};
+use systemprompt::models::artifacts::{DashboardSection, LayoutWidth, SectionLayout, SectionType};
+
+use super::models::{percent_change, TrafficSummary};
+use super::range::{Comparison, TrafficRange};
+use super::repository::TrafficRepository;
+use super::windows::TimeWindow;
+
+struct SummaryMetric {
+    title: &'static str,
+    icon: &'static str,
+    status: &'static str,
+    value: f64,
+    format: fn(f64) -> String,
+}
+
+impl SummaryMetric {
+    fn new(title: &'static str, icon: &'static str, status: &'static str) -> Self {
+        Self {
+            title,
+            icon,
+            status,
+            value: 0.0,
+            format: |value| format!("{value:.0}"),
+        }
+    }
+
+    fn value(mut self, value: f64, format: fn(f64) -> String) -> Self {
+        self.value = value;
+        self.format = format;
+        self
+    }
+
+    fn count(self, value: i32) -> Self {
+        self.value(f64::from(value), |value| format!("{value:.0}"))
+    }
+
+    fn card(&self) -> JsonValue {
+        json!({
+            "title": self.title,
+            "value": (self.format)(self.value),
+            "icon": self.icon,
+            "status": self.status
+        })
+    }
+
+    fn compared_card(&self, previous: &Self, comparison: Comparison) -> JsonValue {
+        let delta = self.value - previous.value;
+        let percent = percent_change(self.value, previous.value);
+        let sign = if delta < 0.0 { "-" } else { "+" };
+        let change = percent.map_or_else(|| "new".to_string(), |percent| format!("{percent:+.1}%"));
+
+        let mut card = self.card();
+        card["previous"] = json!((previous.format)(previous.value));
+        card["delta"] = json!(delta);
+        card["percent_change"] = json!(percent);
+        card["trend"] = json!(format!(
+            "{sign}{} ({change}) vs {}",
+            (self.format)(delta.abs()),
+            comparison.label()
+        ));
+        card
+    }
+}
+
+fn summary_metrics(summary: &TrafficSummary) -> [SummaryMetric; 6] {
+    [
+        SummaryMetric::new("Total Sessions", "sessions", "success").count(summary.total_sessions),
+        SummaryMetric::new("Total Requests", "activity", "success").count(summary.total_requests),
+        SummaryMetric::new("Unique Users", "users", "success").count(summary.unique_users),
+        SummaryMetric::new("Avg Session Duration", "clock", "info")
+            .value(summary.avg_session_duration_secs, |value| {
+                format!("{value:.1}s")
+            }),
+        SummaryMetric::new("Avg Requests/Session", "trending-up", "info")
+            .value(summary.avg_requests_per_session, |value| {
+                format!("{value:.1}")
+            }),
+        SummaryMetric::new("Total AI Cost", "dollar-sign", "warning")
+            .value(summary.total_cost_dollars(), |value| format!("${value:.4}")),
+    ]
+}
+
+fn create_traffic_summary_section(
+    summary: &TrafficSummary,
+    comparison: Option<(&TrafficSummary, Comparison)>,
+) -> Result<DashboardSection, serde_json::Error> {
+    let current = summary_metrics(summary);
+    let cards: Vec<JsonValue> = match comparison {
+        Some((previous, comparison)) => current
+            .iter()
+            .zip(summary_metrics(previous).iter())
+            .map(|(metric, before)| metric.compared_card(before, comparison))
+            .collect(),
+        None => current.iter().map(SummaryMetric::card).collect(),
+    };
+
+    Ok(DashboardSection::new(
+        "traffic_summary",
+        "Traffic Summary",
+        SectionType::MetricsCards,
+    )
+    .with_data(json!({ "cards": cards }))?
+    .with_layout(SectionLayout {
+        width: LayoutWidth::Full,
+        order: 1,
+    }))
+}
+
+pub async fn summary_section(
+    repo: &TrafficRepository,
+    range: &TrafficRange,
+    window: &TimeWindow,
+) -> Result<DashboardSection, McpError> {
+    let traffic_summary = repo
+        .get_traffic_summary(window)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let previous_summary = match range.compare_to {
+        Some(comparison) => {
+            let previous = window
+                .previous(comparison)
+                .map_err(|e| McpError::invalid_params(e, None))?;
+            Some((
+                repo.get_traffic_summary(&previous)
+                    .await
+                    .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+                comparison,
+            ))
+        }
+        None => None,
+    };
+
+    create_traffic_summary_section(
+        &traffic_summary,
+        previous_summary
+            .as_ref()
+            .map(|(previous, comparison)| (previous, *comparison)),
+    )
+    .map_err(|e| McpError::internal_error(e.to_string(), None))
+}
@@ -0,0 +1,139 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Months, Utc};
+use sqlx::PgPool;
+use std::sync::Arc;
+use systemprompt::database::DbPool;
+
+use super::range::{Comparison, Granularity, TrafficPeriod, TrafficRange, MAX_SERIES_BUCKETS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ZonedBounds {
+    pub start_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl TimeWindow {
+    pub fn from_bounds(
+        period: TrafficPeriod,
+        bounds: ZonedBounds,
+        now: DateTime<Utc>,
+    ) -> Result<Self, String> {
+        let (start, end) = match period {
+            TrafficPeriod::LastDays(days) => (
+                now.checked_sub_signed(Duration::days(i64::from(days)))
+                    .ok_or_else(|| format!("The last {days} days reach past the clock"))?,
+                now,
+            ),
+            TrafficPeriod::Dates { start, end } => {
+                let start_at = bounds
+                    .start_at
+                    .ok_or_else(|| format!("Could not resolve start date {start}"))?;
+                let end_at = match end {
+                    Some(end) => bounds
+                        .end_at
+                        .ok_or_else(|| format!("Could not resolve end date {end}"))?
+                        .min(now),
+                    None => now,
+                };
+                (start_at, end_at)
+            }
+        };
+
+        if start >= now {
+            return Err(format!(
+                "Range starts in the future ({}); pick a start date up to today",
+                start.to_rfc3339()
+            ));
+        }
+        if start >= end {
+            return Err(format!(
+                "Range ends before it starts ({} to {})",
+                start.to_rfc3339(),
+                end.to_rfc3339()
+            ));
+        }
+        Ok(Self { start, end })
+    }
+
+    #[must_use]
+    pub fn bucket_count(&self, granularity: Granularity) -> i64 {
+        let step = granularity.step().num_seconds();
+        ((self.end - self.start).num_seconds() + step - 1) / step + 1
+    }
+
+    pub fn ensure_bucket_limit(&self, granularity: Granularity) -> Result<(), String> {
+        let buckets = self.bucket_count(granularity);
+        if buckets > MAX_SERIES_BUCKETS {
+            return Err(format!(
+                "Range needs {buckets} {} buckets but at most {MAX_SERIES_BUCKETS} are allowed; use a coarser granularity or a shorter range",
+                granularity.as_str()
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn previous(&self, comparison: Comparison) -> Result<Self, String> {
+        match comparison {
+            Comparison::PreviousPeriod => Ok(Self {
+                start: self
+                    .start
+                    .checked_sub_signed(self.end - self.start)
+                    .ok_or_else(|| "The previous period reaches past the clock".to_string())?,
+                end: self.start,
+            }),
+            Comparison::PreviousYear => Ok(Self {
+                start: shift_year(self.start)?,
+                end: shift_year(self.end)?,
+            }),
+        }
+    }
+}
+
+fn shift_year(at: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    at.checked_sub_months(Months::new(12))
+        .ok_or_else(|| format!("No date one year before {}", at.to_rfc3339()))
+}
+
+pub struct WindowRepository {
+    pool: Arc<PgPool>,
+}
+
+impl WindowRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn resolve(&self, range: &TrafficRange) -> Result<Option<ZonedBounds>> {
+        let (start, end) = match range.period {
+            TrafficPeriod::LastDays(_) => (None, None),
+            TrafficPeriod::Dates { start, end } => (Some(start), end),
+        };
+
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                ($1::date)::timestamp AT TIME ZONE z.name as "start_at: DateTime<Utc>",
+                ($2::date + 1)::timestamp AT TIME ZONE z.name as "end_at: DateTime<Utc>"
+            FROM pg_timezone_names z
+            WHERE z.name = $3
+            "#,
+            start,
+            end,
+            range.timezone
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        Ok(row.map(|r| ZonedBounds {
+            start_at: r.start_at,
+            end_at: r.end_at,
+        }))
+    }
+}
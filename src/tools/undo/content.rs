@@ -0,0 +1,113 @@
+use anyhow::Result;
+use serde_json::{json, Value as JsonValue};
+use sqlx::PgConnection;
+
+struct Dependent {
+    table: String,
+    column: String,
+}
+
+async fn dependents_on(conn: &mut PgConnection) -> Result<Vec<Dependent>> {
+    sqlx::query_as!(
+        Dependent,
+        r#"
+        SELECT
+            format('%I.%I', n.nspname, c.relname) as "table!",
+            quote_ident(a.attname) as "column!"
+        FROM pg_constraint k
+        JOIN pg_class c ON c.oid = k.conrelid
+        JOIN pg_namespace n ON n.oid = c.relnamespace
+        JOIN pg_attribute a ON a.attrelid = k.conrelid AND a.attnum = k.conkey[1]
+        WHERE k.contype = 'f'
+          AND k.confrelid = 'markdown_content'::regclass
+          AND k.confdeltype = 'c'
+          AND cardinality(k.conkey) = 1
+        ORDER BY 1, 2
+        "#
+    )
+    .fetch_all(conn)
+    .await
+    .map_err(Into::into)
+}
+
+pub async fn snapshot_content_on(conn: &mut PgConnection, id: &str) -> Result<Option<JsonValue>> {
+    let Some(content) = sqlx::query_scalar!(
+        r#"SELECT to_jsonb(mc) as "snapshot!" FROM markdown_content mc WHERE mc.id = $1 FOR UPDATE"#,
+        id
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    let mut dependents = Vec::new();
+    for dependent in dependents_on(conn).await? {
+        let rows: JsonValue = sqlx::query_scalar(&format!(
+            "SELECT COALESCE(jsonb_agg(to_jsonb(d)), '[]'::jsonb) FROM {} d WHERE {} = $1",
+            dependent.table, dependent.column
+        ))
+        .bind(id)
+        .fetch_one(&mut *conn)
+        .await?;
+        if rows.as_array().is_some_and(|rows| !rows.is_empty()) {
+            dependents.push(json!({ "table": dependent.table, "rows": rows }));
+        }
+    }
+
+    Ok(Some(
+        json!({ "content": content, "dependents": dependents }),
+    ))
+}
+
+pub async fn delete_content_on(conn: &mut PgConnection, id: &str) -> Result<()> {
+    sqlx::query!("DELETE FROM markdown_content WHERE id = $1", id)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+pub async fn restore_content_on(
+    conn: &mut PgConnection,
+    content_id: &str,
+    snapshot: &JsonValue,
+) -> Result<u64> {
+    let inserted = sqlx::query!(
+        r#"
+        INSERT INTO markdown_content
+        SELECT * FROM jsonb_populate_record(NULL::markdown_content, $1)
+        ON CONFLICT DO NOTHING
+        "#,
+        snapshot["content"]
+    )
+    .execute(&mut *conn)
+    .await?
+    .rows_affected();
+    if inserted == 0 {
+        anyhow::bail!("Content {content_id} exists again; nothing was restored");
+    }
+
+    let known: Vec<String> = dependents_on(conn)
+        .await?
+        .into_iter()
+        .map(|dependent| dependent.table)
+        .collect();
+    for dependent in snapshot["dependents"].as_array().into_iter().flatten() {
+        let table = dependent["table"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Content snapshot names no dependent table"))?;
+        if !known.iter().any(|name| name == table) {
+            anyhow::bail!(
+                "Cannot restore rows of {table}: it no longer references markdown_content"
+            );
+        }
+        sqlx::query(&format!(
+            "INSERT INTO {table} SELECT * FROM jsonb_populate_recordset(NULL::{table}, $1)"
+        ))
+        .bind(&dependent["rows"])
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(inserted)
+}
@@ -0,0 +1,24 @@
+pub mod content;
+pub mod models;
+pub mod repository;
+
+use rmcp::model::{CallToolResult, Content};
+
+use models::IssuedUndo;
+
+#[must_use]
+pub fn attach_undo(mut result: CallToolResult, undo: &IssuedUndo) -> CallToolResult {
+    result.content.push(Content::text(format!(
+        "Undo with operations action 'undo' and token {} until {}.",
+        undo.token,
+        undo.expires_at.to_rfc3339()
+    )));
+    if let Some(object) = result
+        .structured_content
+        .as_mut()
+        .and_then(|value| value.as_object_mut())
+    {
+        object.insert("undo".to_string(), undo.to_json());
+    }
+    result
+}
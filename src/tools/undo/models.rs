@@ -0,0 +1,126 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+
+use crate::services::user_tokens::{generate_token, hash_token};
+use crate::tools::logs::filter::parse_relative_duration;
+
+pub const UNDO_TTL_ENV: &str = "ADMIN_UNDO_TTL";
+pub const DEFAULT_UNDO_TTL_MINUTES: i64 = 30;
+pub const MAX_UNDO_TTL_DAYS: i64 = 7;
+
+pub fn parse_undo_ttl(value: Option<&str>) -> Result<Duration, String> {
+    let Some(value) = value else {
+        return Ok(Duration::minutes(DEFAULT_UNDO_TTL_MINUTES));
+    };
+    let ttl = parse_relative_duration(value.trim()).ok_or_else(|| {
+        format!("Invalid {UNDO_TTL_ENV} '{value}': expected a duration such as 30m, 2h or 1d")
+    })?;
+    if ttl <= Duration::zero() || ttl > Duration::days(MAX_UNDO_TTL_DAYS) {
+        return Err(format!(
+            "Invalid {UNDO_TTL_ENV} '{value}': must be more than zero and at most {MAX_UNDO_TTL_DAYS}d"
+        ));
+    }
+    Ok(ttl)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssuedUndo {
+    pub token: String,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl IssuedUndo {
+    pub fn issue(now: DateTime<Utc>, ttl: Duration) -> Result<Self, String> {
+        let expires_at = now
+            .checked_add_signed(ttl)
+            .ok_or_else(|| format!("Undo token lifetime {ttl} overflows the clock"))?;
+        let token = generate_token();
+        Ok(Self {
+            token_hash: hash_token(&token),
+            token,
+            expires_at,
+        })
+    }
+
+    #[must_use]
+    pub fn to_json(&self) -> JsonValue {
+        json!({
+            "token": self.token,
+            "expires_at": self.expires_at.to_rfc3339(),
+            "how": "call operations with action 'undo' and this token before it expires"
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UndoAction {
+    RestoreFile {
+        file_id: String,
+    },
+    RestoreContent {
+        content_id: String,
+        snapshot: JsonValue,
+    },
+    RestoreRoles {
+        user_id: String,
+        roles_before: Vec<String>,
+        roles_after: Vec<String>,
+    },
+    RestoreStatus {
+        user_id: String,
+        status_before: String,
+        status_after: String,
+    },
+}
+
+impl UndoAction {
+    #[must_use]
+    pub fn target_id(&self) -> &str {
+        match self {
+            Self::RestoreFile { file_id } => file_id,
+            Self::RestoreContent { content_id, .. } => content_id,
+            Self::RestoreRoles { user_id, .. } | Self::RestoreStatus { user_id, .. } => user_id,
+        }
+    }
+
+    #[must_use]
+    pub fn describe(&self) -> String {
+        match self {
+            Self::RestoreFile { file_id } => format!("restored file {file_id}"),
+            Self::RestoreContent { content_id, .. } => format!("restored content {content_id}"),
+            Self::RestoreRoles {
+                user_id,
+                roles_before,
+                ..
+            } => format!(
+                "restored roles of user {user_id} to [{}]",
+                roles_before.join(", ")
+            ),
+            Self::RestoreStatus {
+                user_id,
+                status_before,
+                ..
+            } => format!("restored user {user_id} to status '{status_before}'"),
+        }
+    }
+}
+
+pub fn check_redeemable(
+    used_at: Option<DateTime<Utc>>,
+    expires_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<(), String> {
+    if let Some(used_at) = used_at {
+        return Err(format!(
+            "Undo token was already used at {}",
+            used_at.to_rfc3339()
+        ));
+    }
+    if expires_at <= now {
+        return Err(format!("Undo token expired at {}", expires_at.to_rfc3339()));
+    }
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use sqlx::types::Uuid;
+use sqlx::{PgConnection, PgPool};
+use std::sync::Arc;
+use systemprompt::database::DbPool;
+
+use crate::services::user_tokens::hash_token;
+use crate::tools::audit::models::{AuditEntry, AuditScope};
+use crate::tools::audit::repository::record_audit;
+
+use super::content::restore_content_on;
+use super::models::{IssuedUndo, UndoAction};
+
+#[derive(Debug, Clone)]
+pub struct UndoRecord {
+    pub actor_id: String,
+    pub tool: String,
+    pub action: String,
+    pub target_id: String,
+    pub undo: UndoAction,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+pub async fn store_undo(
+    conn: &mut PgConnection,
+    issued: &IssuedUndo,
+    origin: &AuditEntry,
+    undo: &UndoAction,
+) -> Result<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO admin_undo_tokens
+            (token_hash, actor_id, tool, action, target_id, undo, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        "#,
+        issued.token_hash,
+        origin.actor_id,
+        origin.tool,
+        origin.action,
+        undo.target_id(),
+        serde_json::to_value(undo)?,
+        issued.expires_at
+    )
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+pub struct UndoRepository {
+    pool: Arc<PgPool>,
+}
+
+impl UndoRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn find(&self, token: &str) -> Result<Option<UndoRecord>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT actor_id, tool, action, target_id, undo,
+                   created_at as "created_at: DateTime<Utc>",
+                   expires_at as "expires_at: DateTime<Utc>",
+                   used_at as "used_at: DateTime<Utc>"
+            FROM admin_undo_tokens
+            WHERE token_hash = $1
+            "#,
+            hash_token(token)
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        row.map(|r| {
+            Ok(UndoRecord {
+                actor_id: r.actor_id,
+                tool: r.tool,
+                action: r.action,
+                target_id: r.target_id,
+                undo: serde_json::from_value(r.undo)?,
+                created_at: r.created_at,
+                expires_at: r.expires_at,
+                used_at: r.used_at,
+            })
+        })
+        .transpose()
+    }
+
+    pub async fn redeem(
+        &self,
+        token: &str,
+        record: &UndoRecord,
+        audit: &AuditScope,
+    ) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        let claimed = sqlx::query!(
+            r#"
+            UPDATE admin_undo_tokens SET used_at = NOW()
+            WHERE token_hash = $1 AND used_at IS NULL AND expires_at > NOW()
+            "#,
+            hash_token(token)
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if claimed == 0 || !restore_on(&mut tx, &record.undo).await? {
+            return Ok(false);
+        }
+
+        let entry = audit.entry(&record.target_id).with_result(json!({
+            "undone_tool": record.tool,
+            "undone_action": record.action,
+            "original_actor_id": record.actor_id,
+            "undo": record.undo
+        }));
+        record_audit(&mut tx, &entry).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+}
+
+async fn restore_on(conn: &mut PgConnection, undo: &UndoAction) -> Result<bool> {
+    let restored = match undo {
+        UndoAction::RestoreFile { file_id } => sqlx::query!(
+            "UPDATE files SET deleted_at = NULL WHERE id = $1 AND deleted_at IS NOT NULL",
+            Uuid::parse_str(file_id)?
+        )
+        .execute(conn)
+        .await?
+        .rows_affected(),
+        UndoAction::RestoreContent {
+            content_id,
+            snapshot,
+        } => restore_content_on(conn, content_id, snapshot).await?,
+        UndoAction::RestoreRoles {
+            user_id,
+            roles_before,
+            roles_after,
+        } => sqlx::query!(
+            "UPDATE users SET roles = $2 WHERE id = $1 AND roles = $3",
+            user_id,
+            roles_before,
+            roles_after
+        )
+        .execute(conn)
+        .await?
+        .rows_affected(),
+        UndoAction::RestoreStatus {
+            user_id,
+            status_before,
+            status_after,
+        } => restore_status_on(conn, user_id, status_before, status_after).await?,
+    };
+    Ok(restored > 0)
+}
+
+async fn restore_status_on(
+    conn: &mut PgConnection,
+    user_id: &str,
+    status_before: &str,
+    status_after: &str,
+) -> Result<u64> {
+    let restored = sqlx::query!(
+        "UPDATE users SET status = $2 WHERE id = $1 AND status = $3",
+        user_id,
+        status_before,
+        status_after
+    )
+    .execute(&mut *conn)
+    .await?
+    .rows_affected();
+    if restored > 0 && status_after == "suspended" {
+        sqlx::query!(
+            "UPDATE admin_user_suspensions SET lifted_at = NOW() WHERE user_id = $1 AND lifted_at IS NULL",
+            user_id
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(restored)
+}
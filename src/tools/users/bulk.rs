@@ -0,0 +1,224 @@
+use rmcp::{
+    model::{CallToolResult, Content},
+    ErrorData as McpError,
+};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::{ArtifactId, McpExecutionId};
+use systemprompt::models::artifacts::{
+    Column, ColumnType, ExecutionMetadata, TableArtifact, ToolResponse,
+};
+
+use crate::tools::undo::models::IssuedUndo;
+use crate::tools::ToolState;
+
+use super::filter::parse_user_filter;
+use super::models::{UserSort, UserSortColumn};
+use super::plan::{plan_bulk, BulkOperation, PlannedChange};
+use super::repository::UsersRepository;
+
+const BULK_MAX_USERS: i64 = 1000;
+
+struct BulkRun {
+    operation: BulkOperation,
+    changes: Vec<PlannedChange>,
+    dry_run: bool,
+    applied: u64,
+    undos: Vec<IssuedUndo>,
+}
+
+pub async fn handle_bulk_users(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    state: &ToolState,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let now = chrono::Utc::now();
+    let filter_args = args
+        .get("filter")
+        .and_then(JsonValue::as_object)
+        .ok_or_else(|| McpError::invalid_params("filter is required for bulk", None))?;
+    let filter =
+        parse_user_filter(filter_args, now).map_err(|e| McpError::invalid_params(e, None))?;
+    if filter.is_empty() {
+        return Err(McpError::invalid_params(
+            "bulk requires at least one filter field; use status 'all' to target every user explicitly",
+            None,
+        ));
+    }
+    let operation = BulkOperation::parse(args, &state.role_names(), now)
+        .map_err(|e| McpError::invalid_params(e, None))?;
+    let dry_run = args
+        .get("dry_run")
+        .and_then(JsonValue::as_bool)
+        .unwrap_or(false);
+
+    let repo = UsersRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let sort = UserSort {
+        column: UserSortColumn::CreatedAt,
+        ascending: true,
+    };
+    let users = repo
+        .list_users(&filter, sort, BULK_MAX_USERS, None)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    if users.next_cursor.is_some() {
+        return Err(McpError::invalid_params(
+            format!("Filter matches more than {BULK_MAX_USERS} users; narrow it and run bulk in batches"),
+            None,
+        ));
+    }
+
+    let mut run = BulkRun {
+        changes: plan_bulk(&users.items, &operation),
+        operation,
+        dry_run,
+        applied: 0,
+        undos: Vec::new(),
+    };
+    if !dry_run {
+        apply_run(&repo, &mut run, args, state).await?;
+    }
+
+    tracing::info!(
+        operation = run.operation.name(),
+        dry_run = dry_run,
+        matched = run.changes.len(),
+        applied = run.applied,
+        "Bulk user operation"
+    );
+
+    Ok(bulk_result(&run, mcp_execution_id))
+}
+
+async fn apply_run(
+    repo: &UsersRepository,
+    run: &mut BulkRun,
+    args: &JsonMap<String, JsonValue>,
+    state: &ToolState,
+) -> Result<(), McpError> {
+    let pending = run.changes.iter().filter(|c| c.applies()).count();
+    run.undos = (0..pending)
+        .map(|_| state.issue_undo())
+        .collect::<Result<Vec<_>, _>>()?;
+    let audit = state.audit_scope("user", &format!("bulk.{}", run.operation.name()), args);
+    run.applied = repo
+        .apply_bulk(&run.changes, &run.operation, &audit, &run.undos)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    Ok(())
+}
+
+fn bulk_result(run: &BulkRun, mcp_execution_id: &McpExecutionId) -> CallToolResult {
+    let matched = run.changes.len();
+    let pending = run.changes.iter().filter(|c| c.applies()).count();
+    let skipped = matched - pending;
+
+    let metadata = ExecutionMetadata::new().tool("users");
+    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
+    let artifact = TableArtifact::new(bulk_columns())
+        .with_rows(
+            run.changes
+                .iter()
+                .map(|c| change_row(c, run.dry_run))
+                .collect(),
+        )
+        .with_metadata(metadata.clone());
+    let mut structured = ToolResponse::new(
+        artifact_id,
+        mcp_execution_id.clone(),
+        artifact,
+        metadata.clone(),
+    )
+    .to_json();
+    if let Some(object) = structured.as_object_mut() {
+        object.insert(
+            "bulk".to_string(),
+            json!({
+                "operation": run.operation.name(),
+                "dry_run": run.dry_run,
+                "matched": matched,
+                "pending": pending,
+                "applied": run.applied,
+                "skipped": skipped
+            }),
+        );
+        if !run.undos.is_empty() {
+            object.insert("undo".to_string(), undo_json(run));
+        }
+    }
+
+    let mut content = vec![Content::text(if run.dry_run {
+        format!(
+            "Dry run: bulk {} would change {pending} of {matched} matching users ({skipped} skipped). Nothing was modified.",
+            run.operation.name()
+        )
+    } else {
+        format!(
+            "Bulk {} applied to {} of {matched} matching users ({skipped} skipped).",
+            run.operation.name(),
+            run.applied
+        )
+    })];
+    if let Some(first) = run.undos.first() {
+        content.push(Content::text(format!(
+            "Undo any user's change with operations action 'undo' and that user's token from `undo` until {}.",
+            first.expires_at.to_rfc3339()
+        )));
+    }
+
+    CallToolResult {
+        content,
+        structured_content: Some(structured),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    }
+}
+
+fn undo_json(run: &BulkRun) -> JsonValue {
+    run.changes
+        .iter()
+        .filter(|c| c.applies())
+        .zip(&run.undos)
+        .map(|(change, undo)| {
+            let mut entry = undo.to_json();
+            if let Some(object) = entry.as_object_mut() {
+                object.insert("user_id".to_string(), json!(change.user_id));
+            }
+            entry
+        })
+        .collect()
+}
+
+fn bulk_columns() -> Vec<Column> {
+    vec![
+        Column::new("user_id", ColumnType::String).with_label("ID"),
+        Column::new("name", ColumnType::String).with_label("Name"),
+        Column::new("email", ColumnType::String).with_label("Email"),
+        Column::new("status_before", ColumnType::String).with_label("Status"),
+        Column::new("status_after", ColumnType::String).with_label("New Status"),
+        Column::new("roles_before", ColumnType::String).with_label("Roles"),
+        Column::new("roles_after", ColumnType::String).with_label("New Roles"),
+        Column::new("outcome", ColumnType::String).with_label("Outcome"),
+    ]
+}
+
+fn change_row(change: &PlannedChange, dry_run: bool) -> JsonValue {
+    let outcome = match (&change.skip_reason, dry_run) {
+        (Some(reason), _) => format!("skipped: {reason}"),
+        (None, true) => "would apply".to_string(),
+        (None, false) => "applied".to_string(),
+    };
+
+    json!({
+        "user_id": change.user_id,
+        "name": change.name,
+        "email": change.email,
+        "status_before": change.status_before,
+        "status_after": change.status_after,
+        "roles_before": change.roles_before.join(", "),
+        "roles_after": change.roles_after.join(", "),
+        "outcome": outcome
+    })
+}
@@ -0,0 +1,163 @@
+use rmcp::{
+    model::{CallToolResult, Content},
+    ErrorData as McpError,
+};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::McpExecutionId;
+use systemprompt::models::artifacts::ExecutionMetadata;
+
+use crate::services::user_tokens::{issue_token, IssuedToken, TokenMessage, TokenPurpose};
+use crate::services::{TokenDelivery, TOKEN_OUTBOX_ENV};
+use crate::tools::ToolState;
+
+use super::repository::UsersRepository;
+use super::validation::parse_new_user;
+
+fn parse_send(args: &JsonMap<String, JsonValue>) -> Result<Option<TokenPurpose>, McpError> {
+    match args.get("send").and_then(JsonValue::as_str) {
+        None | Some("none") => Ok(None),
+        Some(value) => TokenPurpose::parse(value).map(Some).ok_or_else(|| {
+            McpError::invalid_params(
+                format!("Invalid send: {value}. Expected none, invite or password_reset"),
+                None,
+            )
+        }),
+    }
+}
+
+fn send_channel<'a>(
+    state: &'a ToolState,
+    send: Option<TokenPurpose>,
+) -> Result<Option<(TokenPurpose, &'a dyn TokenDelivery)>, McpError> {
+    let Some(purpose) = send else {
+        return Ok(None);
+    };
+    let delivery = state.token_delivery.as_deref().ok_or_else(|| {
+        McpError::invalid_params(
+            format!(
+                "Cannot send a {} token: no token delivery channel is configured (set {TOKEN_OUTBOX_ENV}). Use send 'none'",
+                purpose.as_str()
+            ),
+            None,
+        )
+    })?;
+    Ok(Some((purpose, delivery)))
+}
+
+async fn ensure_email_available(users: &UsersRepository, email: &str) -> Result<(), McpError> {
+    let exists = users
+        .email_exists(email)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    if exists {
+        return Err(McpError::invalid_params(
+            format!("A user with email {email} already exists"),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+pub async fn handle_create_user(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    state: &ToolState,
+    _mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let new_user =
+        parse_new_user(args, &state.role_names()).map_err(|e| McpError::invalid_params(e, None))?;
+    let channel = send_channel(state, parse_send(args)?)?;
+
+    let users = UsersRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    ensure_email_available(&users, &new_user.email).await?;
+
+    let issued = channel.map(|(purpose, _)| issue_token(purpose, chrono::Utc::now()));
+    let stored_token = issued
+        .as_ref()
+        .zip(channel.map(|(_, delivery)| delivery.channel()));
+    let user_id = users
+        .create_user(
+            &new_user,
+            stored_token,
+            &state.audit_scope("user", "create", args),
+        )
+        .await
+        .map_err(|e| McpError::internal_error(format!("Failed to create user: {e}"), None))?;
+    tracing::info!(user_id = %user_id, roles = ?new_user.roles, actor_id = %state.caller.user_id, "User created");
+
+    let token = match (channel, issued) {
+        (Some((_, delivery)), Some(issued)) => {
+            Some(deliver_token(delivery, &user_id, &new_user.email, issued)?)
+        }
+        _ => None,
+    };
+
+    let user = users
+        .find_user(&user_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .ok_or_else(|| {
+            McpError::internal_error(
+                format!("Created user {user_id} could not be read back"),
+                None,
+            )
+        })?;
+
+    let metadata = ExecutionMetadata::new().tool("users");
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "Created user '{}' ({}) with roles [{}]{}",
+            user.name,
+            user_id,
+            user.roles.join(", "),
+            channel.map_or_else(String::new, |(purpose, delivery)| format!(
+                "; {} token sent via {}",
+                purpose.as_str(),
+                delivery.channel()
+            ))
+        ))],
+        structured_content: Some(json!({
+            "success": true,
+            "action": "create",
+            "user": user,
+            "token": token
+        })),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
+
+fn deliver_token(
+    delivery: &dyn TokenDelivery,
+    user_id: &str,
+    email: &str,
+    issued: IssuedToken,
+) -> Result<JsonValue, McpError> {
+    let purpose = issued.purpose;
+    let expires_at = issued.expires_at;
+    delivery
+        .deliver(&TokenMessage {
+            user_id: user_id.to_string(),
+            email: email.to_string(),
+            purpose,
+            token: issued.token,
+            expires_at,
+        })
+        .map_err(|e| {
+            McpError::internal_error(
+                format!(
+                    "User {user_id} was created but the {} token could not be delivered: {e}",
+                    purpose.as_str()
+                ),
+                None,
+            )
+        })?;
+
+    Ok(json!({
+        "purpose": purpose.as_str(),
+        "channel": delivery.channel(),
+        "expires_at": expires_at.to_rfc3339()
+    }))
+}
@@ -0,0 +1,153 @@
+use rmcp::{
+    model::{CallToolResult, Content},
+    ErrorData as McpError,
+};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::{ArtifactId, McpExecutionId};
+use systemprompt::models::artifacts::{
+    DashboardArtifact, DashboardHints, ExecutionMetadata, LayoutMode, ToolResponse,
+};
+
+use crate::tools::conversations::models::RecentConversation;
+use crate::tools::conversations::repository::ConversationsRepository;
+use crate::tools::logs::models::{LogEntry, LogFilter};
+use crate::tools::logs::repository::LogsRepository;
+use crate::tools::operations::repository::{FileListing, OperationsRepository};
+use crate::tools::traffic::models::UserSessionActivity;
+use crate::tools::traffic::repository::TrafficRepository;
+
+use super::models::Suspension;
+use super::repository::UsersRepository;
+use super::sections::{
+    create_conversations_section, create_error_logs_section, create_files_section,
+    create_profile_section, create_sessions_section,
+};
+use super::suspensions::SuspensionRepository;
+
+const DETAIL_ROW_LIMIT: i64 = 20;
+
+struct UserActivity {
+    suspension: Option<Suspension>,
+    sessions: Vec<UserSessionActivity>,
+    conversations: Vec<RecentConversation>,
+    errors: Vec<LogEntry>,
+    files: Vec<FileListing>,
+}
+
+pub async fn handle_get_user(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let user_id = args
+        .get("user_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::invalid_params("user_id is required for get", None))?;
+
+    tracing::debug!(user_id = %user_id, "Loading user detail");
+
+    let users = UsersRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let user = users
+        .find_user(user_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .ok_or_else(|| McpError::invalid_params(format!("User not found: {user_id}"), None))?;
+
+    let UserActivity {
+        suspension,
+        sessions,
+        conversations,
+        errors,
+        files,
+    } = load_activity(pool, user_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let dashboard = DashboardArtifact::new(format!("User: {}", user.name))
+        .with_description(format!(
+            "Profile, sessions, conversations, errors and files for {}",
+            user.email
+        ))
+        .with_hints(
+            DashboardHints::new()
+                .with_layout(LayoutMode::Vertical)
+                .with_refreshable(true)
+                .with_refresh_interval(60),
+        )
+        .add_section(
+            create_profile_section(&user, suspension.as_ref(), &sessions)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_sessions_section(&sessions)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_conversations_section(&conversations)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_error_logs_section(&errors)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        )
+        .add_section(
+            create_files_section(&files)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?,
+        );
+
+    let metadata = ExecutionMetadata::new().tool("users");
+    let artifact_id = ArtifactId::new(uuid::Uuid::new_v4().to_string());
+    let tool_response = ToolResponse::new(
+        artifact_id,
+        mcp_execution_id.clone(),
+        dashboard,
+        metadata.clone(),
+    );
+
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "User '{}' ({}): status {}, roles [{}], {} recent sessions, {} conversations, {} recent errors, {} files",
+            user.name,
+            user_id,
+            user.status,
+            user.roles.join(", "),
+            sessions.len(),
+            conversations.len(),
+            errors.len(),
+            files.len()
+        ))],
+        structured_content: Some(tool_response.to_json()),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
+
+async fn load_activity(pool: &DbPool, user_id: &str) -> anyhow::Result<UserActivity> {
+    let suspensions = SuspensionRepository::new(pool.clone())?;
+    let traffic = TrafficRepository::new(pool.clone())?;
+    let contexts = ConversationsRepository::new(pool.clone())?;
+    let logs = LogsRepository::new(pool.clone())?;
+    let operations = OperationsRepository::new(pool.clone())?;
+    let error_filter = LogFilter {
+        user_id: Some(user_id.to_string()),
+        ..LogFilter::with_level("ERROR")
+    };
+
+    let (suspension, sessions, conversations, errors, files) = tokio::try_join!(
+        suspensions.find_active_suspension(user_id),
+        traffic.get_user_sessions(user_id, DETAIL_ROW_LIMIT),
+        contexts.get_user_conversations(user_id, DETAIL_ROW_LIMIT),
+        logs.fetch_recent_logs(&error_filter, DETAIL_ROW_LIMIT, None),
+        operations.list_user_files(user_id, DETAIL_ROW_LIMIT),
+    )?;
+
+    Ok(UserActivity {
+        suspension,
+        sessions,
+        conversations,
+        errors: errors.items,
+        files,
+    })
+}
@@ -0,0 +1,152 @@
+use chrono::{DateTime, Utc};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+use crate::tools::logs::filter::{parse_relative_duration, parse_time_bound};
+
+use super::models::{UserFilter, UserSort, UserSortColumn};
+
+pub const USER_STATUSES: [&str; 5] = ["active", "inactive", "suspended", "deleted", "all"];
+
+fn string_arg(args: &JsonMap<String, JsonValue>, key: &str) -> Option<String> {
+    args.get(key)
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(String::from)
+}
+
+pub fn parse_user_filter(
+    args: &JsonMap<String, JsonValue>,
+    now: DateTime<Utc>,
+) -> Result<UserFilter, String> {
+    let status = string_arg(args, "status");
+    if let Some(status) = &status {
+        if !USER_STATUSES.contains(&status.as_str()) {
+            return Err(format!(
+                "Invalid status: {status}. Expected one of {}",
+                USER_STATUSES.join(", ")
+            ));
+        }
+    }
+
+    let created_after = string_arg(args, "created_after")
+        .map(|v| parse_time_bound(&v, now))
+        .transpose()?;
+    let created_before = string_arg(args, "created_before")
+        .map(|v| parse_time_bound(&v, now))
+        .transpose()?;
+    if let (Some(after), Some(before)) = (created_after, created_before) {
+        if after >= before {
+            return Err("'created_after' must be earlier than 'created_before'".to_string());
+        }
+    }
+
+    let last_active_before = string_arg(args, "last_active_before")
+        .map(|v| parse_time_bound(&v, now))
+        .transpose()?;
+
+    let min_sessions = match args.get("min_sessions") {
+        None | Some(JsonValue::Null) => None,
+        Some(value) => Some(
+            value
+                .as_i64()
+                .filter(|v| *v >= 0)
+                .ok_or_else(|| "min_sessions must be a non-negative integer".to_string())?,
+        ),
+    };
+
+    Ok(UserFilter {
+        user_id: string_arg(args, "user_id"),
+        email: string_arg(args, "email"),
+        name: string_arg(args, "name"),
+        role: string_arg(args, "role"),
+        status,
+        created_after,
+        created_before,
+        min_sessions,
+        last_active_before,
+    })
+}
+
+pub fn parse_user_sort(args: &JsonMap<String, JsonValue>) -> Result<UserSort, String> {
+    let column = match string_arg(args, "sort") {
+        None => UserSortColumn::default(),
+        Some(value) => UserSortColumn::parse(&value).ok_or_else(|| {
+            format!(
+                "Invalid sort: {value}. Expected one of {}",
+                UserSortColumn::ALL.map(UserSortColumn::as_str).join(", ")
+            )
+        })?,
+    };
+
+    let ascending = match string_arg(args, "order").as_deref() {
+        None | Some("desc") => false,
+        Some("asc") => true,
+        Some(other) => return Err(format!("Invalid order: {other}. Expected asc or desc")),
+    };
+
+    Ok(UserSort { column, ascending })
+}
+
+pub fn parse_expiry(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    let expires_at = match DateTime::parse_from_rfc3339(value) {
+        Ok(timestamp) => timestamp.with_timezone(&Utc),
+        Err(_) => {
+            let duration = parse_relative_duration(value).ok_or_else(|| {
+                format!("Invalid expires_at '{value}': expected RFC 3339 or e.g. 12h, 7d")
+            })?;
+            now.checked_add_signed(duration)
+                .ok_or_else(|| format!("Invalid expires_at '{value}': too far in the future"))?
+        }
+    };
+
+    if expires_at <= now {
+        return Err("expires_at must be in the future".to_string());
+    }
+    Ok(expires_at)
+}
+
+pub(super) fn filter_schema_properties(role_names: &[String]) -> JsonMap<String, JsonValue> {
+    let properties = json!({
+        "email": {
+            "type": "string",
+            "description": "Case-insensitive substring match on email"
+        },
+        "name": {
+            "type": "string",
+            "description": "Case-insensitive substring match on name or display name"
+        },
+        "role": {
+            "type": "string",
+            "enum": role_names,
+            "description": "Only users holding this role"
+        },
+        "status": {
+            "type": "string",
+            "enum": USER_STATUSES,
+            "description": "Filter by status. Deleted users are hidden unless status is 'deleted' or 'all'"
+        },
+        "created_after": {
+            "type": "string",
+            "description": "Only users created at or after this time: RFC 3339 timestamp or relative duration such as 7d"
+        },
+        "created_before": {
+            "type": "string",
+            "description": "Only users created before this time: RFC 3339 timestamp or relative duration such as 30d"
+        },
+        "min_sessions": {
+            "type": "integer",
+            "minimum": 0,
+            "description": "Only users with at least this many sessions"
+        },
+        "last_active_before": {
+            "type": "string",
+            "description": "Only users with no session activity since this time, including users who never had a session: RFC 3339 timestamp or relative duration such as 90d"
+        }
+    });
+
+    match properties {
+        JsonValue::Object(properties) => properties,
+        _ => JsonMap::new(),
+    }
+}
@@ -0,0 +1,287 @@
+use rmcp::{
+    model::{CallToolResult, Content},
+    ErrorData as McpError, Peer, RoleServer,
+};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::identifiers::McpExecutionId;
+use systemprompt::models::artifacts::ExecutionMetadata;
+
+use crate::tools::confirmation::models::DeletionPreview;
+use crate::tools::confirmation::{confirm_deletion, Confirmation};
+use crate::tools::undo::attach_undo;
+use crate::tools::ToolState;
+
+use super::filter::parse_expiry;
+use super::models::{Suspension, User};
+use super::repository::UsersRepository;
+use super::suspensions::SuspensionRepository;
+
+fn required_user_id<'a>(
+    args: &'a JsonMap<String, JsonValue>,
+    action: &str,
+) -> Result<&'a str, McpError> {
+    args.get("user_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::invalid_params(format!("user_id is required for {action}"), None))
+}
+
+pub(super) async fn load_user(pool: &DbPool, user_id: &str) -> Result<User, McpError> {
+    UsersRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .find_user(user_id)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .ok_or_else(|| McpError::invalid_params(format!("User not found: {user_id}"), None))
+}
+
+fn suspensions(pool: &DbPool) -> Result<SuspensionRepository, McpError> {
+    SuspensionRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))
+}
+
+pub async fn handle_delete_user(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    peer: &Peer<RoleServer>,
+    state: &ToolState,
+    mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let user_id_str = required_user_id(args, "delete")?;
+    let user = load_user(pool, user_id_str).await?;
+    if user.status == "deleted" {
+        return Err(McpError::invalid_params(
+            format!("User {user_id_str} is already deleted"),
+            None,
+        ));
+    }
+
+    let preview = DeletionPreview::new("user", "delete", &user.id, format!("user {}", user.email))
+        .with_field("ID", &user.id)
+        .with_field("Name", &user.name)
+        .with_field("Email", &user.email)
+        .with_field("Status", &user.status)
+        .with_field("Roles", user.roles.join(", "))
+        .with_field("Sessions", user.total_sessions.to_string())
+        .with_field("Created", &user.created_at);
+    if let Confirmation::Respond(result) =
+        confirm_deletion(pool, peer, state, args, &preview, mcp_execution_id).await?
+    {
+        return Ok(result);
+    }
+
+    let audit = state
+        .audit_scope("user", "delete", args)
+        .entry(&user.id)
+        .with_change(
+            json!({ "status": user.status }),
+            json!({ "status": "deleted" }),
+        );
+    let undo = state.issue_undo()?;
+    let deleted = UsersRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .soft_delete(&user.id, &user.status, &audit, &undo)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    if !deleted {
+        return Err(McpError::invalid_request(
+            format!("User {user_id_str} changed concurrently; reload and retry"),
+            None,
+        ));
+    }
+
+    tracing::info!(user_id = %user_id_str, user_name = %user.name, actor_id = %state.caller.user_id, "User deleted (soft delete)");
+
+    let metadata = ExecutionMetadata::new().tool("users");
+    let result = CallToolResult {
+        content: vec![Content::text(format!(
+            "Successfully deleted user '{}' ({}). User status has been set to 'deleted'.",
+            user.name, user_id_str
+        ))],
+        structured_content: Some(json!({
+            "success": true,
+            "action": "delete",
+            "user_id": user_id_str,
+            "user_name": user.name
+        })),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    };
+    Ok(attach_undo(result, &undo))
+}
+
+pub async fn handle_suspend_user(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    state: &ToolState,
+    _mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let user_id = required_user_id(args, "suspend")?;
+    let reason = args
+        .get("reason")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| McpError::invalid_params("reason is required for suspend", None))?;
+    let expires_at = args
+        .get("expires_at")
+        .and_then(|v| v.as_str())
+        .map(|v| parse_expiry(v, chrono::Utc::now()))
+        .transpose()
+        .map_err(|e| McpError::invalid_params(e, None))?;
+
+    let user = load_user(pool, user_id).await?;
+    if user.status == "suspended" || user.status == "deleted" {
+        return Err(McpError::invalid_params(
+            format!(
+                "User '{}' is {} and cannot be suspended",
+                user.name, user.status
+            ),
+            None,
+        ));
+    }
+
+    let undo = state.issue_undo()?;
+    let suspension = suspensions(pool)?
+        .suspend_user(
+            user_id,
+            reason,
+            expires_at,
+            &state.audit_scope("user", "suspend", args),
+            &undo,
+        )
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .ok_or_else(|| {
+            McpError::invalid_params(format!("User status changed concurrently: {user_id}"), None)
+        })?;
+
+    tracing::info!(
+        user_id = %user_id,
+        ended_sessions = suspension.ended_sessions,
+        expires_at = ?suspension.expires_at,
+        actor_id = %state.caller.user_id,
+        "User suspended"
+    );
+
+    Ok(attach_undo(
+        suspend_result(&user, user_id, &suspension),
+        &undo,
+    ))
+}
+
+pub async fn handle_reactivate_user(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    state: &ToolState,
+    _mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let user_id = required_user_id(args, "reactivate")?;
+    let user = load_user(pool, user_id).await?;
+    if user.status != "suspended" {
+        return Err(McpError::invalid_params(
+            format!("User '{}' is {}, not suspended", user.name, user.status),
+            None,
+        ));
+    }
+
+    let status = suspensions(pool)?
+        .reactivate_user(user_id, &state.audit_scope("user", "reactivate", args))
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .ok_or_else(|| {
+            McpError::invalid_params(format!("User status changed concurrently: {user_id}"), None)
+        })?;
+
+    tracing::info!(user_id = %user_id, status = %status, actor_id = %state.caller.user_id, "User reactivated");
+
+    let metadata = ExecutionMetadata::new().tool("users");
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "Reactivated user '{}' ({}). Status is now '{}'.",
+            user.name, user_id, status
+        ))],
+        structured_content: Some(json!({
+            "success": true,
+            "action": "reactivate",
+            "user_id": user_id,
+            "user_name": user.name,
+            "status": status
+        })),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
+
+pub async fn handle_restore_user(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    state: &ToolState,
+    _mcp_execution_id: &McpExecutionId,
+) -> Result<CallToolResult, McpError> {
+    let user_id = required_user_id(args, "restore")?;
+    let user = load_user(pool, user_id).await?;
+    if user.status != "deleted" {
+        return Err(McpError::invalid_params(
+            format!("User '{}' is {}, not deleted", user.name, user.status),
+            None,
+        ));
+    }
+
+    let status = suspensions(pool)?
+        .restore_user(user_id, &state.audit_scope("user", "restore", args))
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .ok_or_else(|| {
+            McpError::invalid_params(format!("User status changed concurrently: {user_id}"), None)
+        })?;
+
+    tracing::info!(user_id = %user_id, status = %status, actor_id = %state.caller.user_id, "User restored");
+
+    let metadata = ExecutionMetadata::new().tool("users");
+    Ok(CallToolResult {
+        content: vec![Content::text(format!(
+            "Restored user '{}' ({}). Status is now '{}'.",
+            user.name, user_id, status
+        ))],
+        structured_content: Some(json!({
+            "success": true,
+            "action": "restore",
+            "user_id": user_id,
+            "user_name": user.name,
+            "status": status
+        })),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    })
+}
+
+fn suspend_result(user: &User, user_id: &str, suspension: &Suspension) -> CallToolResult {
+    let metadata = ExecutionMetadata::new().tool("users");
+    CallToolResult {
+        content: vec![Content::text(format!(
+            "Suspended user '{}' ({}) {}. Reason: {}. Marked {} active sessions ended.",
+            user.name,
+            user_id,
+            suspension.expires_at.map_or_else(
+                || "indefinitely".to_string(),
+                |at| format!("until {}", at.to_rfc3339())
+            ),
+            suspension.reason,
+            suspension.ended_sessions
+        ))],
+        structured_content: Some(json!({
+            "success": true,
+            "action": "suspend",
+            "user_id": user_id,
+            "user_name": user.name,
+            "previous_status": suspension.previous_status,
+            "reason": suspension.reason,
+            "suspended_at": suspension.suspended_at.to_rfc3339(),
+            "expires_at": suspension.expires_at.map(|at| at.to_rfc3339()),
+            "ended_sessions": suspension.ended_sessions
+        })),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    }
+}
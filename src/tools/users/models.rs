@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,4 +11,118 @@ pub struct User {
     pub roles: Vec<String>,
     pub total_sessions: i64,
     pub created_at: String,
+    pub last_active: Option<String>,
+    #[serde(skip)]
+    pub sort_key: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Suspension {
+    pub user_id: String,
+    pub reason: String,
+    pub previous_status: String,
+    pub suspended_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub ended_sessions: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UserSortColumn {
+    #[default]
+    CreatedAt,
+    LastActive,
+    Name,
+    Email,
+    TotalSessions,
+}
+
+impl UserSortColumn {
+    pub const ALL: [Self; 5] = [
+        Self::CreatedAt,
+        Self::LastActive,
+        Self::Name,
+        Self::Email,
+        Self::TotalSessions,
+    ];
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::CreatedAt => "created_at",
+            Self::LastActive => "last_active",
+            Self::Name => "name",
+            Self::Email => "email",
+            Self::TotalSessions => "total_sessions",
+        }
+    }
+
+    #[must_use]
+    pub fn is_activity(self) -> bool {
+        matches!(self, Self::LastActive | Self::TotalSessions)
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|column| column.as_str() == value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UserSort {
+    pub column: UserSortColumn,
+    pub ascending: bool,
+}
+
+impl UserSort {
+    #[must_use]
+    pub fn cursor_name(self) -> String {
+        format!(
+            "{}:{}",
+            self.column.as_str(),
+            if self.ascending { "asc" } else { "desc" }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UserFilter {
+    pub user_id: Option<String>,
+    pub email: Option<String>,
+    pub name: Option<String>,
+    pub role: Option<String>,
+    pub status: Option<String>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub min_sessions: Option<i64>,
+    pub last_active_before: Option<DateTime<Utc>>,
+}
+
+impl UserFilter {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.user_id.is_none()
+            && self.email.is_none()
+            && self.name.is_none()
+            && self.role.is_none()
+            && self.status.is_none()
+            && self.created_after.is_none()
+            && self.created_before.is_none()
+            && self.min_sessions.is_none()
+            && self.last_active_before.is_none()
+    }
+
+    #[must_use]
+    pub fn filters_activity(&self) -> bool {
+        self.min_sessions.is_some() || self.last_active_before.is_some()
+    }
+
+    #[must_use]
+    pub fn by_id(user_id: &str) -> Self {
+        Self {
+            user_id: Some(user_id.to_string()),
+            ..Self::default()
+        }
+    }
 }
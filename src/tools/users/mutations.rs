@@ -0,0 +1,124 @@
+use anyhow::Result;
+use serde_json::json;
+use sqlx::PgConnection;
+
+use crate::services::user_tokens::IssuedToken;
+use crate::tools::audit::models::AuditScope;
+use crate::tools::audit::repository::record_audit;
+use crate::tools::undo::models::IssuedUndo;
+use crate::tools::undo::repository::store_undo;
+
+use super::plan::{BulkOperation, PlannedChange};
+use super::suspensions::suspend_on;
+use super::tokens::store_token_on;
+use super::validation::NewUser;
+
+pub async fn record_creation_on(
+    conn: &mut PgConnection,
+    user_id: &str,
+    new_user: &NewUser,
+    token: Option<(&IssuedToken, &str)>,
+    audit: &AuditScope,
+) -> Result<()> {
+    if let Some((token, delivery_channel)) = token {
+        store_token_on(&mut *conn, user_id, token, delivery_channel).await?;
+    }
+    let entry = audit.entry(user_id).with_after(json!({
+        "name": new_user.name,
+        "email": new_user.email,
+        "display_name": new_user.display_name,
+        "roles": new_user.roles,
+        "token": token.map(|(token, _)| token.purpose.as_str())
+    }));
+    record_audit(conn, &entry).await
+}
+
+pub async fn set_roles_on(
+    conn: &mut PgConnection,
+    user_id: &str,
+    roles_before: &[String],
+    roles_after: &[String],
+) -> Result<bool> {
+    let updated = sqlx::query!(
+        "UPDATE users SET roles = $2 WHERE id = $1 AND roles = $3",
+        user_id,
+        roles_after,
+        roles_before
+    )
+    .execute(&mut *conn)
+    .await?
+    .rows_affected();
+
+    Ok(updated > 0)
+}
+
+pub async fn set_status_on(
+    conn: &mut PgConnection,
+    user_id: &str,
+    status_before: &str,
+    status_after: &str,
+) -> Result<bool> {
+    let updated = sqlx::query!(
+        "UPDATE users SET status = $2 WHERE id = $1 AND status = $3",
+        user_id,
+        status_after,
+        status_before
+    )
+    .execute(&mut *conn)
+    .await?
+    .rows_affected();
+
+    Ok(updated > 0)
+}
+
+pub async fn apply_bulk_on(
+    conn: &mut PgConnection,
+    changes: &[PlannedChange],
+    operation: &BulkOperation,
+    audit: &AuditScope,
+    undos: &[IssuedUndo],
+) -> Result<u64> {
+    let pending: Vec<&PlannedChange> = changes.iter().filter(|c| c.applies()).collect();
+    anyhow::ensure!(
+        pending.len() == undos.len(),
+        "Bulk {} needs one undo token per change",
+        operation.name()
+    );
+    let mut applied = 0;
+
+    for (change, undo) in pending.into_iter().zip(undos) {
+        let updated = match operation {
+            BulkOperation::AssignRole(_) | BulkOperation::RemoveRole(_) => {
+                set_roles_on(
+                    conn,
+                    &change.user_id,
+                    &change.roles_before,
+                    &change.roles_after,
+                )
+                .await?
+            }
+            BulkOperation::Delete => {
+                set_status_on(conn, &change.user_id, &change.status_before, "deleted").await?
+            }
+            BulkOperation::Suspend { reason, expires_at } => {
+                suspend_on(conn, &change.user_id, reason, *expires_at)
+                    .await?
+                    .is_some()
+            }
+        };
+
+        if !updated {
+            anyhow::bail!(
+                "User {} changed while the bulk {} was running; no changes were applied",
+                change.user_id,
+                operation.name()
+            );
+        }
+        let entry = change.audit_entry(audit);
+        record_audit(conn, &entry).await?;
+        store_undo(conn, undo, &entry, &change.undo_action()).await?;
+        applied += 1;
+    }
+
+    Ok(applied)
+}
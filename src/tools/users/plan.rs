@@ -0,0 +1,191 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+use crate::tools::audit::models::{AuditEntry, AuditScope};
+use crate::tools::undo::models::UndoAction;
+
+use super::filter::parse_expiry;
+use super::models::User;
+
+pub const BULK_OPERATIONS: [&str; 4] = ["assign_role", "remove_role", "suspend", "delete"];
+pub const LAST_ROLE_ERROR: &str =
+    "Cannot remove the last role from a user. Users must have at least one role.";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BulkOperation {
+    AssignRole(String),
+    RemoveRole(String),
+    Suspend {
+        reason: String,
+        expires_at: Option<DateTime<Utc>>,
+    },
+    Delete,
+}
+
+impl BulkOperation {
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::AssignRole(_) => "assign_role",
+            Self::RemoveRole(_) => "remove_role",
+            Self::Suspend { .. } => "suspend",
+            Self::Delete => "delete",
+        }
+    }
+
+    pub fn parse(
+        args: &JsonMap<String, JsonValue>,
+        known_roles: &[String],
+        now: DateTime<Utc>,
+    ) -> Result<Self, String> {
+        let text = |key: &str| {
+            args.get(key)
+                .and_then(JsonValue::as_str)
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+        };
+        let operation = text("operation").ok_or("operation is required for bulk")?;
+        let role = || {
+            text("role")
+                .map(String::from)
+                .ok_or_else(|| format!("role is required for bulk {operation}"))
+        };
+
+        match operation {
+            "assign_role" => {
+                let role = role()?;
+                if !known_roles.contains(&role) {
+                    return Err(format!(
+                        "Unknown role: {role}. Discovered roles: {}",
+                        known_roles.join(", ")
+                    ));
+                }
+                Ok(Self::AssignRole(role))
+            }
+            "remove_role" => Ok(Self::RemoveRole(role()?)),
+            "suspend" => Ok(Self::Suspend {
+                reason: text("reason")
+                    .map(String::from)
+                    .ok_or("reason is required for bulk suspend")?,
+                expires_at: text("expires_at")
+                    .map(|v| parse_expiry(v, now))
+                    .transpose()?,
+            }),
+            "delete" => Ok(Self::Delete),
+            other => Err(format!(
+                "Invalid operation: {other}. Expected one of {}",
+                BULK_OPERATIONS.join(", ")
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedChange {
+    pub user_id: String,
+    pub name: String,
+    pub email: String,
+    pub status_before: String,
+    pub status_after: String,
+    pub roles_before: Vec<String>,
+    pub roles_after: Vec<String>,
+    pub skip_reason: Option<String>,
+}
+
+impl PlannedChange {
+    #[must_use]
+    pub fn applies(&self) -> bool {
+        self.skip_reason.is_none()
+    }
+
+    #[must_use]
+    pub fn undo_action(&self) -> UndoAction {
+        if self.roles_before == self.roles_after {
+            UndoAction::RestoreStatus {
+                user_id: self.user_id.clone(),
+                status_before: self.status_before.clone(),
+                status_after: self.status_after.clone(),
+            }
+        } else {
+            UndoAction::RestoreRoles {
+                user_id: self.user_id.clone(),
+                roles_before: self.roles_before.clone(),
+                roles_after: self.roles_after.clone(),
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn audit_entry(&self, scope: &AuditScope) -> AuditEntry {
+        scope.entry(&self.user_id).with_change(
+            json!({ "status": self.status_before, "roles": self.roles_before }),
+            json!({ "status": self.status_after, "roles": self.roles_after }),
+        )
+    }
+}
+
+pub fn roles_without(roles: &[String], role: &str) -> Result<Vec<String>, &'static str> {
+    let remaining: Vec<String> = roles.iter().filter(|r| *r != role).cloned().collect();
+    if remaining.is_empty() {
+        return Err(LAST_ROLE_ERROR);
+    }
+    Ok(remaining)
+}
+
+#[must_use]
+pub fn plan_change(user: &User, operation: &BulkOperation) -> PlannedChange {
+    let mut change = PlannedChange {
+        user_id: user.id.clone(),
+        name: user.name.clone(),
+        email: user.email.clone(),
+        status_before: user.status.clone(),
+        status_after: user.status.clone(),
+        roles_before: user.roles.clone(),
+        roles_after: user.roles.clone(),
+        skip_reason: None,
+    };
+
+    let skip_reason = match operation {
+        BulkOperation::AssignRole(role) if user.roles.contains(role) => {
+            Some(format!("already has role '{role}'"))
+        }
+        BulkOperation::AssignRole(role) => {
+            change.roles_after.push(role.clone());
+            None
+        }
+        BulkOperation::RemoveRole(role) if !user.roles.contains(role) => {
+            Some(format!("does not have role '{role}'"))
+        }
+        BulkOperation::RemoveRole(role) => match roles_without(&user.roles, role) {
+            Ok(roles) => {
+                change.roles_after = roles;
+                None
+            }
+            Err(reason) => Some(reason.to_string()),
+        },
+        BulkOperation::Suspend { .. } if user.status == "suspended" || user.status == "deleted" => {
+            Some(format!("already {}", user.status))
+        }
+        BulkOperation::Suspend { .. } => {
+            change.status_after = "suspended".to_string();
+            None
+        }
+        BulkOperation::Delete if user.status == "deleted" => Some("already deleted".to_string()),
+        BulkOperation::Delete => {
+            change.status_after = "deleted".to_string();
+            None
+        }
+    };
+
+    change.skip_reason = skip_reason;
+    change
+}
+
+#[must_use]
+pub fn plan_bulk(users: &[User], operation: &BulkOperation) -> Vec<PlannedChange> {
+    users
+        .iter()
+        .map(|user| plan_change(user, operation))
+        .collect()
+}
@@ -1,55 +1,296 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use sqlx::PgPool;
 use std::sync::Arc;
 use systemprompt::database::DbPool;
+use systemprompt::users::UserRepository;
 
-use super::models::User;
+use crate::services::user_tokens::IssuedToken;
+use crate::tools::pagination::{Page, SortCursor};
+
+use crate::tools::audit::models::{AuditEntry, AuditScope};
+use crate::tools::audit::repository::record_audit;
+use crate::tools::undo::models::{IssuedUndo, UndoAction};
+use crate::tools::undo::repository::store_undo;
+
+use super::models::{User, UserFilter, UserSort};
+use super::mutations::{apply_bulk_on, record_creation_on, set_roles_on, set_status_on};
+use super::plan::{BulkOperation, PlannedChange};
+use super::validation::NewUser;
 
 pub struct UsersRepository {
     pool: Arc<PgPool>,
+    db: DbPool,
 }
 
 impl UsersRepository {
     pub fn new(db: DbPool) -> Result<Self> {
         let pool = db.pool_arc()?;
-        Ok(Self { pool })
+        Ok(Self { pool, db })
+    }
+
+    pub async fn find_user(&self, user_id: &str) -> Result<Option<User>> {
+        let filter = UserFilter {
+            user_id: Some(user_id.to_string()),
+            status: Some("all".to_string()),
+            ..UserFilter::default()
+        };
+        let page = self
+            .list_users(&filter, UserSort::default(), 1, None)
+            .await?;
+        Ok(page.items.into_iter().next())
     }
 
-    pub async fn list_users(&self, user_id: Option<&str>) -> Result<Vec<User>> {
+    pub async fn email_exists(&self, email: &str) -> Result<bool> {
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM users WHERE LOWER(email) = LOWER($1)) as "exists!""#,
+            email
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        Ok(exists)
+    }
+
+    pub async fn list_users(
+        &self,
+        filter: &UserFilter,
+        sort: UserSort,
+        limit: i64,
+        cursor: Option<&SortCursor>,
+    ) -> Result<Page<User, SortCursor>> {
         let rows = sqlx::query!(
             r#"
+            WITH filtered AS (
+                SELECT
+                    u.id,
+                    u.name,
+                    u.email,
+                    u.display_name,
+                    u.status,
+                    u.roles,
+                    u.created_at,
+                    CASE $9::text
+                        WHEN 'name' THEN LOWER(u.name)
+                        WHEN 'email' THEN LOWER(u.email)
+                        ELSE TO_CHAR(u.created_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS.US')
+                    END as profile_key
+                FROM users u
+                WHERE ($1::text IS NULL OR u.id = $1)
+                  AND ($2::text IS NULL OR strpos(LOWER(u.email), LOWER($2)) > 0)
+                  AND ($3::text IS NULL
+                       OR strpos(LOWER(u.name), LOWER($3)) > 0
+                       OR strpos(LOWER(COALESCE(u.display_name, '')), LOWER($3)) > 0)
+                  AND ($4::text IS NULL OR $4 = ANY(u.roles))
+                  AND (CASE
+                        WHEN $5::text IS NULL THEN u.status != 'deleted'
+                        WHEN $5 = 'all' THEN true
+                        ELSE u.status = $5
+                      END)
+                  AND ($6::timestamptz IS NULL OR u.created_at >= $6)
+                  AND ($7::timestamptz IS NULL OR u.created_at < $7)
+            ),
+            candidates AS (
+                SELECT *
+                FROM filtered
+                WHERE $15::bool
+                   OR $10::text IS NULL
+                   OR (CASE
+                        WHEN $11::bool THEN (profile_key, id) > ($10, $12::text)
+                        ELSE (profile_key, id) < ($10, $12::text)
+                      END)
+                ORDER BY
+                    CASE WHEN $11 THEN profile_key END ASC,
+                    CASE WHEN $11 THEN id END ASC,
+                    CASE WHEN NOT $11 THEN profile_key END DESC,
+                    CASE WHEN NOT $11 THEN id END DESC
+                LIMIT CASE WHEN $15 THEN NULL ELSE $13::bigint END
+            ),
+            keyed AS (
+                SELECT
+                    c.*,
+                    COALESCE(s.total_sessions, 0) as total_sessions,
+                    s.last_active,
+                    CASE $9
+                        WHEN 'total_sessions' THEN LPAD(COALESCE(s.total_sessions, 0)::text, 20, '0')
+                        WHEN 'last_active' THEN COALESCE(TO_CHAR(s.last_active AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS.US'), '')
+                        ELSE c.profile_key
+                    END as sort_key
+                FROM candidates c
+                LEFT JOIN LATERAL (
+                    SELECT COUNT(*) as total_sessions, MAX(last_activity_at) as last_active
+                    FROM user_sessions
+                    WHERE user_id = c.id
+                ) s ON true
+                WHERE ($8::bigint IS NULL OR COALESCE(s.total_sessions, 0) >= $8)
+                  AND ($14::timestamptz IS NULL OR s.last_active IS NULL OR s.last_active < $14)
+            )
             SELECT
-                u.id,
-                u.name,
-                u.email,
-                u.display_name,
-                u.status,
-                u.roles as "roles: Vec<String>",
-                u.created_at::text as created_at,
-                COALESCE((SELECT COUNT(*) FROM user_sessions s WHERE s.user_id = u.id), 0) as total_sessions
-            FROM users u
-            WHERE ($1::text IS NULL OR u.id = $1)
-              AND u.status != 'deleted'
-            ORDER BY u.created_at DESC
-            LIMIT 100
+                id as "id!",
+                name as "name!",
+                email as "email!",
+                display_name,
+                status as "status!",
+                roles as "roles!: Vec<String>",
+                created_at::text as "created_at!",
+                total_sessions as "total_sessions!",
+                last_active::text as last_active,
+                sort_key as "sort_key!"
+            FROM keyed
+            WHERE ($10 IS NULL OR (CASE
+                    WHEN $11 THEN (sort_key, id) > ($10, $12)
+                    ELSE (sort_key, id) < ($10, $12)
+                  END))
+            ORDER BY
+                CASE WHEN $11 THEN sort_key END ASC,
+                CASE WHEN $11 THEN id END ASC,
+                CASE WHEN NOT $11 THEN sort_key END DESC,
+                CASE WHEN NOT $11 THEN id END DESC
+            LIMIT $13
             "#,
-            user_id
+            filter.user_id.as_deref(),
+            filter.email.as_deref(),
+            filter.name.as_deref(),
+            filter.role.as_deref(),
+            filter.status.as_deref(),
+            filter.created_after,
+            filter.created_before,
+            filter.min_sessions,
+            sort.column.as_str(),
+            cursor.map(|c| c.key.as_str()),
+            sort.ascending,
+            cursor.map(|c| c.id.as_str()),
+            limit + 1,
+            filter.last_active_before,
+            sort.column.is_activity() || filter.filters_activity()
         )
         .fetch_all(&*self.pool)
         .await?;
 
-        Ok(rows
-            .into_iter()
-            .map(|r| User {
+        let cursor_name = sort.cursor_name();
+        Ok(Page::from_rows(
+            rows,
+            limit as usize,
+            |r| SortCursor::new(cursor_name.clone(), r.sort_key.clone(), r.id.clone()),
+            |r| User {
                 id: r.id,
                 name: r.name,
                 email: r.email,
                 display_name: r.display_name,
                 status: r.status,
                 roles: r.roles,
-                total_sessions: r.total_sessions.unwrap_or(0),
-                created_at: r.created_at.unwrap_or_default(),
-            })
-            .collect())
+                total_sessions: r.total_sessions,
+                created_at: r.created_at,
+                last_active: r.last_active,
+                sort_key: r.sort_key,
+            },
+        ))
+    }
+
+    pub async fn create_user(
+        &self,
+        new_user: &NewUser,
+        token: Option<(&IssuedToken, &str)>,
+        audit: &AuditScope,
+    ) -> Result<String> {
+        let core = UserRepository::new(&self.db)?;
+        let created = core
+            .create(
+                &new_user.name,
+                &new_user.email,
+                None,
+                new_user.display_name.as_deref(),
+            )
+            .await?;
+        let user_id = created.id.to_string();
+
+        let recorded = match core.assign_roles(&created.id, &new_user.roles).await {
+            Ok(_) => self.record_creation(&user_id, new_user, token, audit).await,
+            Err(e) => Err(e.into()),
+        };
+        if let Err(e) = recorded {
+            self.discard_user(&user_id).await.with_context(|| {
+                format!("Creating user {user_id} failed ({e}) and the partial user was not removed")
+            })?;
+            return Err(e);
+        }
+        Ok(user_id)
+    }
+
+    async fn record_creation(
+        &self,
+        user_id: &str,
+        new_user: &NewUser,
+        token: Option<(&IssuedToken, &str)>,
+        audit: &AuditScope,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        record_creation_on(&mut tx, user_id, new_user, token, audit).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn discard_user(&self, user_id: &str) -> Result<()> {
+        sqlx::query!("DELETE FROM users WHERE id = $1", user_id)
+            .execute(&*self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn update_roles(
+        &self,
+        user_id: &str,
+        roles_before: &[String],
+        roles_after: &[String],
+        audit: &AuditEntry,
+        undo: &IssuedUndo,
+    ) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        if !set_roles_on(&mut tx, user_id, roles_before, roles_after).await? {
+            return Ok(false);
+        }
+        record_audit(&mut tx, audit).await?;
+        let restore = UndoAction::RestoreRoles {
+            user_id: user_id.to_string(),
+            roles_before: roles_before.to_vec(),
+            roles_after: roles_after.to_vec(),
+        };
+        store_undo(&mut tx, undo, audit, &restore).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    pub async fn soft_delete(
+        &self,
+        user_id: &str,
+        status_before: &str,
+        audit: &AuditEntry,
+        undo: &IssuedUndo,
+    ) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        if !set_status_on(&mut tx, user_id, status_before, "deleted").await? {
+            return Ok(false);
+        }
+        record_audit(&mut tx, audit).await?;
+        let restore = UndoAction::RestoreStatus {
+            user_id: user_id.to_string(),
+            status_before: status_before.to_string(),
+            status_after: "deleted".to_string(),
+        };
+        store_undo(&mut tx, undo, audit, &restore).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    pub async fn apply_bulk(
+        &self,
+        changes: &[PlannedChange],
+        operation: &BulkOperation,
+        audit: &AuditScope,
+        undos: &[IssuedUndo],
+    ) -> Result<u64> {
+        let mut tx = self.pool.begin().await?;
+        let applied = apply_bulk_on(&mut tx, changes, operation, audit, undos).await?;
+        tx.commit().await?;
+        Ok(applied)
     }
 }
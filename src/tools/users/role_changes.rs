@@ -0,0 +1,159 @@
+use rmcp::{
+    model::{CallToolResult, Content},
+    ErrorData as McpError,
+};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use systemprompt::database::DbPool;
+use systemprompt::models::artifacts::ExecutionMetadata;
+
+use crate::tools::undo::attach_undo;
+use crate::tools::undo::models::IssuedUndo;
+use crate::tools::ToolState;
+
+use super::lifecycle::load_user;
+use super::models::User;
+use super::plan::roles_without;
+use super::repository::UsersRepository;
+
+pub async fn handle_assign_role(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    state: &ToolState,
+) -> Result<CallToolResult, McpError> {
+    let (user_id, role) = role_change_args(args, "assign_role")?;
+    let user = load_user(pool, user_id).await?;
+
+    if user.roles.iter().any(|r| r == role) {
+        return Ok(unchanged_result("assign_role", &user, role));
+    }
+    let mut roles: Vec<String> = user.roles.clone();
+    roles.push(role.to_string());
+
+    let undo = update_roles(pool, &user, &roles, state, args, "assign_role").await?;
+    tracing::info!(user_id = %user_id, role = %role, actor_id = %state.caller.user_id, "Role assigned to user");
+
+    Ok(attach_undo(
+        role_change_result("assign_role", &user, role, &roles),
+        &undo,
+    ))
+}
+
+pub async fn handle_remove_role(
+    pool: &DbPool,
+    args: &JsonMap<String, JsonValue>,
+    state: &ToolState,
+) -> Result<CallToolResult, McpError> {
+    let (user_id, role) = role_change_args(args, "remove_role")?;
+    let user = load_user(pool, user_id).await?;
+
+    if !user.roles.iter().any(|r| r == role) {
+        return Ok(unchanged_result("remove_role", &user, role));
+    }
+    let roles = roles_without(&user.roles, role).map_err(|e| McpError::invalid_params(e, None))?;
+
+    let undo = update_roles(pool, &user, &roles, state, args, "remove_role").await?;
+    tracing::info!(user_id = %user_id, role = %role, actor_id = %state.caller.user_id, "Role removed from user");
+
+    Ok(attach_undo(
+        role_change_result("remove_role", &user, role, &roles),
+        &undo,
+    ))
+}
+
+fn role_change_args<'a>(
+    args: &'a JsonMap<String, JsonValue>,
+    action: &str,
+) -> Result<(&'a str, &'a str), McpError> {
+    let user_id = args
+        .get("user_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| {
+            McpError::invalid_params(format!("user_id is required for {action}"), None)
+        })?;
+    let role = args
+        .get("role")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::invalid_params(format!("role is required for {action}"), None))?;
+    Ok((user_id, role))
+}
+
+async fn update_roles(
+    pool: &DbPool,
+    user: &User,
+    roles: &[String],
+    state: &ToolState,
+    args: &JsonMap<String, JsonValue>,
+    action: &str,
+) -> Result<IssuedUndo, McpError> {
+    let entry = state
+        .audit_scope("user", action, args)
+        .entry(&user.id)
+        .with_change(json!({ "roles": user.roles }), json!({ "roles": roles }));
+    let undo = state.issue_undo()?;
+    let updated = UsersRepository::new(pool.clone())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .update_roles(&user.id, &user.roles, roles, &entry, &undo)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    if !updated {
+        return Err(McpError::invalid_request(
+            format!(
+                "Roles of user {} changed concurrently; reload and retry",
+                user.id
+            ),
+            None,
+        ));
+    }
+    Ok(undo)
+}
+
+fn role_change_result(action: &str, user: &User, role: &str, roles: &[String]) -> CallToolResult {
+    let (verb, preposition) = if action == "assign_role" {
+        ("assigned", "to")
+    } else {
+        ("removed", "from")
+    };
+    let metadata = ExecutionMetadata::new().tool("users");
+    CallToolResult {
+        content: vec![Content::text(format!(
+            "Successfully {verb} role '{role}' {preposition} user '{}' ({})\nCurrent roles: {roles:?}",
+            user.name, user.id
+        ))],
+        structured_content: Some(json!({
+            "success": true,
+            "action": action,
+            "user_id": user.id,
+            "role": role,
+            "current_roles": roles,
+            "changed": true
+        })),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    }
+}
+
+fn unchanged_result(action: &str, user: &User, role: &str) -> CallToolResult {
+    let state = if action == "assign_role" {
+        "already has"
+    } else {
+        "does not have"
+    };
+    let metadata = ExecutionMetadata::new().tool("users");
+    CallToolResult {
+        content: vec![Content::text(format!(
+            "User '{}' ({}) {state} role '{role}'; nothing changed\nCurrent roles: {:?}",
+            user.name, user.id, user.roles
+        ))],
+        structured_content: Some(json!({
+            "success": true,
+            "action": action,
+            "user_id": user.id,
+            "role": role,
+            "current_roles": user.roles,
+            "changed": false
+        })),
+        is_error: Some(false),
+        meta: metadata.to_meta(),
+    }
+}
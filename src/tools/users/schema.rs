@@ -1,26 +1,209 @@
 use serde_json::{json, Value as JsonValue};
 
+use crate::tools::pagination::cursor_schema;
+
+use super::filter::filter_schema_properties;
+use super::models::UserSortColumn;
+use super::plan::BULK_OPERATIONS;
+
+struct UserAction {
+    name: &'static str,
+    required: &'static [&'static str],
+    description: &'static str,
+}
+
+const USER_ACTIONS: [UserAction; 10] = [
+    UserAction {
+        name: "list",
+        required: &[],
+        description: "list users matching the filters",
+    },
+    UserAction {
+        name: "get",
+        required: &["user_id"],
+        description: "dashboard with profile, sessions, conversations, errors and files",
+    },
+    UserAction {
+        name: "create",
+        required: &["name", "email"],
+        description: "create a user with roles (default: user) and optionally send an invite or password_reset token",
+    },
+    UserAction {
+        name: "assign_role",
+        required: &["user_id", "role"],
+        description: "add a role to the user",
+    },
+    UserAction {
+        name: "remove_role",
+        required: &["user_id", "role"],
+        description: "remove a role; users must keep at least one role",
+    },
+    UserAction {
+        name: "delete",
+        required: &["user_id"],
+        description: "soft delete, sets status to 'deleted'; when confirmation is required the first call returns a preview and a confirmation_token",
+    },
+    UserAction {
+        name: "suspend",
+        required: &["user_id", "reason"],
+        description: "suspend with a reason and optional expires_at; marks active sessions ended, but access tokens already issued stay valid until they expire",
+    },
+    UserAction {
+        name: "reactivate",
+        required: &["user_id"],
+        description: "lift a suspension and return to the previous status",
+    },
+    UserAction {
+        name: "restore",
+        required: &["user_id"],
+        description: "return a soft-deleted user to 'active'",
+    },
+    UserAction {
+        name: "bulk",
+        required: &["operation", "filter"],
+        description: "apply one operation to every user matching filter in a single transaction; dry_run previews the affected users",
+    },
+];
+
+fn action_description() -> String {
+    let actions: Vec<String> = USER_ACTIONS
+        .iter()
+        .map(|action| {
+            if action.required.is_empty() {
+                format!("{}: {}", action.name, action.description)
+            } else {
+                format!(
+                    "{}: {} (requires {})",
+                    action.name,
+                    action.description,
+                    action.required.join(", ")
+                )
+            }
+        })
+        .collect();
+    format!(
+        "Operation to perform. Defaults to 'list' if not specified. {}.",
+        actions.join("; ")
+    )
+}
+
+fn action_requirements() -> Vec<JsonValue> {
+    USER_ACTIONS
+        .iter()
+        .filter(|action| !action.required.is_empty())
+        .map(|action| {
+            json!({
+                "if": {
+                    "properties": { "action": { "const": action.name } },
+                    "required": ["action"]
+                },
+                "then": { "required": action.required }
+            })
+        })
+        .collect()
+}
+
 #[must_use]
 pub fn users_input_schema_with_roles(role_names: &[String]) -> JsonValue {
-    json!({
+    let mut schema = json!({
         "type": "object",
         "properties": {
             "action": {
                 "type": "string",
-                "enum": ["list", "assign_role", "remove_role", "delete"],
-                "description": "Operation to perform. Defaults to 'list' if not specified."
+                "enum": USER_ACTIONS.iter().map(|action| action.name).collect::<Vec<_>>(),
+                "default": "list",
+                "description": action_description()
             },
             "user_id": {
                 "type": "string",
-                "description": "User ID. Optional for list, required for assign_role, remove_role, delete."
+                "description": "User ID. Optional for list, not used by bulk, required for every other action."
+            },
+            "reason": {
+                "type": "string",
+                "description": "Why the user is being suspended (required for suspend and bulk suspend)"
+            },
+            "expires_at": {
+                "type": "string",
+                "description": "When a suspension lifts automatically: RFC 3339 timestamp or duration from now such as 12h, 7d (suspend only; omit for an indefinite suspension)"
             },
             "role": {
                 "type": "string",
                 "enum": role_names,
-                "description": "Role to assign or remove. Required for assign_role and remove_role actions."
-            }
+                "description": "Role to assign or remove. Required for assign_role and remove_role, including bulk operations. For list, only users holding this role are returned."
+            },
+            "name": {
+                "type": "string",
+                "description": "For create, the new user's login name (no whitespace). For list, a case-insensitive substring match on name or display name"
+            },
+            "email": {
+                "type": "string",
+                "description": "For create, the new user's email address. For list, a case-insensitive substring match on email"
+            },
+            "display_name": {
+                "type": "string",
+                "description": "Display name for the new user (create only)"
+            },
+            "roles": {
+                "type": "array",
+                "items": { "type": "string", "enum": role_names },
+                "minItems": 1,
+                "description": "Roles for the new user, validated against the discovered roles (create only, default: [\"user\"])"
+            },
+            "send": {
+                "type": "string",
+                "enum": ["none", "invite", "password_reset"],
+                "default": "none",
+                "description": "Token to issue and deliver to the new user's email (create only). Rejected when the server has no token delivery channel configured"
+            },
+            "confirmation_token": {
+                "type": "string",
+                "description": "Token from a delete preview. When the server requires confirmation, delete only returns a preview of the user until it is repeated with this token"
+            },
+            "operation": {
+                "type": "string",
+                "enum": BULK_OPERATIONS,
+                "description": "Change applied to every user matching filter (required for bulk). assign_role and remove_role use role; suspend uses reason and expires_at"
+            },
+            "filter": {
+                "type": "object",
+                "description": "Users targeted by bulk, using the same fields as list. At least one field is required",
+                "properties": filter_schema_properties(role_names)
+            },
+            "dry_run": {
+                "type": "boolean",
+                "default": false,
+                "description": "For bulk, return the affected users without changing anything"
+            },
+            "sort": {
+                "type": "string",
+                "enum": UserSortColumn::ALL.map(UserSortColumn::as_str),
+                "default": "created_at",
+                "description": "Column to sort the list by. total_sessions and last_active change while users are active, so pages sorted by them can skip or repeat users"
+            },
+            "order": {
+                "type": "string",
+                "enum": ["asc", "desc"],
+                "default": "desc",
+                "description": "Sort direction"
+            },
+            "limit": {
+                "type": "integer",
+                "default": 50,
+                "minimum": 1,
+                "maximum": 500,
+                "description": "Maximum number of users to return for list"
+            },
+            "cursor": cursor_schema()
+        },
+        "allOf": action_requirements()
+    });
+
+    if let Some(properties) = schema["properties"].as_object_mut() {
+        for (name, property) in filter_schema_properties(role_names) {
+            properties.entry(name).or_insert(property);
         }
-    })
+    }
+    schema
 }
 
 #[must_use]
@@ -62,7 +245,8 @@ fn list_users_output_schema() -> JsonValue {
                     }
                 }
             },
-            "count": {"type": "integer"}
+            "count": {"type": "integer"},
+            "next_cursor": {"type": ["string", "null"]}
         },
         "x-artifact-type": "table",
         "x-table-hints": {
@@ -0,0 +1,274 @@
+use serde_json::json;
+use systemprompt::models::artifacts::{
+    Column, ColumnType, DashboardSection, LayoutWidth, SectionLayout, SectionType, TableArtifact,
+    TableHints,
+};
+
+use crate::tools::conversations::models::RecentConversation;
+use crate::tools::logs::models::LogEntry;
+use crate::tools::operations::repository::FileListing;
+use crate::tools::traffic::models::UserSessionActivity;
+
+use super::models::{Suspension, User};
+
+pub fn create_profile_section(
+    user: &User,
+    suspension: Option<&Suspension>,
+    sessions: &[UserSessionActivity],
+) -> Result<DashboardSection, serde_json::Error> {
+    let recent_cost_cents: i64 = sessions.iter().map(|s| s.ai_cost_cents).sum();
+    let active_sessions = sessions.iter().filter(|s| s.active).count();
+    let last_active = user.last_active.as_deref().unwrap_or("never");
+    let status_detail = suspension.map_or_else(
+        || format!("roles: {}", user.roles.join(", ")),
+        |s| {
+            format!(
+                "suspended: {} (until {})",
+                s.reason,
+                s.expires_at
+                    .map_or_else(|| "lifted".to_string(), |at| at.to_rfc3339())
+            )
+        },
+    );
+
+    let cards = vec![
+        json!({
+            "title": user.display_name.as_deref().unwrap_or(&user.name),
+            "value": user.email,
+            "subtitle": format!("{} · joined {}", user.id, user.created_at),
+            "icon": "user",
+            "status": "info"
+        }),
+        json!({
+            "title": "Status",
+            "value": user.status,
+            "subtitle": status_detail,
+            "icon": "shield",
+            "status": match user.status.as_str() {
+                "active" => "success",
+                "deleted" | "suspended" => "error",
+                _ => "warning",
+            }
+        }),
+        json!({
+            "title": "Sessions",
+            "value": user.total_sessions.to_string(),
+            "subtitle": format!("{active_sessions} active · last active {last_active}"),
+            "icon": "activity",
+            "status": "info"
+        }),
+        json!({
+            "title": "AI Cost",
+            "value": format_cost(recent_cost_cents),
+            "subtitle": format!("across {} recent sessions", sessions.len()),
+            "icon": "dollar-sign",
+            "status": "info"
+        }),
+    ];
+
+    Ok(
+        DashboardSection::new("user_profile", "Profile", SectionType::MetricsCards)
+            .with_data(json!({ "cards": cards }))?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 1,
+            }),
+    )
+}
+
+pub fn create_sessions_section(
+    sessions: &[UserSessionActivity],
+) -> Result<DashboardSection, serde_json::Error> {
+    let table = TableArtifact::new(vec![
+        Column::new("started_at", ColumnType::Date).with_header("Started"),
+        Column::new("device_type", ColumnType::String).with_header("Device"),
+        Column::new("country", ColumnType::String).with_header("Country"),
+        Column::new("referrer", ColumnType::String).with_header("Referrer"),
+        Column::new("duration", ColumnType::String).with_header("Duration"),
+        Column::new("request_count", ColumnType::Integer).with_header("Requests"),
+        Column::new("ai_cost", ColumnType::String).with_header("AI Cost"),
+        Column::new("active", ColumnType::Boolean).with_header("Active"),
+    ])
+    .with_rows(
+        sessions
+            .iter()
+            .map(|session| {
+                json!({
+                    "session_id": session.session_id,
+                    "started_at": session.started_at.to_rfc3339(),
+                    "device_type": session.device_type,
+                    "country": session.country,
+                    "referrer": session.referrer,
+                    "duration": format_duration(session.duration_seconds),
+                    "request_count": session.request_count,
+                    "ai_cost": format_cost(session.ai_cost_cents),
+                    "active": session.active,
+                })
+            })
+            .collect(),
+    )
+    .with_hints(
+        TableHints::new()
+            .with_sortable(vec![
+                "started_at".to_string(),
+                "request_count".to_string(),
+                "ai_cost".to_string(),
+            ])
+            .filterable(),
+    );
+
+    Ok(
+        DashboardSection::new("user_sessions", "Recent Sessions", SectionType::Table)
+            .with_data(table.to_response())?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 2,
+            }),
+    )
+}
+
+pub fn create_conversations_section(
+    conversations: &[RecentConversation],
+) -> Result<DashboardSection, serde_json::Error> {
+    let table = TableArtifact::new(vec![
+        Column::new("conversation_name", ColumnType::String).with_header("Conversation"),
+        Column::new("agent_name", ColumnType::String).with_header("Agent"),
+        Column::new("status", ColumnType::String).with_header("Status"),
+        Column::new("message_count", ColumnType::Integer).with_header("Messages"),
+        Column::new("last_updated", ColumnType::String).with_header("Last Updated"),
+    ])
+    .with_rows(
+        conversations
+            .iter()
+            .map(|conversation| {
+                json!({
+                    "context_id": conversation.context_id,
+                    "conversation_name": conversation
+                        .conversation_name
+                        .as_deref()
+                        .unwrap_or("Untitled"),
+                    "agent_name": conversation.agent_name,
+                    "status": conversation.status,
+                    "message_count": conversation.message_count,
+                    "last_updated": conversation
+                        .last_updated_formatted
+                        .as_deref()
+                        .unwrap_or(&conversation.last_updated),
+                })
+            })
+            .collect(),
+    )
+    .with_hints(
+        TableHints::new()
+            .with_sortable(vec![
+                "agent_name".to_string(),
+                "status".to_string(),
+                "message_count".to_string(),
+                "last_updated".to_string(),
+            ])
+            .filterable(),
+    );
+
+    Ok(
+        DashboardSection::new("user_conversations", "Conversations", SectionType::Table)
+            .with_data(table.to_response())?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 3,
+            }),
+    )
+}
+
+pub fn create_error_logs_section(logs: &[LogEntry]) -> Result<DashboardSection, serde_json::Error> {
+    let table = TableArtifact::new(vec![
+        Column::new("timestamp", ColumnType::String).with_header("Time"),
+        Column::new("module", ColumnType::String).with_header("Module"),
+        Column::new("message", ColumnType::String).with_header("Message"),
+        Column::new("session_id", ColumnType::String).with_header("Session"),
+    ])
+    .with_rows(
+        logs.iter()
+            .map(|log| {
+                json!({
+                    "id": log.id,
+                    "timestamp": log.timestamp,
+                    "module": log.module,
+                    "message": log.message,
+                    "session_id": log.session_id.as_deref().unwrap_or("N/A"),
+                })
+            })
+            .collect(),
+    )
+    .with_hints(
+        TableHints::new()
+            .with_sortable(vec!["timestamp".to_string(), "module".to_string()])
+            .filterable(),
+    );
+
+    Ok(
+        DashboardSection::new("user_errors", "Recent Errors", SectionType::Table)
+            .with_data(table.to_response())?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 4,
+            }),
+    )
+}
+
+pub fn create_files_section(files: &[FileListing]) -> Result<DashboardSection, serde_json::Error> {
+    let table = TableArtifact::new(vec![
+        Column::new("file_path", ColumnType::String).with_header("Path"),
+        Column::new("public_url", ColumnType::Link).with_header("URL"),
+        Column::new("mime_type", ColumnType::String).with_header("Type"),
+        Column::new("file_size_bytes", ColumnType::Integer).with_header("Size"),
+        Column::new("created_at", ColumnType::Date).with_header("Created"),
+    ])
+    .with_rows(
+        files
+            .iter()
+            .map(|file| {
+                json!({
+                    "id": file.id.to_string(),
+                    "file_path": file.path,
+                    "public_url": file.public_url,
+                    "mime_type": file.mime_type,
+                    "file_size_bytes": file.size_bytes,
+                    "created_at": file.created_at.to_rfc3339(),
+                })
+            })
+            .collect(),
+    )
+    .with_hints(
+        TableHints::new()
+            .with_sortable(vec![
+                "file_path".to_string(),
+                "file_size_bytes".to_string(),
+                "created_at".to_string(),
+            ])
+            .filterable(),
+    );
+
+    Ok(
+        DashboardSection::new("user_files", "Files", SectionType::Table)
+            .with_data(table.to_response())?
+            .with_layout(SectionLayout {
+                width: LayoutWidth::Full,
+                order: 5,
+            }),
+    )
+}
+
+pub(crate) fn format_cost(cost_cents: i64) -> String {
+    format!("${:.4}", cost_cents as f64 / 1_000_000.0)
+}
+
+pub(crate) fn format_duration(seconds: f64) -> String {
+    let total = seconds.max(0.0).round() as i64;
+    if total < 60 {
+        format!("{total}s")
+    } else if total < 3600 {
+        format!("{}m {}s", total / 60, total % 60)
+    } else {
+        format!("{}h {}m", total / 3600, (total % 3600) / 60)
+    }
+}
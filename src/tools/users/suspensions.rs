@@ -0,0 +1,252 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use sqlx::{PgConnection, PgPool};
+use std::sync::Arc;
+use std::time::Duration;
+use systemprompt::database::DbPool;
+
+use crate::tools::audit::models::AuditScope;
+use crate::tools::audit::repository::record_audit;
+use crate::tools::undo::models::{IssuedUndo, UndoAction};
+use crate::tools::undo::repository::store_undo;
+
+use super::models::Suspension;
+
+const EXPIRY_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+pub fn spawn_expiry_sweeper(db_pool: DbPool) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(EXPIRY_SWEEP_INTERVAL);
+
+        loop {
+            interval.tick().await;
+            match lift_expired(&db_pool).await {
+                Ok(0) => {}
+                Ok(lifted) => tracing::info!(count = lifted, "Expired user suspensions lifted"),
+                Err(e) => tracing::warn!(error = %e, "Suspension expiry sweep failed"),
+            }
+        }
+    });
+}
+
+async fn lift_expired(db_pool: &DbPool) -> Result<u64> {
+    SuspensionRepository::new(db_pool.clone())?
+        .lift_expired_suspensions()
+        .await
+}
+
+pub struct SuspensionRepository {
+    pool: Arc<PgPool>,
+}
+
+impl SuspensionRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn suspend_user(
+        &self,
+        user_id: &str,
+        reason: &str,
+        expires_at: Option<DateTime<Utc>>,
+        audit: &AuditScope,
+        undo: &IssuedUndo,
+    ) -> Result<Option<Suspension>> {
+        let mut tx = self.pool.begin().await?;
+        let Some(suspension) = suspend_on(&mut tx, user_id, reason, expires_at).await? else {
+            return Ok(None);
+        };
+        let entry = audit.entry(user_id).with_change(
+            json!({ "status": suspension.previous_status }),
+            json!({
+                "status": "suspended",
+                "reason": suspension.reason,
+                "expires_at": suspension.expires_at,
+                "ended_sessions": suspension.ended_sessions
+            }),
+        );
+        record_audit(&mut tx, &entry).await?;
+        let action = UndoAction::RestoreStatus {
+            user_id: user_id.to_string(),
+            status_before: suspension.previous_status.clone(),
+            status_after: "suspended".to_string(),
+        };
+        store_undo(&mut tx, undo, &entry, &action).await?;
+        tx.commit().await?;
+        Ok(Some(suspension))
+    }
+
+    pub async fn reactivate_user(
+        &self,
+        user_id: &str,
+        audit: &AuditScope,
+    ) -> Result<Option<String>> {
+        let mut tx = self.pool.begin().await?;
+
+        let previous_status = sqlx::query_scalar!(
+            r#"
+            UPDATE admin_user_suspensions
+            SET lifted_at = NOW()
+            WHERE user_id = $1 AND lifted_at IS NULL
+            RETURNING previous_status
+            "#,
+            user_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .unwrap_or_else(|| "active".to_string());
+
+        let updated = sqlx::query!(
+            "UPDATE users SET status = $2 WHERE id = $1 AND status = 'suspended'",
+            user_id,
+            previous_status
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if updated == 0 {
+            return Ok(None);
+        }
+
+        let entry = audit.entry(user_id).with_change(
+            json!({ "status": "suspended" }),
+            json!({ "status": previous_status }),
+        );
+        record_audit(&mut tx, &entry).await?;
+        tx.commit().await?;
+        Ok(Some(previous_status))
+    }
+
+    pub async fn restore_user(&self, user_id: &str, audit: &AuditScope) -> Result<Option<String>> {
+        let mut tx = self.pool.begin().await?;
+        let Some(status) = sqlx::query_scalar!(
+            r#"
+            UPDATE users
+            SET status = CASE
+                WHEN EXISTS (
+                    SELECT 1 FROM admin_user_suspensions
+                    WHERE user_id = $1 AND lifted_at IS NULL
+                ) THEN 'suspended'
+                ELSE 'active'
+            END
+            WHERE id = $1 AND status = 'deleted'
+            RETURNING status as "status!"
+            "#,
+            user_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let entry = audit
+            .entry(user_id)
+            .with_change(json!({ "status": "deleted" }), json!({ "status": status }));
+        record_audit(&mut tx, &entry).await?;
+        tx.commit().await?;
+        Ok(Some(status))
+    }
+
+    pub async fn find_active_suspension(&self, user_id: &str) -> Result<Option<Suspension>> {
+        sqlx::query_as!(
+            Suspension,
+            r#"
+            SELECT user_id, reason, previous_status, suspended_at, expires_at, ended_sessions
+            FROM admin_user_suspensions
+            WHERE user_id = $1 AND lifted_at IS NULL
+            "#,
+            user_id
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn lift_expired_suspensions(&self) -> Result<u64> {
+        let lifted = sqlx::query!(
+            r#"
+            WITH expired AS (
+                UPDATE admin_user_suspensions
+                SET lifted_at = NOW()
+                WHERE lifted_at IS NULL AND expires_at <= NOW()
+                RETURNING user_id, previous_status
+            )
+            UPDATE users u
+            SET status = expired.previous_status
+            FROM expired
+            WHERE u.id = expired.user_id AND u.status = 'suspended'
+            "#
+        )
+        .execute(&*self.pool)
+        .await?
+        .rows_affected();
+
+        Ok(lifted)
+    }
+}
+
+pub async fn suspend_on(
+    conn: &mut PgConnection,
+    user_id: &str,
+    reason: &str,
+    expires_at: Option<DateTime<Utc>>,
+) -> Result<Option<Suspension>> {
+    let Some(previous_status) = sqlx::query_scalar!(
+        r#"
+        SELECT status as "status!"
+        FROM users
+        WHERE id = $1 AND status NOT IN ('suspended', 'deleted')
+        FOR UPDATE
+        "#,
+        user_id
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    sqlx::query!(
+        "UPDATE users SET status = 'suspended' WHERE id = $1",
+        user_id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    let ended_sessions = sqlx::query!(
+        "UPDATE user_sessions SET ended_at = NOW() WHERE user_id = $1 AND ended_at IS NULL",
+        user_id
+    )
+    .execute(&mut *conn)
+    .await?
+    .rows_affected() as i64;
+
+    let suspended_at = sqlx::query_scalar!(
+        r#"
+        INSERT INTO admin_user_suspensions
+            (user_id, reason, previous_status, expires_at, ended_sessions)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING suspended_at
+        "#,
+        user_id,
+        reason,
+        previous_status,
+        expires_at,
+        ended_sessions
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    Ok(Some(Suspension {
+        user_id: user_id.to_string(),
+        reason: reason.to_string(),
+        previous_status,
+        suspended_at,
+        expires_at,
+        ended_sessions,
+    }))
+}
@@ -0,0 +1,60 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+use std::sync::Arc;
+use systemprompt::database::DbPool;
+
+use crate::services::user_tokens::{hash_token, IssuedToken, TokenPurpose};
+
+pub async fn store_token_on(
+    conn: &mut PgConnection,
+    user_id: &str,
+    token: &IssuedToken,
+    delivery_channel: &str,
+) -> Result<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO admin_user_tokens
+            (user_id, purpose, token_hash, delivery_channel, expires_at)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+        user_id,
+        token.purpose.as_str(),
+        token.token_hash,
+        delivery_channel,
+        token.expires_at
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+pub struct UserTokenRepository {
+    pool: Arc<PgPool>,
+}
+
+impl UserTokenRepository {
+    pub fn new(db: DbPool) -> Result<Self> {
+        let pool = db.pool_arc()?;
+        Ok(Self { pool })
+    }
+
+    pub async fn redeem(&self, token: &str, purpose: TokenPurpose) -> Result<Option<String>> {
+        sqlx::query_scalar!(
+            r#"
+            UPDATE admin_user_tokens
+            SET used_at = NOW()
+            WHERE token_hash = $1
+              AND purpose = $2
+              AND used_at IS NULL
+              AND expires_at > NOW()
+            RETURNING user_id
+            "#,
+            hash_token(token),
+            purpose.as_str()
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(Into::into)
+    }
+}
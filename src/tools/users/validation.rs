@@ -0,0 +1,99 @@
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+pub const DEFAULT_NEW_USER_ROLE: &str = "user";
+const MAX_NAME_LENGTH: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewUser {
+    pub name: String,
+    pub email: String,
+    pub display_name: Option<String>,
+    pub roles: Vec<String>,
+}
+
+fn text_arg<'a>(args: &'a JsonMap<String, JsonValue>, key: &str) -> Option<&'a str> {
+    args.get(key)
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+}
+
+pub fn validate_email(email: &str) -> Result<String, String> {
+    let email = email.trim().to_lowercase();
+    let valid = match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !email.chars().any(char::is_whitespace)
+        }
+        None => false,
+    };
+
+    if valid {
+        Ok(email)
+    } else {
+        Err(format!("Invalid email address: {email}"))
+    }
+}
+
+fn known_role(role: &str, known_roles: &[String]) -> Result<String, String> {
+    if known_roles.iter().any(|known| known == role) {
+        Ok(role.to_string())
+    } else {
+        Err(format!(
+            "Unknown role: {role}. Discovered roles: {}",
+            known_roles.join(", ")
+        ))
+    }
+}
+
+pub fn validate_roles(
+    requested: Option<&JsonValue>,
+    known_roles: &[String],
+) -> Result<Vec<String>, String> {
+    let Some(requested) = requested else {
+        return known_role(DEFAULT_NEW_USER_ROLE, known_roles).map(|role| vec![role]);
+    };
+    let requested = requested
+        .as_array()
+        .ok_or("roles must be an array of role names")?;
+
+    let mut roles: Vec<String> = Vec::new();
+    for role in requested {
+        let role = role
+            .as_str()
+            .ok_or("roles must be an array of role names")?;
+        let role = known_role(role, known_roles)?;
+        if !roles.contains(&role) {
+            roles.push(role);
+        }
+    }
+
+    if roles.is_empty() {
+        return Err("Users must have at least one role".to_string());
+    }
+    Ok(roles)
+}
+
+pub fn parse_new_user(
+    args: &JsonMap<String, JsonValue>,
+    known_roles: &[String],
+) -> Result<NewUser, String> {
+    let name = text_arg(args, "name").ok_or("name is required for create")?;
+    if name.chars().any(char::is_whitespace) || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(format!(
+            "Invalid name: {name}. Names must not contain whitespace and are limited to {MAX_NAME_LENGTH} characters"
+        ));
+    }
+    let email = validate_email(text_arg(args, "email").ok_or("email is required for create")?)?;
+
+    Ok(NewUser {
+        name: name.to_string(),
+        email,
+        display_name: text_arg(args, "display_name").map(String::from),
+        roles: validate_roles(args.get("roles"), known_roles)?,
+    })
+}
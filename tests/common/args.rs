@@ -0,0 +1,5 @@
+use serde_json::{Map, Value as JsonValue};
+
+pub fn args(value: JsonValue) -> Map<String, JsonValue> {
+    value.as_object().cloned().unwrap_or_default()
+}
@@ -0,0 +1,11 @@
+use chrono::{DateTime, TimeZone, Utc};
+
+pub fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> Result<DateTime<Utc>, String> {
+    Utc.with_ymd_and_hms(y, mo, d, h, mi, 0)
+        .single()
+        .ok_or_else(|| "invalid test timestamp".to_string())
+}
+
+pub fn fixed_now() -> Result<DateTime<Utc>, String> {
+    utc(2025, 1, 10, 12, 0)
+}
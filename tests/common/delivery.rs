@@ -0,0 +1,32 @@
+use anyhow::{anyhow, Result};
+use std::sync::{Arc, Mutex};
+use systemprompt_admin::services::user_tokens::TokenMessage;
+use systemprompt_admin::services::TokenDelivery;
+
+#[derive(Debug, Clone, Default)]
+pub struct RecordingDelivery {
+    outbox: Arc<Mutex<Vec<TokenMessage>>>,
+}
+
+impl RecordingDelivery {
+    pub fn sent(&self) -> Result<Vec<TokenMessage>> {
+        self.outbox
+            .lock()
+            .map(|outbox| outbox.clone())
+            .map_err(|_| anyhow!("Recorded token outbox is poisoned"))
+    }
+}
+
+impl TokenDelivery for RecordingDelivery {
+    fn channel(&self) -> &'static str {
+        "recording"
+    }
+
+    fn deliver(&self, message: &TokenMessage) -> Result<()> {
+        self.outbox
+            .lock()
+            .map_err(|_| anyhow!("Recorded token outbox is poisoned"))?
+            .push(message.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use chrono::{DateTime, TimeZone, Utc};
+
+pub fn utc_micros(micros: i64) -> Result<DateTime<Utc>, String> {
+    Utc.timestamp_micros(micros)
+        .single()
+        .ok_or_else(|| "invalid test timestamp".to_string())
+}
@@ -1,3 +0,0 @@
-pub mod db;
-
-pub use db::TestDb;
@@ -1 +1,2 @@
+mod role_catalog_test;
 mod tool_execution_test;
@@ -0,0 +1,43 @@
+use systemprompt_admin::server::role_catalog::RoleCatalog;
+use systemprompt_admin::services::role_discovery::default_core_roles;
+use systemprompt_admin::services::{DiscoveredRole, RoleSource};
+
+fn editor_role() -> DiscoveredRole {
+    DiscoveredRole {
+        name: "editor".to_string(),
+        display_name: "Editor".to_string(),
+        description: "Edits content".to_string(),
+        source: RoleSource::Extension {
+            extension_name: "blog".to_string(),
+        },
+        permissions: vec!["content.write".to_string()],
+    }
+}
+
+#[tokio::test]
+async fn replace_ignores_unchanged_roles() {
+    let catalog = RoleCatalog::new(default_core_roles());
+    let before = catalog.snapshot().await;
+
+    assert!(!catalog.replace(default_core_roles()).await);
+    assert!(std::sync::Arc::ptr_eq(&before, &catalog.snapshot().await));
+}
+
+#[tokio::test]
+async fn replace_swaps_roles_and_tool_schemas_together() {
+    let catalog = RoleCatalog::new(default_core_roles());
+    let before = catalog.snapshot().await;
+
+    let mut roles = default_core_roles();
+    roles.push(editor_role());
+    assert!(catalog.replace(roles).await);
+
+    let after = catalog.snapshot().await;
+    assert_eq!(
+        after.role_names(),
+        vec!["anonymous", "user", "admin", "editor"]
+    );
+    assert!(after.tool_schemas.contains_key("user"));
+    assert!(after.tool_schemas.contains_key("roles"));
+    assert_eq!(before.role_names(), vec!["anonymous", "user", "admin"]);
+}
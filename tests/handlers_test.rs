@@ -1,2 +1 @@
-mod common;
 mod handlers;
@@ -1,50 +1,65 @@
 use systemprompt_admin::prompts::build_admin_analysis_prompt;
+use systemprompt_admin::tools::register_tools;
 
 #[test]
-fn admin_analysis_prompt_contains_focus_area() {
-    let prompt = build_admin_analysis_prompt("logs", "24h");
+fn admin_analysis_prompt_contains_focus_area() -> anyhow::Result<()> {
+    let prompt = build_admin_analysis_prompt(&register_tools(), "logs", "24h")?;
 
     assert!(prompt.contains("logs"));
     assert!(prompt.contains("24h"));
+    Ok(())
 }
 
 #[test]
-fn admin_analysis_prompt_contains_structure_sections() {
-    let prompt = build_admin_analysis_prompt("all", "7d");
+fn admin_analysis_prompt_contains_structure_sections() -> anyhow::Result<()> {
+    let prompt = build_admin_analysis_prompt(&register_tools(), "all", "7d")?;
 
     assert!(prompt.contains("Executive Summary"));
     assert!(prompt.contains("Detailed Analysis"));
     assert!(prompt.contains("Recommendations"));
+    Ok(())
 }
 
 #[test]
-fn admin_analysis_prompt_handles_all_focus_areas() {
+fn admin_analysis_prompt_handles_all_focus_areas() -> anyhow::Result<()> {
     let areas = ["logs", "database", "system", "users", "all"];
 
     for area in &areas {
-        let prompt = build_admin_analysis_prompt(area, "24h");
+        let prompt = build_admin_analysis_prompt(&register_tools(), area, "24h")?;
         assert!(!prompt.is_empty());
         assert!(prompt.contains(area));
     }
+    Ok(())
 }
 
 #[test]
-fn admin_analysis_prompt_handles_all_time_periods() {
+fn admin_analysis_prompt_handles_all_time_periods() -> anyhow::Result<()> {
     let periods = ["1h", "24h", "7d", "30d"];
 
     for period in &periods {
-        let prompt = build_admin_analysis_prompt("all", period);
+        let prompt = build_admin_analysis_prompt(&register_tools(), "all", period)?;
         assert!(!prompt.is_empty());
         assert!(prompt.contains(period));
     }
+    Ok(())
 }
 
 #[test]
-fn admin_analysis_prompt_contains_tool_instructions() {
-    let prompt = build_admin_analysis_prompt("all", "24h");
+fn admin_analysis_prompt_contains_tool_instructions() -> anyhow::Result<()> {
+    let prompt = build_admin_analysis_prompt(&register_tools(), "all", "24h")?;
 
-    assert!(prompt.contains("get_logs"));
-    assert!(prompt.contains("db_admin"));
-    assert!(prompt.contains("system_status"));
-    assert!(prompt.contains("user_activity"));
+    for tool in [
+        "logs",
+        "jobs",
+        "conversations",
+        "traffic",
+        "user",
+        "operations",
+    ] {
+        assert!(
+            prompt.contains(&format!("Call `{tool}`")),
+            "Missing instruction for {tool}"
+        );
+    }
+    Ok(())
 }
@@ -0,0 +1,112 @@
+use serde_json::Value as JsonValue;
+use systemprompt_admin::prompts::live_metrics::{
+    snapshot_message, summarize_snapshot, SnapshotJob, SystemHealthSnapshot,
+};
+use systemprompt_admin::prompts::SYSTEM_HEALTH_PERMISSION;
+use systemprompt_admin::tools::conversations::models::ConversationSummary;
+use systemprompt_admin::tools::logs::models::LogStats;
+use systemprompt_admin::tools::permissions::required_permission;
+
+use super::super::common::fixed_now;
+
+fn empty_snapshot() -> Result<SystemHealthSnapshot, String> {
+    Ok(SystemHealthSnapshot {
+        generated_at: fixed_now()?,
+        logs_24h: LogStats {
+            total_logs: 0,
+            error_count: 0,
+            warn_count: 0,
+            info_count: 0,
+            unique_modules: 0,
+            unique_users: 0,
+            last_log_time: None,
+        },
+        jobs: Vec::new(),
+        conversations_24h: ConversationSummary {
+            total_conversations: 0,
+            total_messages: 0,
+            avg_messages_per_conversation: 0.0,
+            avg_execution_time_ms: 0.0,
+            failed_conversations: 0,
+        },
+    })
+}
+
+fn job(name: &str, status: &str) -> SnapshotJob {
+    SnapshotJob {
+        job_name: name.to_string(),
+        schedule: "0 0 * * * *".to_string(),
+        last_run: None,
+        last_status: Some(status.to_string()),
+        run_count: 1,
+        last_error: None,
+    }
+}
+
+#[test]
+fn summarize_snapshot_reports_log_and_conversation_counts() -> Result<(), String> {
+    let mut snapshot = empty_snapshot()?;
+    snapshot.logs_24h.total_logs = 1200;
+    snapshot.logs_24h.error_count = 7;
+    snapshot.logs_24h.warn_count = 31;
+    snapshot.logs_24h.unique_modules = 12;
+    snapshot.conversations_24h.total_conversations = 40;
+    snapshot.conversations_24h.total_messages = 380;
+    snapshot.conversations_24h.failed_conversations = 2;
+    snapshot.conversations_24h.avg_execution_time_ms = 1534.4;
+
+    let summary = summarize_snapshot(&snapshot);
+
+    assert!(summary.contains("2025-01-10T12:00:00+00:00"));
+    assert!(summary.contains("1200 total, 7 errors, 31 warnings across 12 modules"));
+    assert!(summary.contains("40 conversations, 380 messages, 2 failed, 1534ms"));
+    Ok(())
+}
+
+#[test]
+fn summarize_snapshot_names_failing_jobs() -> Result<(), String> {
+    let mut snapshot = empty_snapshot()?;
+    snapshot.jobs = vec![
+        job("database_cleanup", "success"),
+        job("ingest_files", "failed"),
+    ];
+
+    let summary = summarize_snapshot(&snapshot);
+
+    assert!(summary.contains("2 enabled jobs, 1 failing (ingest_files)"));
+    Ok(())
+}
+
+#[test]
+fn summarize_snapshot_reports_empty_snapshot() -> Result<(), String> {
+    let summary = summarize_snapshot(&empty_snapshot()?);
+
+    assert!(summary.contains("0 enabled jobs, 0 failing"));
+    assert!(summary.contains("last entry: never"));
+    Ok(())
+}
+
+#[test]
+fn snapshot_message_embeds_the_snapshot_as_json() -> anyhow::Result<()> {
+    let mut snapshot = empty_snapshot().map_err(anyhow::Error::msg)?;
+    snapshot.jobs = vec![job("ingest_files", "failed")];
+
+    let message = snapshot_message(&snapshot)?;
+    let (summary, block) = message
+        .split_once("```json\n")
+        .ok_or_else(|| anyhow::anyhow!("snapshot message has no JSON block"))?;
+    let json: JsonValue = serde_json::from_str(block.trim_end().trim_end_matches("```"))?;
+
+    assert_eq!(summary.trim_end(), summarize_snapshot(&snapshot));
+    assert_eq!(json["jobs"][0]["job_name"], "ingest_files");
+    assert!(!message.contains("admin://"));
+    Ok(())
+}
+
+#[test]
+fn system_health_prompt_requires_the_health_report_permission() {
+    assert_eq!(
+        required_permission("health", "report"),
+        Some(SYSTEM_HEALTH_PERMISSION)
+    );
+}
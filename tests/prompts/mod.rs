@@ -1,2 +1,4 @@
 mod admin_analysis_test;
+mod live_metrics_test;
 mod system_health_test;
+mod tool_references_test;
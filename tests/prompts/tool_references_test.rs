@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+use systemprompt_admin::prompts::admin_analysis::TIME_PERIODS;
+use systemprompt_admin::prompts::tool_guide::tool_step;
+use systemprompt_admin::prompts::{build_admin_analysis_prompt, build_system_health_prompt};
+use systemprompt_admin::services::DiscoveredRole;
+use systemprompt_admin::tools::{list_tools_for_caller, register_tools, Caller};
+
+fn registered_names() -> HashSet<String> {
+    let mut names = HashSet::new();
+
+    for tool in register_tools() {
+        names.insert(tool.name.to_string());
+        if let Some(properties) = tool
+            .input_schema
+            .get("properties")
+            .and_then(|p| p.as_object())
+        {
+            names.extend(properties.keys().cloned());
+        }
+    }
+
+    names
+}
+
+fn backticked_names(prompt: &str) -> Vec<String> {
+    prompt
+        .split('`')
+        .skip(1)
+        .step_by(2)
+        .map(String::from)
+        .collect()
+}
+
+fn assert_only_registered_names(prompt: &str) {
+    let known = registered_names();
+
+    for name in backticked_names(prompt) {
+        assert!(
+            known.contains(&name),
+            "Prompt references `{name}`, which is not a registered tool or argument"
+        );
+    }
+}
+
+#[test]
+fn system_health_prompt_references_only_registered_tools() -> anyhow::Result<()> {
+    assert_only_registered_names(&build_system_health_prompt(&register_tools(), true)?);
+    assert_only_registered_names(&build_system_health_prompt(&register_tools(), false)?);
+    Ok(())
+}
+
+#[test]
+fn admin_analysis_prompt_references_only_registered_tools() -> anyhow::Result<()> {
+    for period in TIME_PERIODS {
+        assert_only_registered_names(&build_admin_analysis_prompt(
+            &register_tools(),
+            "all",
+            period,
+        )?);
+    }
+    Ok(())
+}
+
+#[test]
+fn prompts_do_not_mention_retired_tool_names() -> anyhow::Result<()> {
+    let prompts = [
+        build_system_health_prompt(&register_tools(), true)?,
+        build_admin_analysis_prompt(&register_tools(), "all", "24h")?,
+    ];
+
+    for prompt in &prompts {
+        for retired in ["system_status", "db_admin", "get_logs", "user_activity"] {
+            assert!(!prompt.contains(retired), "Prompt still mentions {retired}");
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn prompts_include_generated_tool_reference() -> anyhow::Result<()> {
+    let prompt = build_system_health_prompt(&register_tools(), true)?;
+
+    assert!(prompt.contains("Available Admin Tools"));
+    for tool in register_tools() {
+        assert!(prompt.contains(&format!("`{}`", tool.name)));
+    }
+    Ok(())
+}
+
+#[test]
+fn every_prompt_step_resolves_against_tool_schemas() -> anyhow::Result<()> {
+    for include_recommendations in [true, false] {
+        build_system_health_prompt(&register_tools(), include_recommendations)?;
+    }
+    for period in TIME_PERIODS {
+        build_admin_analysis_prompt(&register_tools(), "all", period)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn tool_step_rejects_unknown_tool() {
+    assert!(tool_step(&register_tools(), "system_status", &[], "to check status").is_err());
+}
+
+#[test]
+fn tool_step_rejects_unknown_argument() {
+    let step = tool_step(
+        &register_tools(),
+        "logs",
+        &[("severity", "ERROR")],
+        "to read logs",
+    );
+
+    assert!(step.is_err());
+}
+
+#[test]
+fn tool_step_rejects_value_outside_schema_enum() {
+    let tools = register_tools();
+
+    assert!(tool_step(&tools, "logs", &[("level", "FATAL")], "to read logs").is_err());
+    assert!(tool_step(
+        &tools,
+        "traffic",
+        &[("time_range", "1h")],
+        "to read traffic"
+    )
+    .is_err());
+}
+
+#[test]
+fn tool_step_renders_valid_arguments() -> anyhow::Result<()> {
+    let step = tool_step(
+        &register_tools(),
+        "logs",
+        &[("level", "ERROR")],
+        "to read logs",
+    )?;
+
+    assert_eq!(step, "- Call `logs` with `level`=\"ERROR\" to read logs");
+    Ok(())
+}
+
+#[test]
+fn prompts_guide_callers_only_to_the_tools_they_can_list() -> anyhow::Result<()> {
+    let roles = vec![
+        DiscoveredRole::core("log_reader", "Log Reader", "Reads logs")
+            .with_permissions(&["admin.logs.read"]),
+    ];
+    let caller = Caller::new("user-1", vec!["log_reader".to_string()], &roles);
+    let tools = list_tools_for_caller(&[], &caller)
+        .map_err(|e| anyhow::anyhow!(e.message))?
+        .tools;
+
+    let prompts = [
+        build_system_health_prompt(&tools, true)?,
+        build_admin_analysis_prompt(&tools, "all", "24h")?,
+    ];
+
+    for prompt in &prompts {
+        assert!(prompt.contains("Call `logs`"));
+        for hidden in ["health", "jobs", "user", "operations"] {
+            assert!(
+                !prompt.contains(&format!("`{hidden}`")),
+                "Prompt guides a log reader to `{hidden}`"
+            );
+        }
+    }
+    Ok(())
+}
@@ -1 +1,6 @@
+mod common {
+    mod clock;
+
+    pub use clock::fixed_now;
+}
 mod prompts;
@@ -0,0 +1,68 @@
+use serde_json::{json, Map};
+use serial_test::serial;
+use systemprompt_admin::tools::audit::models::{AuditFilter, AuditScope};
+use systemprompt_admin::tools::audit::repository::{record_audit, AuditRepository};
+
+use super::super::common::TestDb;
+
+#[tokio::test]
+#[serial]
+async fn recorded_entries_are_listed_by_actor_newest_first() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let actor_id = format!("audit-test-{}", uuid::Uuid::new_v4());
+    let scope = AuditScope::new(&actor_id, "user", "assign_role", &Map::new());
+
+    let pool = db.db_pool().pool_arc()?;
+    let mut tx = pool.begin().await?;
+    for target in ["user-a", "user-b"] {
+        let entry = scope.entry(target).with_change(
+            json!({ "roles": ["user"] }),
+            json!({ "roles": ["user", "admin"] }),
+        );
+        record_audit(&mut tx, &entry).await?;
+    }
+    tx.commit().await?;
+
+    let repo = AuditRepository::new(db.db_pool())?;
+    let filter = AuditFilter {
+        actor_id: Some(actor_id.clone()),
+        ..AuditFilter::default()
+    };
+
+    let page = repo.list(&filter, 1, None).await?;
+    assert_eq!(page.items.len(), 1);
+    assert!(page.next_cursor.is_some());
+
+    let next = repo.list(&filter, 10, page.next_cursor.as_ref()).await?;
+    assert_eq!(next.items.len(), 1);
+    assert_ne!(page.items[0].id, next.items[0].id);
+    assert!(page.items[0].created_at >= next.items[0].created_at);
+    for record in page.items.iter().chain(&next.items) {
+        assert_eq!(record.actor_id, actor_id);
+        assert_eq!(record.action, "assign_role");
+    }
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn rolled_back_changes_leave_no_audit_record() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let actor_id = format!("audit-test-{}", uuid::Uuid::new_v4());
+    let scope = AuditScope::new(&actor_id, "operations", "delete_file", &Map::new());
+
+    let pool = db.db_pool().pool_arc()?;
+    let mut tx = pool.begin().await?;
+    record_audit(&mut tx, &scope.entry("file-1")).await?;
+    tx.rollback().await?;
+
+    let filter = AuditFilter {
+        actor_id: Some(actor_id),
+        ..AuditFilter::default()
+    };
+    let page = AuditRepository::new(db.db_pool())?
+        .list(&filter, 10, None)
+        .await?;
+    assert!(page.items.is_empty());
+    Ok(())
+}
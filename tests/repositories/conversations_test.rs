@@ -1,8 +1,12 @@
+use chrono::{DateTime, Utc};
 use serial_test::serial;
+use std::collections::HashSet;
 use systemprompt_admin::tools::conversations::repository::ConversationsRepository;
 
 use super::super::common::TestDb;
 
+const MAX_PAGES: usize = 50;
+
 #[tokio::test]
 #[serial]
 async fn get_conversation_summary_returns_valid_structure() -> anyhow::Result<()> {
@@ -26,31 +30,48 @@ async fn get_recent_conversations_paginated_respects_limit() -> anyhow::Result<(
     let repo = ConversationsRepository::new(db.db_pool())?;
 
     let conversations = repo
-        .get_recent_conversations_paginated("7 days", 5, 0, None)
+        .get_recent_conversations_paginated("7 days", 5, None, None)
         .await?;
 
-    assert!(conversations.len() <= 5);
+    assert!(conversations.items.len() <= 5);
     Ok(())
 }
 
 #[tokio::test]
 #[serial]
-async fn get_recent_conversations_handles_offset() -> anyhow::Result<()> {
+async fn get_recent_conversations_follows_cursor() -> anyhow::Result<()> {
     let db = TestDb::new().await?;
     let repo = ConversationsRepository::new(db.db_pool())?;
 
-    let page1 = repo
-        .get_recent_conversations_paginated("7 days", 5, 0, None)
-        .await?;
-    let page2 = repo
-        .get_recent_conversations_paginated("7 days", 5, 5, None)
+    let mut seen = HashSet::new();
+    let mut previous: Option<(DateTime<Utc>, String)> = None;
+    let mut page = repo
+        .get_recent_conversations_paginated("30 days", 5, None, None)
         .await?;
 
-    if !page1.is_empty() && !page2.is_empty() {
-        assert_ne!(
-            page1[0].context_id, page2[0].context_id,
-            "Page 2 should have different results than page 1"
-        );
+    for _ in 0..MAX_PAGES {
+        for row in &page.items {
+            let key = (row.sort_timestamp, row.context_id.clone());
+            if let Some(previous) = &previous {
+                assert!(
+                    key < *previous,
+                    "Rows must strictly follow the previous row in keyset order"
+                );
+            }
+            assert!(
+                seen.insert(row.context_id.clone()),
+                "Conversation {} was returned twice",
+                row.context_id
+            );
+            previous = Some(key);
+        }
+
+        let Some(cursor) = page.next_cursor.as_ref() else {
+            break;
+        };
+        page = repo
+            .get_recent_conversations_paginated("30 days", 5, Some(cursor), None)
+            .await?;
     }
     Ok(())
 }
@@ -71,3 +92,37 @@ async fn get_conversation_trends_returns_all_periods() -> anyhow::Result<()> {
     assert!(trend.conversations_30d >= 0);
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn get_user_conversations_returns_exactly_that_users_contexts() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let pool = db.db_pool().pool_arc()?;
+    let repo = ConversationsRepository::new(db.db_pool())?;
+
+    let missing = format!("conversations-test-{}", uuid::Uuid::new_v4());
+    assert!(repo.get_user_conversations(&missing, 20).await?.is_empty());
+
+    let busiest: Option<String> = sqlx::query_scalar(
+        "SELECT user_id FROM user_contexts GROUP BY user_id ORDER BY COUNT(*) DESC, user_id LIMIT 1",
+    )
+    .fetch_optional(&*pool)
+    .await?;
+    let Some(user_id) = busiest else {
+        return Ok(());
+    };
+
+    let expected: Vec<String> = sqlx::query_scalar(
+        "SELECT context_id FROM user_contexts WHERE user_id = $1 \
+         ORDER BY updated_at DESC, context_id DESC LIMIT 20",
+    )
+    .bind(&user_id)
+    .fetch_all(&*pool)
+    .await?;
+
+    let owned = repo.get_user_conversations(&user_id, 20).await?;
+    let actual: Vec<String> = owned.iter().map(|c| c.context_id.clone()).collect();
+    assert_eq!(actual, expected);
+    assert!(owned.iter().all(|c| c.user_id == user_id));
+    Ok(())
+}
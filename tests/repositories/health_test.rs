@@ -0,0 +1,44 @@
+use serial_test::serial;
+use systemprompt_admin::tools::health::repository::HealthRepository;
+
+use super::super::common::TestDb;
+
+#[tokio::test]
+#[serial]
+async fn check_database_reports_reachable_pool() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = HealthRepository::new(db.db_pool())?;
+
+    let health = repo.check_database().await;
+
+    assert!(health.reachable);
+    assert!(health.error.is_none());
+    assert!(health.latency_ms >= 0.0);
+    assert!(health.pool_max >= health.pool_size);
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn largest_tables_respects_limit_and_order() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = HealthRepository::new(db.db_pool())?;
+
+    let tables = repo.largest_tables(5).await?;
+
+    assert!(tables.len() <= 5);
+    for pair in tables.windows(2) {
+        assert!(pair[0].total_bytes >= pair[1].total_bytes);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn estimate_log_rows_is_non_negative() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = HealthRepository::new(db.db_pool())?;
+
+    assert!(repo.estimate_log_rows().await? >= 0);
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use serial_test::serial;
+use systemprompt_admin::tools::logs::models::{LogFilter, ModuleMatch};
 use systemprompt_admin::tools::logs::repository::LogsRepository;
 
 use super::super::common::TestDb;
@@ -9,9 +10,11 @@ async fn fetch_recent_logs_respects_limit() -> anyhow::Result<()> {
     let db = TestDb::new().await?;
     let repo = LogsRepository::new(db.db_pool())?;
 
-    let logs = repo.fetch_recent_logs(0, 10, None).await?;
+    let logs = repo
+        .fetch_recent_logs(&LogFilter::default(), 10, None)
+        .await?;
 
-    assert!(logs.len() <= 10);
+    assert!(logs.items.len() <= 10);
     Ok(())
 }
 
@@ -21,14 +24,23 @@ async fn fetch_recent_logs_handles_pagination() -> anyhow::Result<()> {
     let db = TestDb::new().await?;
     let repo = LogsRepository::new(db.db_pool())?;
 
-    let page1 = repo.fetch_recent_logs(0, 5, None).await?;
-    let page2 = repo.fetch_recent_logs(1, 5, None).await?;
+    let page1 = repo
+        .fetch_recent_logs(&LogFilter::default(), 5, None)
+        .await?;
 
-    if !page1.is_empty() && !page2.is_empty() {
-        assert_ne!(
-            page1[0].id, page2[0].id,
-            "Page 2 should have different results"
+    if let Some(cursor) = page1.next_cursor.as_ref() {
+        let page2 = repo
+            .fetch_recent_logs(&LogFilter::default(), 5, Some(cursor))
+            .await?;
+        assert!(
+            page2
+                .items
+                .iter()
+                .all(|log| page1.items.iter().all(|seen| seen.id != log.id)),
+            "Page 2 should not repeat rows from page 1"
         );
+    } else {
+        assert!(page1.items.len() <= 5);
     }
     Ok(())
 }
@@ -39,7 +51,7 @@ async fn fetch_log_stats_returns_valid_structure() -> anyhow::Result<()> {
     let db = TestDb::new().await?;
     let repo = LogsRepository::new(db.db_pool())?;
 
-    let stats = repo.fetch_log_stats().await?;
+    let stats = repo.fetch_log_stats(&LogFilter::default()).await?;
 
     assert!(stats.total_logs >= 0);
     assert!(stats.error_count >= 0);
@@ -49,3 +61,80 @@ async fn fetch_log_stats_returns_valid_structure() -> anyhow::Result<()> {
     assert!(stats.unique_users >= 0);
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn fetch_log_by_id_matches_recent_log() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = LogsRepository::new(db.db_pool())?;
+
+    let logs = repo
+        .fetch_recent_logs(&LogFilter::default(), 1, None)
+        .await?;
+
+    if let Some(first) = logs.items.first() {
+        let found = repo.fetch_log_by_id(&first.id).await?;
+        assert_eq!(found.map(|log| log.id), Some(first.id.clone()));
+    }
+
+    assert!(repo.fetch_log_by_id("missing-log-id").await?.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn filtered_logs_and_stats_share_the_same_window() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = LogsRepository::new(db.db_pool())?;
+
+    let filter = LogFilter {
+        context_id: Some("missing-context-id".to_string()),
+        ..LogFilter::with_level("ERROR")
+    };
+
+    let logs = repo.fetch_recent_logs(&filter, 10, None).await?;
+    let stats = repo.fetch_log_stats(&filter).await?;
+
+    assert!(logs.items.is_empty());
+    assert!(logs.next_cursor.is_none());
+    assert_eq!(stats.total_logs, 0);
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn fetch_recent_logs_applies_module_prefix_and_search() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = LogsRepository::new(db.db_pool())?;
+
+    let Some(sample) = repo
+        .fetch_recent_logs(&LogFilter::default(), 1, None)
+        .await?
+        .items
+        .into_iter()
+        .next()
+    else {
+        return Ok(());
+    };
+
+    let prefix: String = sample.module.chars().take(3).collect();
+    let filter = LogFilter {
+        module: Some(prefix.clone()),
+        module_match: ModuleMatch::Prefix,
+        ..LogFilter::default()
+    };
+    let logs = repo.fetch_recent_logs(&filter, 20, None).await?.items;
+    assert!(logs.iter().all(|log| log.module.starts_with(&prefix)));
+
+    let needle: String = sample.message.chars().take(5).collect();
+    let filter = LogFilter {
+        search: Some(needle.to_uppercase()),
+        ..LogFilter::default()
+    };
+    let logs = repo.fetch_recent_logs(&filter, 20, None).await?.items;
+    assert!(!logs.is_empty());
+    assert!(logs
+        .iter()
+        .all(|log| log.message.to_lowercase().contains(&needle.to_lowercase())));
+    Ok(())
+}
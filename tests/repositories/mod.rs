@@ -1,5 +1,11 @@
+mod audit_test;
 mod content_test;
 mod conversations_test;
+mod health_test;
 mod logs_test;
+mod roles_test;
+mod sessions_test;
 mod traffic_test;
+mod traffic_windows_test;
+mod undo_test;
 mod users_test;
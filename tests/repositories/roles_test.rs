@@ -0,0 +1,22 @@
+use serial_test::serial;
+use systemprompt_admin::tools::roles::repository::RolesRepository;
+
+use super::super::common::TestDb;
+
+#[tokio::test]
+#[serial]
+async fn count_role_holders_returns_distinct_positive_counts() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = RolesRepository::new(db.db_pool())?;
+
+    let counts = repo.count_role_holders().await?;
+
+    for pair in counts.windows(2) {
+        assert!(pair[0].role < pair[1].role);
+    }
+    for count in &counts {
+        assert!(!count.role.is_empty());
+        assert!(count.user_count > 0);
+    }
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use serde_json::Map;
+use serial_test::serial;
+use systemprompt_admin::tools::audit::models::AuditScope;
+use systemprompt_admin::tools::sessions::models::{SessionFilter, SessionFlag};
+use systemprompt_admin::tools::sessions::repository::SessionsRepository;
+
+use super::super::common::TestDb;
+
+#[tokio::test]
+#[serial]
+async fn list_sessions_respects_filters() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = SessionsRepository::new(db.db_pool())?;
+
+    let active = repo
+        .list_sessions(&SessionFilter::default(), 100, None)
+        .await?;
+    assert!(active.items.iter().all(|session| session.active()));
+
+    let bots = SessionFilter {
+        status: "all".to_string(),
+        traffic: "bot".to_string(),
+        ..SessionFilter::default()
+    };
+    let sessions = repo.list_sessions(&bots, 100, None).await?;
+    assert!(sessions.items.iter().all(|session| session.is_bot));
+    assert!(sessions
+        .items
+        .windows(2)
+        .all(|pair| pair[0].started_at >= pair[1].started_at));
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn unknown_sessions_are_left_untouched() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = SessionsRepository::new(db.db_pool())?;
+    let session_id = format!("sess-test-{}", uuid::Uuid::new_v4());
+    let scope = AuditScope::new("sessions-test", "sessions", "mark", &Map::new());
+
+    assert!(repo.find_session(&session_id).await?.is_none());
+    assert!(repo.timeline(&session_id, 10).await?.is_empty());
+    assert!(
+        !repo
+            .mark_session(&session_id, SessionFlag::Bot, &scope)
+            .await?
+    );
+    assert!(repo
+        .end_sessions(Some(&session_id), None, &scope)
+        .await?
+        .is_empty());
+    Ok(())
+}
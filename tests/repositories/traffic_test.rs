@@ -1,15 +1,27 @@
+use chrono::{Duration, Utc};
 use serial_test::serial;
+use systemprompt_admin::tools::traffic::bots::{BotDimension, BotTrafficRepository};
+use systemprompt_admin::tools::traffic::navigation::{NavigationRepository, PageBoundary};
 use systemprompt_admin::tools::traffic::repository::TrafficRepository;
+use systemprompt_admin::tools::traffic::windows::TimeWindow;
 
 use super::super::common::TestDb;
 
+fn last_30_days() -> TimeWindow {
+    let now = Utc::now();
+    TimeWindow {
+        start: now - Duration::days(30),
+        end: now,
+    }
+}
+
 #[tokio::test]
 #[serial]
 async fn get_traffic_summary_returns_valid_data() -> anyhow::Result<()> {
     let db = TestDb::new().await?;
     let repo = TrafficRepository::new(db.db_pool())?;
 
-    let summary = repo.get_traffic_summary(30).await?;
+    let summary = repo.get_traffic_summary(&last_30_days()).await?;
 
     assert!(summary.total_sessions >= 0);
     assert!(summary.total_requests >= 0);
@@ -26,7 +38,9 @@ async fn get_device_breakdown_calculates_percentages() -> anyhow::Result<()> {
     let db = TestDb::new().await?;
     let repo = TrafficRepository::new(db.db_pool())?;
 
-    let breakdown = repo.get_device_breakdown_with_trends(30).await?;
+    let breakdown = repo
+        .get_device_breakdown_with_trends(&last_30_days())
+        .await?;
 
     let total_percentage: f64 = breakdown.iter().map(|d| d.percentage).sum();
     if !breakdown.is_empty() {
@@ -45,7 +59,7 @@ async fn get_geographic_breakdown_limits_results() -> anyhow::Result<()> {
     let db = TestDb::new().await?;
     let repo = TrafficRepository::new(db.db_pool())?;
 
-    let breakdown = repo.get_geographic_breakdown(30).await?;
+    let breakdown = repo.get_geographic_breakdown(&last_30_days()).await?;
 
     assert!(breakdown.len() <= 20);
     for item in &breakdown {
@@ -61,7 +75,7 @@ async fn get_browser_breakdown_handles_results() -> anyhow::Result<()> {
     let db = TestDb::new().await?;
     let repo = TrafficRepository::new(db.db_pool())?;
 
-    let breakdown = repo.get_browser_breakdown(30).await?;
+    let breakdown = repo.get_browser_breakdown(&last_30_days()).await?;
 
     assert!(breakdown.len() <= 10);
     for item in &breakdown {
@@ -77,7 +91,7 @@ async fn get_os_breakdown_limits_to_10() -> anyhow::Result<()> {
     let db = TestDb::new().await?;
     let repo = TrafficRepository::new(db.db_pool())?;
 
-    let breakdown = repo.get_os_breakdown(30).await?;
+    let breakdown = repo.get_os_breakdown(&last_30_days()).await?;
 
     assert!(breakdown.len() <= 10);
     for item in &breakdown {
@@ -93,7 +107,7 @@ async fn get_normalized_referrers_returns_valid_data() -> anyhow::Result<()> {
     let db = TestDb::new().await?;
     let repo = TrafficRepository::new(db.db_pool())?;
 
-    let referrers = repo.get_normalized_referrers(30).await?;
+    let referrers = repo.get_normalized_referrers(&last_30_days()).await?;
 
     assert!(referrers.len() <= 20);
     for referrer in &referrers {
@@ -104,3 +118,124 @@ async fn get_normalized_referrers_returns_valid_data() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test]
+#[serial]
+async fn get_user_sessions_returns_exactly_that_users_sessions() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let pool = db.db_pool().pool_arc()?;
+    let repo = TrafficRepository::new(db.db_pool())?;
+
+    let missing = format!("traffic-test-{}", uuid::Uuid::new_v4());
+    assert!(repo.get_user_sessions(&missing, 20).await?.is_empty());
+
+    let busiest: Option<String> = sqlx::query_scalar(
+        "SELECT user_id FROM user_sessions WHERE user_id IS NOT NULL \
+         GROUP BY user_id ORDER BY COUNT(*) DESC, user_id LIMIT 1",
+    )
+    .fetch_optional(&*pool)
+    .await?;
+    let Some(user_id) = busiest else {
+        return Ok(());
+    };
+
+    let expected: Vec<String> = sqlx::query_scalar(
+        "SELECT session_id FROM user_sessions WHERE user_id = $1 \
+         ORDER BY started_at DESC, session_id DESC LIMIT 20",
+    )
+    .bind(&user_id)
+    .fetch_all(&*pool)
+    .await?;
+
+    let sessions = repo.get_user_sessions(&user_id, 20).await?;
+    let actual: Vec<String> = sessions.iter().map(|s| s.session_id.clone()).collect();
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn boundary_pages_are_ranked_per_session() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let pool = db.db_pool().pool_arc()?;
+    let repo = NavigationRepository::new(db.db_pool())?;
+    let window = last_30_days();
+
+    for (boundary, column) in [
+        (PageBoundary::Landing, "started_at"),
+        (PageBoundary::Exit, "ended_at"),
+    ] {
+        let bounded: i64 = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM user_sessions WHERE {column} >= $1 AND {column} < $2 \
+             AND is_bot = false AND is_scanner = false"
+        ))
+        .bind(window.start)
+        .bind(window.end)
+        .fetch_one(&*pool)
+        .await?;
+        let every_page = repo.get_boundary_pages(&window, boundary, i64::MAX).await?;
+        assert!(every_page.iter().map(|page| page.sessions).sum::<i64>() <= bounded);
+
+        let pages = repo.get_boundary_pages(&window, boundary, 20).await?;
+        assert!(pages.len() <= 20);
+        assert!(pages.iter().map(|page| page.percentage).sum::<f64>() <= 100.1);
+        assert!(pages
+            .windows(2)
+            .all(|pair| pair[0].sessions >= pair[1].sessions));
+        for page in &pages {
+            assert!(!page.page.starts_with("GET "));
+            assert!(page.single_page_sessions <= page.sessions);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn navigation_paths_have_the_requested_number_of_steps() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = NavigationRepository::new(db.db_pool())?;
+
+    for steps in [2, 3] {
+        let paths = repo
+            .get_navigation_paths(&last_30_days(), steps, 15)
+            .await?;
+        assert!(paths.len() <= 15);
+        for path in &paths {
+            assert_eq!(path.path.split(" → ").count(), steps as usize);
+            assert!(path.sessions <= path.occurrences);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn bot_report_only_counts_automated_sessions() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = BotTrafficRepository::new(db.db_pool())?;
+    let window = last_30_days();
+
+    let summary = repo.get_bot_summary(&window).await?;
+    assert!(summary.automated_requests() <= summary.total_requests);
+    assert!((0.0..=100.0).contains(&summary.automated_share()));
+
+    for dimension in [BotDimension::UserAgent, BotDimension::Country] {
+        let rows = repo.get_bot_breakdown(&window, dimension, 20).await?;
+        assert!(rows.len() <= 20);
+        assert!(rows.iter().map(|row| row.percentage).sum::<f64>() <= 100.1);
+        let sessions: i64 = rows
+            .iter()
+            .map(|row| row.bot_sessions + row.scanner_sessions)
+            .sum();
+        assert!(sessions <= summary.bot_sessions + summary.scanner_sessions);
+    }
+
+    let endpoints = repo.get_bot_endpoints(&window, 25).await?;
+    assert!(endpoints.len() <= 25);
+    for endpoint in &endpoints {
+        assert_eq!(endpoint.hits, endpoint.bot_hits + endpoint.scanner_hits);
+        assert!(endpoint.sessions <= endpoint.hits);
+    }
+    Ok(())
+}
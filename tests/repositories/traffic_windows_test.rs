@@ -0,0 +1,81 @@
+use chrono::{Duration, NaiveDate, Utc};
+use serial_test::serial;
+use systemprompt_admin::tools::traffic::range::{Granularity, TrafficPeriod, TrafficRange};
+use systemprompt_admin::tools::traffic::series::SeriesRepository;
+use systemprompt_admin::tools::traffic::windows::{TimeWindow, WindowRepository};
+
+use super::super::common::TestDb;
+
+#[tokio::test]
+#[serial]
+async fn custom_ranges_resolve_in_the_requested_timezone() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = WindowRepository::new(db.db_pool())?;
+    let start = NaiveDate::from_ymd_opt(2024, 1, 1).ok_or_else(|| anyhow::anyhow!("bad date"))?;
+    let end = NaiveDate::from_ymd_opt(2024, 1, 31).ok_or_else(|| anyhow::anyhow!("bad date"))?;
+    let range = TrafficRange {
+        period: TrafficPeriod::Dates {
+            start,
+            end: Some(end),
+        },
+        timezone: "Europe/Berlin".to_string(),
+        compare_to: None,
+        granularity: Granularity::Day,
+        include_bots: false,
+    };
+
+    let bounds = repo
+        .resolve(&range)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Europe/Berlin should resolve"))?;
+    let window =
+        TimeWindow::from_bounds(range.period, bounds, Utc::now()).map_err(anyhow::Error::msg)?;
+    assert_eq!(window.start.to_rfc3339(), "2023-12-31T23:00:00+00:00");
+    assert_eq!(window.end.to_rfc3339(), "2024-01-31T23:00:00+00:00");
+
+    let unknown = TrafficRange {
+        timezone: "Mars/Olympus_Mons".to_string(),
+        ..range
+    };
+    assert!(repo.resolve(&unknown).await?.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn traffic_series_zero_fills_every_bucket() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = SeriesRepository::new(db.db_pool())?;
+    let end = Utc::now();
+    let window = TimeWindow {
+        start: end - Duration::days(7),
+        end,
+    };
+
+    let daily = repo
+        .get_traffic_series(&window, Granularity::Day, "UTC")
+        .await?;
+    assert!((7..=8).contains(&daily.len()));
+    assert!(daily.windows(2).all(|pair| pair[0].bucket < pair[1].bucket));
+
+    let hourly = repo
+        .get_traffic_series(&window, Granularity::Hour, "UTC")
+        .await?;
+    assert!((168..=169).contains(&hourly.len()));
+    for point in &hourly {
+        assert!(point.sessions >= 0);
+        assert!(point.requests >= 0);
+        assert!(point.unique_users <= point.sessions);
+        assert!(point.ai_cost_cents >= 0);
+    }
+
+    let decade = TimeWindow {
+        start: end - Duration::days(3650),
+        end,
+    };
+    assert!(repo
+        .get_traffic_series(&decade, Granularity::Hour, "UTC")
+        .await
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use chrono::{Duration, Utc};
+use serde_json::Map;
+use serial_test::serial;
+use systemprompt_admin::tools::audit::models::AuditScope;
+use systemprompt_admin::tools::undo::content::{restore_content_on, snapshot_content_on};
+use systemprompt_admin::tools::undo::models::{IssuedUndo, UndoAction};
+use systemprompt_admin::tools::undo::repository::{store_undo, UndoRepository};
+
+use super::super::common::TestDb;
+
+#[tokio::test]
+#[serial]
+async fn unknown_tokens_are_not_found() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = UndoRepository::new(db.db_pool())?;
+
+    assert!(repo.find("not-a-real-token").await?.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn tokens_for_missing_targets_are_not_redeemed() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let actor_id = format!("undo-test-{}", uuid::Uuid::new_v4());
+    let scope = AuditScope::new(&actor_id, "operations", "delete_file", &Map::new());
+    let undo = IssuedUndo::issue(Utc::now(), Duration::minutes(5)).map_err(anyhow::Error::msg)?;
+    let action = UndoAction::RestoreFile {
+        file_id: uuid::Uuid::new_v4().to_string(),
+    };
+
+    let pool = db.db_pool().pool_arc()?;
+    let mut tx = pool.begin().await?;
+    store_undo(&mut tx, &undo, &scope.entry(action.target_id()), &action).await?;
+    tx.commit().await?;
+
+    let repo = UndoRepository::new(db.db_pool())?;
+    let record = repo
+        .find(&undo.token)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("stored undo token not found"))?;
+    assert_eq!(record.undo, action);
+    assert_eq!(record.action, "delete_file");
+    assert!(record.used_at.is_none());
+
+    let undo_scope = AuditScope::new(&actor_id, "operations", "undo", &Map::new());
+    assert!(!repo.redeem(&undo.token, &record, &undo_scope).await?);
+    let unchanged = repo.find(&undo.token).await?;
+    assert!(unchanged.is_some_and(|record| record.used_at.is_none()));
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn content_snapshots_refuse_to_overwrite_existing_content() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let pool = db.db_pool().pool_arc()?;
+    let mut tx = pool.begin().await?;
+
+    let missing = format!("undo-test-{}", uuid::Uuid::new_v4());
+    assert!(snapshot_content_on(&mut tx, &missing).await?.is_none());
+
+    let existing: Option<String> = sqlx::query_scalar("SELECT id FROM markdown_content LIMIT 1")
+        .fetch_optional(&mut *tx)
+        .await?;
+    let Some(id) = existing else {
+        return Ok(());
+    };
+    let snapshot = snapshot_content_on(&mut tx, &id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("existing content has no snapshot"))?;
+    assert_eq!(snapshot["content"]["id"], id.as_str());
+    assert!(snapshot["dependents"].is_array());
+    assert!(restore_content_on(&mut tx, &id, &snapshot).await.is_err());
+    Ok(())
+}
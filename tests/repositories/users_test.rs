@@ -1,5 +1,13 @@
+use chrono::Duration;
+use serde_json::Map;
 use serial_test::serial;
+use systemprompt_admin::tools::audit::models::{AuditFilter, AuditScope};
+use systemprompt_admin::tools::audit::repository::AuditRepository;
+use systemprompt_admin::tools::undo::models::IssuedUndo;
+use systemprompt_admin::tools::users::models::{UserFilter, UserSort, UserSortColumn};
+use systemprompt_admin::tools::users::plan::{plan_bulk, BulkOperation};
 use systemprompt_admin::tools::users::repository::UsersRepository;
+use systemprompt_admin::tools::users::suspensions::SuspensionRepository;
 
 use super::super::common::TestDb;
 
@@ -9,11 +17,14 @@ async fn list_users_returns_valid_data() -> anyhow::Result<()> {
     let db = TestDb::new().await?;
     let repo = UsersRepository::new(db.db_pool())?;
 
-    let users = repo.list_users(None).await?;
+    let users = repo
+        .list_users(&UserFilter::default(), UserSort::default(), 100, None)
+        .await?;
 
-    for user in &users {
+    for user in &users.items {
         assert!(!user.id.is_empty());
         assert!(!user.status.is_empty());
+        assert_ne!(user.status, "deleted");
         assert!(user.total_sessions >= 0);
     }
     Ok(())
@@ -25,8 +36,195 @@ async fn list_users_limits_results() -> anyhow::Result<()> {
     let db = TestDb::new().await?;
     let repo = UsersRepository::new(db.db_pool())?;
 
-    let users = repo.list_users(None).await?;
+    let users = repo
+        .list_users(&UserFilter::default(), UserSort::default(), 10, None)
+        .await?;
 
-    assert!(users.len() <= 100);
+    assert!(users.items.len() <= 10);
+    if users.next_cursor.is_some() {
+        assert_eq!(users.items.len(), 10);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn list_users_cursor_does_not_repeat_rows() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = UsersRepository::new(db.db_pool())?;
+    let sort = UserSort {
+        column: UserSortColumn::Name,
+        ascending: true,
+    };
+
+    let page1 = repo
+        .list_users(&UserFilter::default(), sort, 2, None)
+        .await?;
+
+    if let Some(cursor) = page1.next_cursor.as_ref() {
+        let page2 = repo
+            .list_users(&UserFilter::default(), sort, 2, Some(cursor))
+            .await?;
+        for user in &page2.items {
+            assert!(page1.items.iter().all(|seen| seen.id != user.id));
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn list_users_applies_filters() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = UsersRepository::new(db.db_pool())?;
+    let filter = UserFilter {
+        role: Some("admin".to_string()),
+        min_sessions: Some(1),
+        ..UserFilter::default()
+    };
+
+    let users = repo
+        .list_users(&filter, UserSort::default(), 100, None)
+        .await?;
+
+    for user in &users.items {
+        assert!(user.roles.iter().any(|r| r == "admin"));
+        assert!(user.total_sessions >= 1);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn list_users_deleted_view_only_returns_deleted() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = UsersRepository::new(db.db_pool())?;
+    let filter = UserFilter {
+        status: Some("deleted".to_string()),
+        ..UserFilter::default()
+    };
+
+    let users = repo
+        .list_users(&filter, UserSort::default(), 100, None)
+        .await?;
+
+    assert!(users.items.iter().all(|u| u.status == "deleted"));
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn list_users_sorts_by_session_count() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = UsersRepository::new(db.db_pool())?;
+    let sort = UserSort {
+        column: UserSortColumn::TotalSessions,
+        ascending: false,
+    };
+
+    let users = repo
+        .list_users(&UserFilter::default(), sort, 50, None)
+        .await?;
+
+    for pair in users.items.windows(2) {
+        assert!(pair[0].total_sessions >= pair[1].total_sessions);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn find_user_matches_listing_and_handles_unknown_ids() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = UsersRepository::new(db.db_pool())?;
+
+    assert!(repo.find_user("nonexistent-user-id").await?.is_none());
+
+    let listed = repo
+        .list_users(&UserFilter::default(), UserSort::default(), 1, None)
+        .await?;
+    if let Some(user) = listed.items.first() {
+        let found = repo.find_user(&user.id).await?;
+        assert_eq!(found.map(|u| u.email), Some(user.email.clone()));
+    }
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn lifecycle_changes_ignore_unknown_users() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = SuspensionRepository::new(db.db_pool())?;
+    let actor_id = format!("users-test-{}", uuid::Uuid::new_v4());
+    let audit = |action: &str| AuditScope::new(&actor_id, "user", action, &Map::new());
+
+    let undo =
+        IssuedUndo::issue(chrono::Utc::now(), Duration::minutes(30)).map_err(anyhow::Error::msg)?;
+
+    assert!(repo
+        .suspend_user(
+            "nonexistent-user-id",
+            "test",
+            None,
+            &audit("suspend"),
+            &undo
+        )
+        .await?
+        .is_none());
+    assert!(repo
+        .reactivate_user("nonexistent-user-id", &audit("reactivate"))
+        .await?
+        .is_none());
+    assert!(repo
+        .restore_user("nonexistent-user-id", &audit("restore"))
+        .await?
+        .is_none());
+    assert!(repo
+        .find_active_suspension("nonexistent-user-id")
+        .await?
+        .is_none());
+
+    let filter = AuditFilter {
+        actor_id: Some(actor_id.clone()),
+        ..AuditFilter::default()
+    };
+    let recorded = AuditRepository::new(db.db_pool())?
+        .list(&filter, 10, None)
+        .await?;
+    assert!(recorded.items.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn lift_expired_suspensions_leaves_no_expired_open_suspensions() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = SuspensionRepository::new(db.db_pool())?;
+
+    repo.lift_expired_suspensions().await?;
+
+    assert_eq!(repo.lift_expired_suspensions().await?, 0);
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn apply_bulk_ignores_skipped_changes() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let repo = UsersRepository::new(db.db_pool())?;
+    let operation = BulkOperation::AssignRole("user".to_string());
+    let filter = UserFilter {
+        role: Some("user".to_string()),
+        ..UserFilter::default()
+    };
+
+    let users = repo
+        .list_users(&filter, UserSort::default(), 5, None)
+        .await?;
+    let changes = plan_bulk(&users.items, &operation);
+
+    assert!(changes.iter().all(|c| !c.applies()));
+    let audit = AuditScope::new("test-admin", "user", "bulk.assign_role", &Map::new());
+    assert_eq!(repo.apply_bulk(&changes, &operation, &audit, &[]).await?, 0);
     Ok(())
 }
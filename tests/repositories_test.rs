@@ -1,2 +1,6 @@
-mod common;
+mod common {
+    mod db;
+
+    pub use db::TestDb;
+}
 mod repositories;
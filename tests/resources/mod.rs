@@ -0,0 +1 @@
+mod uri_test;
@@ -0,0 +1,109 @@
+use systemprompt_admin::resources::{resource_templates, AdminResourceUri, ERROR_LOGS_URI};
+
+#[test]
+fn parse_recognises_every_resource_kind() {
+    let cases = [
+        (
+            "admin://users/u-1",
+            AdminResourceUri::User("u-1".to_string()),
+        ),
+        (
+            "admin://conversations/ctx-1",
+            AdminResourceUri::Conversation("ctx-1".to_string()),
+        ),
+        (
+            "admin://logs/log-1",
+            AdminResourceUri::Log("log-1".to_string()),
+        ),
+        (
+            "admin://jobs/database_cleanup",
+            AdminResourceUri::Job("database_cleanup".to_string()),
+        ),
+        (
+            "admin://files/4f1c2c7e-8a55-4b7a-9a63-2f0d4d2b8f11",
+            AdminResourceUri::File("4f1c2c7e-8a55-4b7a-9a63-2f0d4d2b8f11".to_string()),
+        ),
+    ];
+
+    for (uri, expected) in cases {
+        assert_eq!(AdminResourceUri::parse(uri), Some(expected), "{uri}");
+    }
+}
+
+#[test]
+fn parse_rejects_unknown_or_malformed_uris() {
+    let invalid = [
+        "admin://unknown/1",
+        "admin://users/",
+        "admin://users",
+        "admin://users/1/extra",
+        "file://users/1",
+        "",
+    ];
+
+    for uri in invalid {
+        assert_eq!(AdminResourceUri::parse(uri), None, "{uri}");
+    }
+}
+
+#[test]
+fn display_round_trips_through_parse() {
+    let uri = AdminResourceUri::Conversation("ctx-42".to_string());
+
+    assert_eq!(uri.to_string(), "admin://conversations/ctx-42");
+    assert_eq!(AdminResourceUri::parse(&uri.to_string()), Some(uri));
+}
+
+#[test]
+fn resource_templates_cover_all_kinds() {
+    let templates = resource_templates();
+    let uris: Vec<&str> = templates.iter().map(|t| t.uri_template.as_str()).collect();
+
+    for prefix in ["users", "conversations", "logs", "jobs", "files"] {
+        assert!(
+            uris.iter()
+                .any(|u| u.starts_with(&format!("admin://{prefix}/{{"))),
+            "Missing template for {prefix}"
+        );
+    }
+}
+
+#[test]
+fn error_logs_uri_is_not_a_log_id() {
+    assert_eq!(
+        AdminResourceUri::parse(ERROR_LOGS_URI),
+        Some(AdminResourceUri::ErrorLogs)
+    );
+    assert_eq!(AdminResourceUri::ErrorLogs.to_string(), ERROR_LOGS_URI);
+}
+
+#[test]
+fn only_error_logs_and_jobs_are_subscribable() {
+    assert!(AdminResourceUri::ErrorLogs.is_subscribable());
+    assert!(AdminResourceUri::Job("database_cleanup".to_string()).is_subscribable());
+
+    assert!(!AdminResourceUri::User("u-1".to_string()).is_subscribable());
+    assert!(!AdminResourceUri::Log("log-1".to_string()).is_subscribable());
+    assert!(!AdminResourceUri::File("f-1".to_string()).is_subscribable());
+}
+
+#[test]
+fn every_resource_kind_maps_to_its_read_permission() {
+    let cases = [
+        ("admin://users/u-1", "admin.users.read"),
+        ("admin://conversations/c-1", "admin.conversations.read"),
+        (ERROR_LOGS_URI, "admin.logs.read"),
+        ("admin://logs/log-1", "admin.logs.read"),
+        ("admin://jobs/database_cleanup", "admin.jobs.read"),
+        ("admin://files/f-1", "admin.files.read"),
+    ];
+
+    for (uri, permission) in cases {
+        let parsed = AdminResourceUri::parse(uri);
+        assert_eq!(
+            parsed.as_ref().map(AdminResourceUri::read_permission),
+            Some(permission),
+            "{uri}"
+        );
+    }
+}
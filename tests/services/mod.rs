@@ -0,0 +1,2 @@
+mod token_outbox_test;
+mod user_tokens_test;
@@ -0,0 +1,56 @@
+use serde_json::Value as JsonValue;
+use std::os::unix::fs::PermissionsExt;
+use systemprompt_admin::services::user_tokens::{issue_token, TokenMessage, TokenPurpose};
+use systemprompt_admin::services::{token_delivery_from_env, OutboxDelivery, TokenDelivery};
+
+use super::super::common::fixed_now;
+
+#[test]
+fn outbox_is_configured_only_by_a_non_empty_path() {
+    assert!(token_delivery_from_env(None).is_none());
+    assert!(token_delivery_from_env(Some("  ")).is_none());
+    assert_eq!(
+        token_delivery_from_env(Some("/tmp/outbox.jsonl")).map(|delivery| delivery.channel()),
+        Some("outbox")
+    );
+}
+
+#[test]
+fn outbox_appends_one_private_json_line_per_message() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("outbox-{}.jsonl", uuid::Uuid::new_v4()));
+    let delivery = OutboxDelivery::new(&path);
+    let now = fixed_now().map_err(anyhow::Error::msg)?;
+    let messages: Vec<TokenMessage> = [TokenPurpose::Invite, TokenPurpose::PasswordReset]
+        .into_iter()
+        .map(|purpose| {
+            let issued = issue_token(purpose, now);
+            TokenMessage {
+                user_id: "user-1".to_string(),
+                email: "ada@example.com".to_string(),
+                purpose,
+                token: issued.token,
+                expires_at: issued.expires_at,
+            }
+        })
+        .collect();
+
+    for message in &messages {
+        delivery.deliver(message)?;
+    }
+    let written = std::fs::read_to_string(delivery.path())?;
+    let mode = std::fs::metadata(delivery.path())?.permissions().mode();
+    std::fs::remove_file(delivery.path())?;
+
+    let lines = written
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<Vec<JsonValue>, _>>()?;
+    assert_eq!(lines.len(), messages.len());
+    for (line, message) in lines.iter().zip(&messages) {
+        assert_eq!(line["token"], message.token.as_str());
+        assert_eq!(line["purpose"], message.purpose.as_str());
+        assert_eq!(line["email"], "ada@example.com");
+    }
+    assert_eq!(mode & 0o777, 0o600);
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anyhow::anyhow;
+use chrono::Duration;
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use serial_test::serial;
+use std::sync::Arc;
+use systemprompt::identifiers::McpExecutionId;
+use systemprompt_admin::services::role_discovery::default_core_roles;
+use systemprompt_admin::services::user_tokens::{hash_token, issue_token, TokenPurpose};
+use systemprompt_admin::services::TokenDelivery;
+use systemprompt_admin::tools::permissions::Caller;
+use systemprompt_admin::tools::users::create::handle_create_user;
+use systemprompt_admin::tools::users::repository::UsersRepository;
+use systemprompt_admin::tools::users::tokens::UserTokenRepository;
+use systemprompt_admin::tools::ToolState;
+
+use super::super::common::{args, fixed_now, RecordingDelivery, TestDb};
+
+#[test]
+fn token_purposes_round_trip() {
+    for purpose in [TokenPurpose::Invite, TokenPurpose::PasswordReset] {
+        assert_eq!(TokenPurpose::parse(purpose.as_str()), Some(purpose));
+    }
+    assert_eq!(TokenPurpose::parse("magic_link"), None);
+}
+
+#[test]
+fn issued_tokens_are_unique_and_stored_as_hashes() -> Result<(), String> {
+    let now = fixed_now()?;
+
+    let first = issue_token(TokenPurpose::Invite, now);
+    let second = issue_token(TokenPurpose::Invite, now);
+
+    assert_ne!(first.token, second.token);
+    assert_eq!(first.token.len(), 64);
+    assert_eq!(first.token_hash, hash_token(&first.token));
+    assert_ne!(first.token_hash, first.token);
+    assert_eq!(first.expires_at, now + Duration::days(7));
+    assert_eq!(
+        issue_token(TokenPurpose::PasswordReset, now).expires_at,
+        now + Duration::hours(1)
+    );
+    Ok(())
+}
+
+fn invite_state(delivery: Option<RecordingDelivery>) -> ToolState {
+    let roles = default_core_roles();
+    let caller = Caller::new("admin-1", vec!["admin".to_string()], &roles);
+    ToolState::new(
+        Arc::new(roles),
+        delivery.map(|delivery| Arc::new(delivery) as Arc<dyn TokenDelivery>),
+        caller,
+    )
+}
+
+fn create_args(email: &str) -> JsonMap<String, JsonValue> {
+    args(json!({
+        "action": "create",
+        "name": email.split('@').next(),
+        "email": email,
+        "roles": ["user"],
+        "send": "invite"
+    }))
+}
+
+#[tokio::test]
+#[serial]
+async fn create_user_delivers_a_redeemable_invite_through_the_channel() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let delivery = RecordingDelivery::default();
+    let state = invite_state(Some(delivery.clone()));
+    let email = format!("invite-{}@example.com", uuid::Uuid::new_v4().simple());
+    let execution_id = McpExecutionId::new(uuid::Uuid::new_v4().to_string());
+
+    let result = handle_create_user(&db.db_pool(), &create_args(&email), &state, &execution_id)
+        .await
+        .map_err(|e| anyhow!(e.message))?;
+
+    let structured = result
+        .structured_content
+        .ok_or_else(|| anyhow!("create returned no structured content"))?;
+    let user_id = structured["user"]["id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("create returned no user id"))?;
+    let sent = delivery.sent()?;
+    let [message] = sent.as_slice() else {
+        anyhow::bail!("expected one delivered token, got {}", sent.len());
+    };
+    assert_eq!(message.user_id, user_id);
+    assert_eq!(message.email, email);
+    assert_eq!(message.purpose, TokenPurpose::Invite);
+    assert_eq!(structured["token"]["channel"], "recording");
+    assert!(!structured.to_string().contains(&message.token));
+
+    let tokens = UserTokenRepository::new(db.db_pool())?;
+    assert!(tokens
+        .redeem(&message.token, TokenPurpose::PasswordReset)
+        .await?
+        .is_none());
+    assert_eq!(
+        tokens
+            .redeem(&message.token, TokenPurpose::Invite)
+            .await?
+            .as_deref(),
+        Some(user_id)
+    );
+    assert!(tokens
+        .redeem(&message.token, TokenPurpose::Invite)
+        .await?
+        .is_none());
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn create_user_refuses_to_send_without_a_channel() -> anyhow::Result<()> {
+    let db = TestDb::new().await?;
+    let email = format!("invite-{}@example.com", uuid::Uuid::new_v4().simple());
+    let execution_id = McpExecutionId::new(uuid::Uuid::new_v4().to_string());
+
+    let denied = handle_create_user(
+        &db.db_pool(),
+        &create_args(&email),
+        &invite_state(None),
+        &execution_id,
+    )
+    .await;
+
+    assert!(denied.is_err_and(|e| e.message.contains("ADMIN_TOKEN_OUTBOX")));
+    assert!(
+        !UsersRepository::new(db.db_pool())?
+            .email_exists(&email)
+            .await?
+    );
+    Ok(())
+}
@@ -0,0 +1,12 @@
+mod common {
+    mod args;
+    mod clock;
+    mod db;
+    mod delivery;
+
+    pub use args::args;
+    pub use clock::fixed_now;
+    pub use db::TestDb;
+    pub use delivery::RecordingDelivery;
+}
+mod services;
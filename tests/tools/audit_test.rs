@@ -0,0 +1,84 @@
+use chrono::{Duration, Utc};
+use serde_json::{json, Map, Value as JsonValue};
+use systemprompt_admin::tools::audit::filter::parse_audit_filter;
+use systemprompt_admin::tools::audit::models::AuditScope;
+use systemprompt_admin::tools::users::plan::PlannedChange;
+
+use super::super::common::{args, utc};
+
+#[test]
+fn parse_audit_filter_reads_every_field() -> Result<(), String> {
+    let now = utc(2025, 3, 1, 12, 0)?;
+
+    let filter = parse_audit_filter(
+        &args(json!({
+            "actor_id": "admin-1",
+            "target_id": " user-9 ",
+            "tool": "user",
+            "action": "remove_role",
+            "since": "7d",
+            "until": "2025-03-01T00:00:00Z"
+        })),
+        now,
+    )?;
+
+    assert_eq!(filter.actor_id.as_deref(), Some("admin-1"));
+    assert_eq!(filter.target_id.as_deref(), Some("user-9"));
+    assert_eq!(filter.tool.as_deref(), Some("user"));
+    assert_eq!(filter.action.as_deref(), Some("remove_role"));
+    assert_eq!(filter.since, Some(now - Duration::days(7)));
+    Ok(())
+}
+
+#[test]
+fn parse_audit_filter_rejects_inverted_ranges() {
+    let result = parse_audit_filter(&args(json!({ "since": "1h", "until": "2d" })), Utc::now());
+    assert!(result.is_err());
+}
+
+#[test]
+fn audit_scope_entries_carry_actor_and_arguments() {
+    let arguments = args(json!({ "action": "delete_file", "uuid": "f-1" }));
+    let scope = AuditScope::new("admin-1", "operations", "delete_file", &arguments);
+
+    let entry = scope.entry("f-1").with_change(
+        json!({ "deleted_at": null }),
+        json!({ "deleted_at": "now" }),
+    );
+
+    assert_eq!(entry.actor_id, "admin-1");
+    assert_eq!(entry.tool, "operations");
+    assert_eq!(entry.action, "delete_file");
+    assert_eq!(entry.target_id, "f-1");
+    assert_eq!(entry.arguments, JsonValue::Object(arguments));
+    assert_eq!(entry.before, Some(json!({ "deleted_at": null })));
+    assert_eq!(entry.after, Some(json!({ "deleted_at": "now" })));
+}
+
+#[test]
+fn planned_change_audit_entry_snapshots_status_and_roles() {
+    let change = PlannedChange {
+        user_id: "user-1".to_string(),
+        name: "ada".to_string(),
+        email: "ada@example.com".to_string(),
+        status_before: "active".to_string(),
+        status_after: "active".to_string(),
+        roles_before: vec!["user".to_string()],
+        roles_after: vec!["user".to_string(), "editor".to_string()],
+        skip_reason: None,
+    };
+    let scope = AuditScope::new("admin-1", "user", "bulk.assign_role", &Map::new());
+
+    let entry = change.audit_entry(&scope);
+
+    assert_eq!(entry.target_id, "user-1");
+    assert_eq!(entry.action, "bulk.assign_role");
+    assert_eq!(
+        entry.before,
+        Some(json!({ "status": "active", "roles": ["user"] }))
+    );
+    assert_eq!(
+        entry.after,
+        Some(json!({ "status": "active", "roles": ["user", "editor"] }))
+    );
+}
@@ -0,0 +1,59 @@
+use chrono::Duration;
+use serde_json::json;
+use systemprompt_admin::services::user_tokens::hash_token;
+use systemprompt_admin::tools::confirmation::models::{
+    parse_confirmation_setting, DeletionPreview, IssuedConfirmation, CONFIRMATION_TTL_MINUTES,
+};
+
+use super::super::common::fixed_now;
+
+fn file_preview() -> DeletionPreview {
+    DeletionPreview::new("operations", "delete_file", "f-1", "file /uploads/logo.png")
+        .with_field("Path", "/uploads/logo.png")
+        .with_field("Type", "image/png")
+}
+
+#[test]
+fn confirmation_setting_accepts_common_truthy_values() {
+    for value in ["1", "true", "TRUE", " yes ", "on"] {
+        assert!(parse_confirmation_setting(Some(value)), "{value} is off");
+    }
+    for value in ["0", "false", "off", "", "maybe"] {
+        assert!(!parse_confirmation_setting(Some(value)), "{value} is on");
+    }
+    assert!(!parse_confirmation_setting(None));
+}
+
+#[test]
+fn preview_message_lists_what_will_be_deleted() {
+    let message = file_preview().message();
+
+    assert!(message.starts_with("Confirm operations delete_file: file /uploads/logo.png."));
+    assert!(message.contains("Path: /uploads/logo.png"));
+    assert!(message.contains("Type: image/png"));
+}
+
+#[test]
+fn preview_json_keeps_target_and_fields() {
+    let value = file_preview().to_json();
+
+    assert_eq!(value["target_id"], json!("f-1"));
+    assert_eq!(value["action"], json!("delete_file"));
+    assert_eq!(value["fields"]["Path"], json!("/uploads/logo.png"));
+}
+
+#[test]
+fn issued_confirmations_are_short_lived_and_hashed() -> Result<(), String> {
+    let now = fixed_now()?;
+    let first = IssuedConfirmation::issue(now);
+    let second = IssuedConfirmation::issue(now);
+
+    assert_ne!(first.token, second.token);
+    assert_eq!(first.token_hash, hash_token(&first.token));
+    assert_eq!(
+        first.expires_at,
+        now + Duration::minutes(CONFIRMATION_TTL_MINUTES)
+    );
+    assert_eq!(first.to_json()["token"], json!(first.token));
+    Ok(())
+}
@@ -6,11 +6,15 @@ fn register_tools_returns_all_expected_tools() {
 
     let expected_names = [
         "user",
+        "roles",
+        "audit",
+        "sessions",
         "traffic",
         "content",
         "conversations",
         "logs",
         "jobs",
+        "health",
         "operations",
     ];
 
@@ -56,7 +60,7 @@ fn all_tools_have_input_and_output_schemas() {
 #[test]
 fn register_tools_returns_correct_count() {
     let tools = register_tools();
-    assert_eq!(tools.len(), 7);
+    assert_eq!(tools.len(), 11);
 }
 
 #[test]
@@ -0,0 +1,128 @@
+use systemprompt_admin::tools::health::assessment::assess;
+use systemprompt_admin::tools::health::models::{
+    DatabaseHealth, HealthLevel, JobIssue, SystemResources,
+};
+use systemprompt_admin::tools::health::system::{
+    disk_usage_kb, parse_loadavg, parse_meminfo_field,
+};
+
+fn healthy_database() -> DatabaseHealth {
+    DatabaseHealth {
+        reachable: true,
+        latency_ms: 2.0,
+        pool_size: 4,
+        pool_idle: 3,
+        pool_max: 10,
+        error: None,
+    }
+}
+
+fn healthy_resources() -> SystemResources {
+    SystemResources {
+        mem_total_kb: Some(8_000_000),
+        mem_available_kb: Some(4_000_000),
+        load_1m: Some(0.5),
+        cpu_count: Some(4),
+        disk_total_kb: Some(100_000_000),
+        disk_used_kb: Some(40_000_000),
+    }
+}
+
+#[test]
+fn parse_meminfo_field_reads_kilobytes() {
+    let meminfo =
+        "MemTotal:       16316412 kB\nMemFree:         1234567 kB\nMemAvailable:    8123456 kB\n";
+
+    assert_eq!(parse_meminfo_field(meminfo, "MemTotal"), Some(16_316_412));
+    assert_eq!(
+        parse_meminfo_field(meminfo, "MemAvailable"),
+        Some(8_123_456)
+    );
+    assert_eq!(parse_meminfo_field(meminfo, "SwapTotal"), None);
+}
+
+#[test]
+fn parse_loadavg_reads_one_minute_average() {
+    assert_eq!(parse_loadavg("0.52 0.58 0.59 1/467 12345\n"), Some(0.52));
+    assert_eq!(parse_loadavg(""), None);
+}
+
+#[test]
+fn disk_usage_kb_converts_blocks_to_kilobytes() {
+    assert_eq!(
+        disk_usage_kb(25_600_000, 12_800_000, 4096),
+        Some((102_400_000, 51_200_000))
+    );
+    assert_eq!(disk_usage_kb(10, 20, 4096), None);
+    assert_eq!(disk_usage_kb(u64::MAX, 0, 4096), None);
+}
+
+#[test]
+fn assess_is_green_when_all_checks_pass() {
+    let assessment = assess(&healthy_database(), &[], &healthy_resources());
+
+    assert_eq!(assessment.level, HealthLevel::Green);
+    assert!(assessment.findings.is_empty());
+}
+
+#[test]
+fn assess_is_amber_for_failing_jobs() {
+    let issues = vec![JobIssue {
+        job_name: "cleanup".to_string(),
+        issue: "failing".to_string(),
+        detail: "timeout".to_string(),
+    }];
+
+    let assessment = assess(&healthy_database(), &issues, &healthy_resources());
+
+    assert_eq!(assessment.level, HealthLevel::Amber);
+    assert_eq!(assessment.findings.len(), 1);
+    assert_eq!(assessment.findings[0].source, "scheduler");
+}
+
+#[test]
+fn assess_is_red_when_database_is_unreachable() {
+    let database = DatabaseHealth {
+        reachable: false,
+        error: Some("connection refused".to_string()),
+        ..healthy_database()
+    };
+
+    let assessment = assess(&database, &[], &healthy_resources());
+
+    assert_eq!(assessment.level, HealthLevel::Red);
+    assert!(assessment.findings[0]
+        .message
+        .contains("connection refused"));
+}
+
+#[test]
+fn assess_escalates_on_disk_and_memory_pressure() {
+    let resources = SystemResources {
+        mem_available_kb: Some(200_000),
+        disk_used_kb: Some(90_000_000),
+        ..healthy_resources()
+    };
+
+    let assessment = assess(&healthy_database(), &[], &resources);
+
+    assert_eq!(assessment.level, HealthLevel::Red);
+    assert!(assessment
+        .findings
+        .iter()
+        .any(|f| f.source == "memory" && f.level == HealthLevel::Red));
+    assert!(assessment
+        .findings
+        .iter()
+        .any(|f| f.source == "disk" && f.level == HealthLevel::Amber));
+}
+
+#[test]
+fn probe_failures_degrade_a_healthy_assessment() {
+    let assessment = assess(&healthy_database(), &[], &healthy_resources())
+        .with_probe_failure("database", "Failed to count logs: timeout".to_string());
+
+    assert_eq!(assessment.level, HealthLevel::Amber);
+    assert_eq!(assessment.findings.len(), 1);
+    assert_eq!(assessment.findings[0].source, "database");
+}
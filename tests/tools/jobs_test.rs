@@ -0,0 +1,77 @@
+use anyhow::anyhow;
+use serde_json::Value as JsonValue;
+use systemprompt_admin::tools::jobs::runner::{registered_job_names, JobRun};
+use systemprompt_admin::tools::{jobs_input_schema, jobs_output_schema};
+
+#[test]
+fn successful_run_keeps_message_and_status() {
+    let run = JobRun::from_outcome("cleanup", Ok(Some("removed 3 rows".to_string())), 42);
+
+    assert!(run.succeeded);
+    assert_eq!(run.status(), "success");
+    assert_eq!(run.message.as_deref(), Some("removed 3 rows"));
+    assert_eq!(run.error, None);
+    assert_eq!(run.summary(), "succeeded in 42ms");
+}
+
+#[test]
+fn failed_run_reports_error() {
+    let run = JobRun::from_outcome("cleanup", Err("connection refused".to_string()), 7);
+
+    assert!(!run.succeeded);
+    assert_eq!(run.status(), "failed");
+    assert_eq!(run.summary(), "failed after 7ms: connection refused");
+}
+
+#[test]
+fn bookkeeping_failure_preserves_job_outcome() {
+    let run = JobRun::from_outcome("cleanup", Ok(None), 12)
+        .with_record_result(Err(anyhow!("scheduled_jobs is locked")));
+
+    assert!(run.succeeded);
+    assert_eq!(
+        run.record_error.as_deref(),
+        Some("scheduled_jobs is locked")
+    );
+    assert_eq!(
+        run.summary(),
+        "succeeded in 12ms (the run could not be recorded: scheduled_jobs is locked)"
+    );
+}
+
+#[test]
+fn recorded_run_has_no_record_error() {
+    let run =
+        JobRun::from_outcome("cleanup", Err("boom".to_string()), 3).with_record_result(Ok(()));
+
+    assert!(!run.succeeded);
+    assert_eq!(run.record_error, None);
+}
+
+#[test]
+fn jobs_output_schema_covers_list_and_execute_shapes() {
+    let schema = jobs_output_schema();
+    let shapes = schema["oneOf"].as_array().map(Vec::len);
+
+    assert_eq!(schema["type"], "object");
+    assert_eq!(shapes, Some(2));
+    assert_eq!(
+        schema["oneOf"][0]["properties"]["x-artifact-type"]["enum"][0],
+        "table"
+    );
+}
+
+#[test]
+fn execute_job_enum_lists_registered_jobs_only_when_there_are_any() {
+    let schema = jobs_input_schema();
+    let execute_job = &schema["properties"]["execute_job"];
+    let names = registered_job_names();
+
+    match execute_job.get("enum").and_then(JsonValue::as_array) {
+        Some(values) => {
+            assert!(!values.is_empty());
+            assert_eq!(values.len(), names.len());
+        }
+        None => assert!(names.is_empty()),
+    }
+}
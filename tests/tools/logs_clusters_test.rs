@@ -0,0 +1,127 @@
+use chrono::{DateTime, Duration, Utc};
+use systemprompt_admin::tools::logs::clusters::{
+    bucket_labels, cluster_events, fingerprint, hour_buckets, sparkline,
+};
+use systemprompt_admin::tools::logs::models::LogEvent;
+
+use super::super::common::utc;
+
+fn event(timestamp: DateTime<Utc>, level: &str, module: &str, message: &str) -> LogEvent {
+    LogEvent {
+        timestamp,
+        level: level.to_string(),
+        module: module.to_string(),
+        message: message.to_string(),
+    }
+}
+
+#[test]
+fn fingerprint_strips_uuids_numbers_and_paths() {
+    assert_eq!(
+        fingerprint("Task 550e8400-e29b-41d4-a716-446655440000 failed after 3021ms"),
+        "Task <uuid> failed after <n>ms"
+    );
+    assert_eq!(
+        fingerprint("Failed to open /var/lib/app/cache/17.json: permission denied"),
+        "Failed to open <path>: permission denied"
+    );
+    assert_eq!(
+        fingerprint("retry 3 of 5 for user=42"),
+        "retry <n> of <n> for user=<n>"
+    );
+}
+
+#[test]
+fn fingerprint_groups_messages_that_differ_only_in_identifiers() {
+    assert_eq!(
+        fingerprint("Context a1b2c3d4-0000-1111-2222-333344445555 not found"),
+        fingerprint("Context ffffffff-9999-8888-7777-666655554444 not found")
+    );
+    assert_ne!(
+        fingerprint("Context not found"),
+        fingerprint("Session not found")
+    );
+}
+
+#[test]
+fn sparkline_scales_to_maximum() {
+    assert_eq!(sparkline(&[0, 1, 2, 4]), " ▂▄█");
+    assert_eq!(sparkline(&[0, 0]), "  ");
+    assert_eq!(sparkline(&[]), "");
+}
+
+#[test]
+fn hour_buckets_round_up_partial_hours() -> Result<(), String> {
+    let since = utc(2025, 3, 1, 0, 0)?;
+
+    assert_eq!(hour_buckets(since, since + Duration::hours(24)), 24);
+    assert_eq!(hour_buckets(since, since + Duration::minutes(90)), 2);
+    assert_eq!(hour_buckets(since, since), 1);
+    assert_eq!(bucket_labels(since, 2), vec!["03-01 00:00", "03-01 01:00"]);
+    Ok(())
+}
+
+#[test]
+fn cluster_events_counts_buckets_and_sorts_by_growth() -> Result<(), String> {
+    let since = utc(2025, 3, 1, 0, 0)?;
+    let until = since + Duration::hours(4);
+
+    let events = vec![
+        event(
+            since + Duration::minutes(5),
+            "WARN",
+            "db",
+            "Slow query took 812ms",
+        ),
+        event(
+            since + Duration::minutes(10),
+            "WARN",
+            "db",
+            "Slow query took 950ms",
+        ),
+        event(
+            since + Duration::minutes(200),
+            "ERROR",
+            "agent",
+            "Task 1 failed",
+        ),
+        event(
+            since + Duration::minutes(210),
+            "ERROR",
+            "api",
+            "Task 2 failed",
+        ),
+        event(
+            since + Duration::minutes(220),
+            "ERROR",
+            "agent",
+            "Task 3 failed",
+        ),
+        event(
+            until + Duration::minutes(1),
+            "ERROR",
+            "agent",
+            "Task 4 failed",
+        ),
+    ];
+
+    let clusters = cluster_events(&events, since, until);
+
+    assert_eq!(clusters.len(), 2);
+
+    let growing = &clusters[0];
+    assert_eq!(growing.fingerprint, "Task <n> failed");
+    assert_eq!(growing.count, 3);
+    assert_eq!(growing.level, "ERROR");
+    assert_eq!(growing.modules, vec!["agent", "api"]);
+    assert_eq!(growing.hourly, vec![0, 0, 0, 3]);
+    assert_eq!(growing.first_seen, since + Duration::minutes(200));
+    assert_eq!(growing.last_seen, since + Duration::minutes(220));
+    assert_eq!(growing.growth, 3);
+
+    let fading = &clusters[1];
+    assert_eq!(fading.fingerprint, "Slow query took <n>ms");
+    assert_eq!(fading.hourly, vec![2, 0, 0, 0]);
+    assert_eq!(fading.growth, -2);
+    Ok(())
+}
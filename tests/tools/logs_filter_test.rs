@@ -0,0 +1,102 @@
+use chrono::{Duration, Utc};
+use serde_json::{json, Map};
+use systemprompt_admin::tools::logs::filter::{parse_log_filter, parse_time_bound};
+use systemprompt_admin::tools::logs::models::{ModuleMatch, SearchMode};
+
+use super::super::common::{args, utc};
+
+#[test]
+fn parse_time_bound_accepts_rfc3339_and_relative_durations() -> Result<(), String> {
+    let now = utc(2025, 1, 10, 12, 0)?;
+
+    assert_eq!(
+        parse_time_bound("2025-01-09T08:30:00Z", now),
+        Ok(utc(2025, 1, 9, 8, 30)?)
+    );
+    assert_eq!(
+        parse_time_bound("15m", now),
+        Ok(now - Duration::minutes(15))
+    );
+    assert_eq!(parse_time_bound("1h", now), Ok(now - Duration::hours(1)));
+    assert_eq!(parse_time_bound("7d", now), Ok(now - Duration::days(7)));
+    Ok(())
+}
+
+#[test]
+fn parse_time_bound_rejects_invalid_values() {
+    let now = Utc::now();
+
+    assert!(parse_time_bound("yesterday", now).is_err());
+    assert!(parse_time_bound("-1h", now).is_err());
+    assert!(parse_time_bound("1w", now).is_err());
+    assert!(parse_time_bound("", now).is_err());
+}
+
+#[test]
+fn parse_time_bound_rejects_huge_amounts() {
+    let now = Utc::now();
+
+    assert!(parse_time_bound("99999999999999d", now).is_err());
+    assert!(parse_time_bound("9999999999999999h", now).is_err());
+    assert!(parse_time_bound("999999999999m", now).is_err());
+    assert!(parse_time_bound("100000000d", now).is_err());
+}
+
+#[test]
+fn parse_log_filter_reads_all_filters() -> Result<(), String> {
+    let now = Utc::now();
+    let filter = parse_log_filter(
+        &args(json!({
+            "level": "ERROR",
+            "since": "1h",
+            "module": "systemprompt_core_agent",
+            "module_match": "prefix",
+            "user_id": "user-1",
+            "session_id": "session-1",
+            "context_id": "context-1",
+            "search": "timeout",
+            "search_mode": "full_text"
+        })),
+        now,
+    )?;
+
+    assert_eq!(filter.level.as_deref(), Some("ERROR"));
+    assert_eq!(filter.since, Some(now - Duration::hours(1)));
+    assert!(filter.until.is_none());
+    assert_eq!(filter.module.as_deref(), Some("systemprompt_core_agent"));
+    assert_eq!(filter.module_match, ModuleMatch::Prefix);
+    assert_eq!(filter.user_id.as_deref(), Some("user-1"));
+    assert_eq!(filter.session_id.as_deref(), Some("session-1"));
+    assert_eq!(filter.context_id.as_deref(), Some("context-1"));
+    assert_eq!(filter.search.as_deref(), Some("timeout"));
+    assert_eq!(filter.search_mode, SearchMode::FullText);
+    Ok(())
+}
+
+#[test]
+fn parse_log_filter_defaults_to_unfiltered() -> Result<(), String> {
+    let filter = parse_log_filter(&Map::new(), Utc::now())?;
+
+    assert!(filter.level.is_none());
+    assert!(filter.module.is_none());
+    assert!(filter.search.is_none());
+    assert_eq!(filter.module_match, ModuleMatch::Exact);
+    assert_eq!(filter.search_mode, SearchMode::Substring);
+    assert_eq!(filter.describe(), "all logs");
+    Ok(())
+}
+
+#[test]
+fn parse_log_filter_rejects_inverted_window() {
+    let result = parse_log_filter(&args(json!({ "since": "1h", "until": "2h" })), Utc::now());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_log_filter_rejects_unknown_modes() {
+    let now = Utc::now();
+
+    assert!(parse_log_filter(&args(json!({ "module_match": "regex" })), now).is_err());
+    assert!(parse_log_filter(&args(json!({ "search_mode": "fuzzy" })), now).is_err());
+}
@@ -1 +1,17 @@
+mod audit_test;
+mod confirmation_test;
 mod dispatch_test;
+mod health_test;
+mod jobs_test;
+mod logs_clusters_test;
+mod logs_filter_test;
+mod pagination_test;
+mod permissions_test;
+mod roles_test;
+mod sessions_test;
+mod traffic_range_test;
+mod undo_test;
+mod users_filter_test;
+mod users_plan_test;
+mod users_schema_test;
+mod users_validation_test;
@@ -0,0 +1,136 @@
+use serde_json::{json, Map};
+use systemprompt_admin::tools::pagination::{
+    parse_cursor_arg, parse_sort_cursor_arg, split_page, with_next_cursor, Cursor, Page, SortCursor,
+};
+
+use super::super::common::utc_micros;
+
+#[test]
+fn cursor_round_trips_with_microsecond_precision() -> Result<(), String> {
+    let cursor = Cursor::new(utc_micros(1_736_512_496_123_456)?, "log:with:colons");
+
+    let decoded = Cursor::decode(&cursor.encode())?;
+
+    assert_eq!(decoded, cursor);
+    Ok(())
+}
+
+#[test]
+fn cursor_encoding_is_opaque() -> Result<(), String> {
+    let encoded = Cursor::new(utc_micros(1_736_512_496_123_456)?, "abc").encode();
+
+    assert!(encoded.chars().all(|c| c.is_ascii_hexdigit() || c == '.'));
+    assert!(!encoded.contains("abc"));
+    Ok(())
+}
+
+#[test]
+fn cursor_decode_rejects_tampered_values() {
+    assert!(Cursor::decode("not-a-cursor").is_err());
+    assert!(Cursor::decode("abc").is_err());
+    assert!(Cursor::decode("763a313a").is_err());
+    assert!(Cursor::decode("").is_err());
+}
+
+#[test]
+fn split_page_returns_cursor_only_when_more_rows_exist() -> Result<(), String> {
+    let ts = utc_micros(1_736_512_496_123_456)?;
+    let rows = vec!["a", "b", "c"];
+
+    let (items, next) = split_page(rows.clone(), 2, |id| Cursor::new(ts, *id));
+    assert_eq!(items, vec!["a", "b"]);
+    assert_eq!(next, Some(Cursor::new(ts, "b")));
+
+    let (items, next) = split_page(rows, 3, |id| Cursor::new(ts, *id));
+    assert_eq!(items.len(), 3);
+    assert!(next.is_none());
+    Ok(())
+}
+
+#[test]
+fn page_from_rows_maps_items() -> Result<(), String> {
+    let ts = utc_micros(1_736_512_496_123_456)?;
+
+    let page: Page<String> = Page::from_rows(
+        vec![1, 2, 3],
+        2,
+        |n| Cursor::new(ts, n.to_string()),
+        |n| format!("row-{n}"),
+    );
+
+    assert_eq!(page.items, vec!["row-1", "row-2"]);
+    assert_eq!(page.next_cursor, Some(Cursor::new(ts, "2")));
+    Ok(())
+}
+
+#[test]
+fn parse_cursor_arg_accepts_encoded_cursor_and_ignores_empty() -> Result<(), String> {
+    let cursor = Cursor::new(utc_micros(1_736_512_496_123_456)?, "id-1");
+    let mut args = Map::new();
+
+    assert_eq!(
+        parse_cursor_arg(&args).map_err(|e| e.message.to_string())?,
+        None
+    );
+
+    args.insert("cursor".to_string(), json!(""));
+    assert_eq!(
+        parse_cursor_arg(&args).map_err(|e| e.message.to_string())?,
+        None
+    );
+
+    args.insert("cursor".to_string(), json!(cursor.encode()));
+    assert_eq!(
+        parse_cursor_arg(&args).map_err(|e| e.message.to_string())?,
+        Some(cursor)
+    );
+
+    args.insert("cursor".to_string(), json!("zz"));
+    assert!(parse_cursor_arg(&args).is_err());
+    Ok(())
+}
+
+#[test]
+fn with_next_cursor_sets_field_on_structured_content() -> Result<(), String> {
+    let cursor = Cursor::new(utc_micros(1_736_512_496_123_456)?, "id-1");
+
+    let with_cursor = with_next_cursor(json!({ "artifact": {} }), Some(cursor.encode()));
+    assert_eq!(with_cursor["next_cursor"], json!(cursor.encode()));
+
+    let without_cursor = with_next_cursor(json!({ "artifact": {} }), None);
+    assert!(without_cursor["next_cursor"].is_null());
+    Ok(())
+}
+
+#[test]
+fn sort_cursor_round_trips_keys_with_separators() -> Result<(), String> {
+    let cursor = SortCursor::new("name:asc", "o'brien.smith", "user-1");
+
+    let decoded = SortCursor::decode(&cursor.encode())?;
+
+    assert_eq!(decoded, cursor);
+    assert!(Cursor::decode(&cursor.encode()).is_err());
+    Ok(())
+}
+
+#[test]
+fn sort_cursor_allows_empty_sort_key() -> Result<(), String> {
+    let cursor = SortCursor::new("last_active:desc", "", "user-1");
+
+    assert_eq!(SortCursor::decode(&cursor.encode())?, cursor);
+    Ok(())
+}
+
+#[test]
+fn parse_sort_cursor_arg_rejects_cursor_from_other_sort() -> Result<(), String> {
+    let cursor = SortCursor::new("name:asc", "ada", "user-1");
+    let mut args = Map::new();
+    args.insert("cursor".to_string(), json!(cursor.encode()));
+
+    assert_eq!(
+        parse_sort_cursor_arg(&args, "name:asc").map_err(|e| e.message.to_string())?,
+        Some(cursor)
+    );
+    assert!(parse_sort_cursor_arg(&args, "name:desc").is_err());
+    Ok(())
+}
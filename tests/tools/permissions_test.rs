@@ -0,0 +1,193 @@
+use serde_json::{json, Value as JsonValue};
+use systemprompt_admin::services::role_discovery::default_core_roles;
+use systemprompt_admin::services::{DiscoveredRole, RoleSource};
+use systemprompt_admin::tools::permissions::{
+    permission_matches, required_permission, tool_action, Caller,
+};
+use systemprompt_admin::tools::{register_tools, users_input_schema};
+
+use super::super::common::args;
+
+fn extension_role(name: &str, permissions: &[&str]) -> DiscoveredRole {
+    DiscoveredRole {
+        name: name.to_string(),
+        display_name: name.to_string(),
+        description: String::new(),
+        source: RoleSource::Extension {
+            extension_name: "support".to_string(),
+        },
+        permissions: permissions.iter().map(|p| (*p).to_string()).collect(),
+    }
+}
+
+fn roles() -> Vec<DiscoveredRole> {
+    let mut roles = default_core_roles();
+    roles.push(extension_role(
+        "support",
+        &["admin.users.read", "admin.users.write"],
+    ));
+    roles.push(extension_role("janitor", &["admin.files.*"]));
+    roles.push(extension_role(
+        "bulk_editor",
+        &["admin.users.bulk", "admin.users.write"],
+    ));
+    roles
+}
+
+#[test]
+fn permission_matches_exact_and_wildcard_grants() {
+    assert!(permission_matches("admin.users.read", "admin.users.read"));
+    assert!(permission_matches("admin.users.*", "admin.users.delete"));
+    assert!(permission_matches("admin.*", "admin.files.delete"));
+    assert!(permission_matches("*", "admin.files.delete"));
+    assert!(!permission_matches("admin.users.read", "admin.users.write"));
+    assert!(!permission_matches("admin.user.*", "admin.users.read"));
+    assert!(!permission_matches("admin.users.*", "admin.users"));
+}
+
+#[test]
+fn tool_action_reads_action_arguments_per_tool() {
+    assert_eq!(tool_action("user", &args(json!({}))), "list");
+    assert_eq!(
+        tool_action("user", &args(json!({ "action": "delete" }))),
+        "delete"
+    );
+    assert_eq!(
+        tool_action("jobs", &args(json!({ "execute_job": "cleanup" }))),
+        "execute"
+    );
+    assert_eq!(tool_action("jobs", &args(json!({}))), "list");
+    assert_eq!(tool_action("sessions", &args(json!({}))), "list");
+    assert_eq!(tool_action("traffic", &args(json!({}))), "report");
+    assert_eq!(
+        tool_action(
+            "user",
+            &args(json!({ "action": "bulk", "operation": "delete" }))
+        ),
+        "bulk.delete"
+    );
+    assert_eq!(
+        tool_action("user", &args(json!({ "action": "bulk" }))),
+        "bulk"
+    );
+}
+
+#[test]
+fn bulk_operations_require_bulk_and_operation_permissions() {
+    let bulk_editor = Caller::new("user-4", vec!["bulk_editor".to_string()], &roles());
+    let support = Caller::new("user-5", vec!["support".to_string()], &roles());
+    let bulk = |operation: &str| args(json!({ "action": "bulk", "operation": operation }));
+
+    assert!(bulk_editor.authorize("user", &bulk("assign_role")).is_ok());
+    assert!(bulk_editor.authorize("user", &bulk("suspend")).is_ok());
+    assert!(bulk_editor.authorize("user", &bulk("delete")).is_err());
+    assert!(bulk_editor.authorize("user", &bulk("purge")).is_err());
+    assert!(support.authorize("user", &bulk("assign_role")).is_err());
+}
+
+#[test]
+fn undo_requires_the_original_action_permission() {
+    let support = Caller::new("user-5", vec!["support".to_string()], &roles());
+    let bulk_editor = Caller::new("user-4", vec!["bulk_editor".to_string()], &roles());
+
+    assert!(support.authorize_action("user", "assign_role").is_ok());
+    assert!(support.authorize_action("user", "remove_role").is_ok());
+    assert!(support.authorize_action("user", "delete").is_err());
+    assert!(support
+        .authorize_action("user", "bulk.remove_role")
+        .is_err());
+    assert!(bulk_editor
+        .authorize_action("user", "bulk.remove_role")
+        .is_ok());
+    assert!(bulk_editor.authorize_action("user", "bulk.delete").is_err());
+}
+
+#[test]
+fn caller_permissions_come_from_held_discovered_roles() {
+    let caller = Caller::new(
+        "user-1",
+        vec!["user".to_string(), "support".to_string()],
+        &roles(),
+    );
+
+    assert_eq!(
+        caller.permissions,
+        vec!["admin.users.read", "admin.users.write"]
+    );
+    assert!(caller
+        .authorize("user", &args(json!({ "action": "suspend" })))
+        .is_ok());
+    assert!(caller
+        .authorize("user", &args(json!({ "action": "delete" })))
+        .is_err());
+    assert!(caller
+        .authorize("operations", &args(json!({ "action": "delete_content" })))
+        .is_err());
+    assert!(caller.can_use_tool("user"));
+    assert!(!caller.can_use_tool("operations"));
+}
+
+#[test]
+fn file_permissions_do_not_grant_content_deletes() {
+    let caller = Caller::new("user-2", vec!["janitor".to_string()], &roles());
+
+    assert!(caller
+        .authorize("operations", &args(json!({ "action": "delete_file" })))
+        .is_ok());
+    assert!(caller
+        .authorize("operations", &args(json!({ "action": "delete_content" })))
+        .is_err());
+}
+
+#[test]
+fn require_permission_names_the_missing_grant() {
+    let caller = Caller::new("user-4", vec!["janitor".to_string()], &roles());
+
+    assert!(caller
+        .require_permission("admin://files/f-1", "admin.files.read")
+        .is_ok());
+    let denied = caller.require_permission("admin://users/u-1", "admin.users.read");
+    assert!(denied
+        .is_err_and(|message| message.contains("admin.users.read") && message.contains("janitor")));
+}
+
+#[test]
+fn admin_role_can_use_every_registered_tool() {
+    let admin = Caller::new("admin-1", vec!["admin".to_string()], &default_core_roles());
+    let user = Caller::new("user-3", vec!["user".to_string()], &default_core_roles());
+
+    for tool in register_tools() {
+        assert!(
+            admin.can_use_tool(&tool.name),
+            "admin cannot use {}",
+            tool.name
+        );
+        assert!(!user.can_use_tool(&tool.name), "user can use {}", tool.name);
+    }
+}
+
+#[test]
+fn every_user_action_declares_a_permission() {
+    let schema = users_input_schema();
+    let actions: Vec<&str> = schema["properties"]["action"]["enum"]
+        .as_array()
+        .map(|values| values.iter().filter_map(JsonValue::as_str).collect())
+        .unwrap_or_default();
+
+    assert!(!actions.is_empty());
+    for action in actions {
+        assert!(
+            required_permission("user", action).is_some(),
+            "user {action} has no permission"
+        );
+    }
+}
+
+#[test]
+fn undeclared_actions_are_denied() {
+    let admin = Caller::new("admin-1", vec!["admin".to_string()], &default_core_roles());
+
+    assert!(admin
+        .authorize("operations", &args(json!({ "action": "drop_database" })))
+        .is_err());
+}
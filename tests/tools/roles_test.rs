@@ -0,0 +1,75 @@
+use systemprompt_admin::services::{DiscoveredRole, RoleSource};
+use systemprompt_admin::tools::roles::models::{summarize_roles, RoleHolderCount, RoleStatus};
+
+fn count(role: &str, user_count: i64) -> RoleHolderCount {
+    RoleHolderCount {
+        role: role.to_string(),
+        user_count,
+    }
+}
+
+fn extension_role(name: &str, extension_name: &str, permissions: &[&str]) -> DiscoveredRole {
+    DiscoveredRole {
+        name: name.to_string(),
+        display_name: name.to_string(),
+        description: String::new(),
+        source: RoleSource::Extension {
+            extension_name: extension_name.to_string(),
+        },
+        permissions: permissions.iter().map(|p| (*p).to_string()).collect(),
+    }
+}
+
+#[test]
+fn summarize_roles_counts_holders_and_keeps_discovery_order() {
+    let discovered = vec![
+        DiscoveredRole::core("user", "User", "Authenticated user"),
+        extension_role("editor", "blog", &["content.write"]),
+    ];
+
+    let roles = summarize_roles(&discovered, &[count("editor", 3), count("user", 40)]);
+
+    let names: Vec<&str> = roles.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec!["user", "editor"]);
+    assert_eq!(roles[0].source, "core");
+    assert_eq!(roles[0].user_count, 40);
+    assert_eq!(roles[1].source, "blog");
+    assert_eq!(roles[1].permissions, vec!["content.write"]);
+    assert!(roles.iter().all(|r| r.status == RoleStatus::Held));
+}
+
+#[test]
+fn summarize_roles_flags_unheld_and_orphaned_roles() {
+    let discovered = vec![
+        DiscoveredRole::core("admin", "Admin", "Full system administrator"),
+        extension_role("moderator", "forum", &[]),
+    ];
+
+    let roles = summarize_roles(
+        &discovered,
+        &[count("admin", 2), count("legacy", 1), count("beta", 5)],
+    );
+
+    let statuses: Vec<(&str, RoleStatus, i64)> = roles
+        .iter()
+        .map(|r| (r.name.as_str(), r.status, r.user_count))
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            ("admin", RoleStatus::Held, 2),
+            ("moderator", RoleStatus::Unheld, 0),
+            ("beta", RoleStatus::Orphaned, 5),
+            ("legacy", RoleStatus::Orphaned, 1),
+        ]
+    );
+    assert_eq!(roles[2].source, "undeclared");
+}
+
+#[test]
+fn role_status_parses_its_own_names() {
+    for status in RoleStatus::ALL {
+        assert_eq!(RoleStatus::parse(status.as_str()), Some(status));
+    }
+    assert_eq!(RoleStatus::parse("all"), None);
+}
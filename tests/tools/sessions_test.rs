@@ -0,0 +1,84 @@
+use chrono::Utc;
+use serde_json::json;
+use systemprompt_admin::tools::permissions::required_permission;
+use systemprompt_admin::tools::sessions::models::{
+    parse_session_filter, SessionFilter, SessionFlag, SessionSummary,
+};
+use systemprompt_admin::tools::sessions_input_schema;
+
+use super::super::common::args;
+
+fn summary(is_bot: bool, is_scanner: bool) -> SessionSummary {
+    SessionSummary {
+        session_id: "sess_test".to_string(),
+        user_id: None,
+        started_at: Utc::now(),
+        last_activity_at: None,
+        ended_at: None,
+        device_type: "desktop".to_string(),
+        browser: "Firefox".to_string(),
+        country: "Unknown".to_string(),
+        referrer: "Direct".to_string(),
+        request_count: 0,
+        ai_cost_cents: 0,
+        is_bot,
+        is_scanner,
+    }
+}
+
+#[test]
+fn session_filter_defaults_to_active_sessions_of_all_traffic() -> Result<(), String> {
+    assert_eq!(
+        parse_session_filter(&args(json!({})))?,
+        SessionFilter::default()
+    );
+
+    let filter = parse_session_filter(&args(json!({
+        "user_id": " user_1 ",
+        "status": "ended",
+        "traffic": "bot"
+    })))?;
+    assert_eq!(filter.user_id.as_deref(), Some("user_1"));
+    assert_eq!(filter.status, "ended");
+    assert_eq!(filter.traffic, "bot");
+    Ok(())
+}
+
+#[test]
+fn session_filter_rejects_unknown_values() {
+    assert!(parse_session_filter(&args(json!({ "status": "expired" }))).is_err());
+    assert!(parse_session_filter(&args(json!({ "traffic": "crawler" }))).is_err());
+}
+
+#[test]
+fn session_flags_set_exactly_one_marker() {
+    assert_eq!(SessionFlag::parse("bot"), Some(SessionFlag::Bot));
+    assert_eq!(SessionFlag::parse("Bot"), None);
+    assert!(SessionFlag::Bot.is_bot() && !SessionFlag::Bot.is_scanner());
+    assert!(SessionFlag::Scanner.is_scanner() && !SessionFlag::Scanner.is_bot());
+    assert!(!SessionFlag::Human.is_bot() && !SessionFlag::Human.is_scanner());
+}
+
+#[test]
+fn scanner_marker_takes_precedence_over_bot() {
+    assert_eq!(summary(false, false).traffic(), "human");
+    assert_eq!(summary(true, false).traffic(), "bot");
+    assert_eq!(summary(true, true).traffic(), "scanner");
+    assert!(summary(false, false).active());
+}
+
+#[test]
+fn end_action_says_it_only_marks_sessions_ended() {
+    let schema = sessions_input_schema();
+    let action = &schema["properties"]["action"];
+
+    assert_eq!(action["enum"], json!(["list", "get", "end", "mark"]));
+    assert!(action["description"]
+        .as_str()
+        .is_some_and(|description| description.contains("does not revoke access tokens")));
+    assert_eq!(
+        required_permission("sessions", "end"),
+        Some("admin.sessions.end")
+    );
+    assert_eq!(required_permission("sessions", "revoke"), None);
+}
@@ -0,0 +1,199 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde_json::json;
+use systemprompt_admin::tools::traffic::models::{percent_change, BotTrafficSummary};
+use systemprompt_admin::tools::traffic::range::{
+    parse_traffic_range, Comparison, Granularity, TrafficPeriod, TrafficRange, MAX_SERIES_BUCKETS,
+};
+use systemprompt_admin::tools::traffic::windows::{TimeWindow, ZonedBounds};
+
+use super::super::common::args;
+
+fn at(value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|at| at.with_timezone(&Utc))
+        .map_err(|e| e.to_string())
+}
+
+#[test]
+fn traffic_range_defaults_to_last_30_days_in_utc() -> Result<(), String> {
+    assert_eq!(
+        parse_traffic_range(&args(json!({})))?,
+        TrafficRange::default()
+    );
+    assert_eq!(
+        parse_traffic_range(&args(json!({ "time_range": "7d" })))?.period,
+        TrafficPeriod::LastDays(7)
+    );
+    Ok(())
+}
+
+#[test]
+fn custom_dates_take_precedence_over_time_range() -> Result<(), String> {
+    let range = parse_traffic_range(&args(json!({
+        "time_range": "7d",
+        "start": "2024-03-01",
+        "end": "2024-03-31",
+        "timezone": "America/New_York",
+        "compare_to": "previous_year"
+    })))?;
+
+    assert_eq!(
+        range.period,
+        TrafficPeriod::Dates {
+            start: NaiveDate::from_ymd_opt(2024, 3, 1).ok_or("bad date")?,
+            end: NaiveDate::from_ymd_opt(2024, 3, 31),
+        }
+    );
+    assert_eq!(range.timezone, "America/New_York");
+    assert_eq!(range.compare_to, Some(Comparison::PreviousYear));
+    Ok(())
+}
+
+#[test]
+fn invalid_ranges_are_rejected() {
+    for value in [
+        json!({ "time_range": "14d" }),
+        json!({ "start": "03/01/2024" }),
+        json!({ "end": "2024-03-31" }),
+        json!({ "start": "2024-04-01", "end": "2024-03-31" }),
+        json!({ "compare_to": "last_week" }),
+    ] {
+        assert!(
+            parse_traffic_range(&args(value.clone())).is_err(),
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn comparison_windows_shift_back_by_period_or_year() -> Result<(), String> {
+    let window = TimeWindow {
+        start: at("2024-03-01T00:00:00Z")?,
+        end: at("2024-03-08T00:00:00Z")?,
+    };
+
+    let previous = window.previous(Comparison::PreviousPeriod)?;
+    assert_eq!(previous.start, at("2024-02-23T00:00:00Z")?);
+    assert_eq!(previous.end, window.start);
+
+    let last_year = window.previous(Comparison::PreviousYear)?;
+    assert_eq!(last_year.start, at("2023-03-01T00:00:00Z")?);
+    assert_eq!(last_year.end, at("2023-03-08T00:00:00Z")?);
+    Ok(())
+}
+
+#[test]
+fn windows_clamp_to_now_and_reject_future_or_inverted_ranges() -> Result<(), String> {
+    let now = at("2024-03-10T12:00:00Z")?;
+    let dates = |start: &str, end: Option<&str>| -> Result<TrafficPeriod, String> {
+        let date =
+            |value: &str| NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|e| e.to_string());
+        Ok(TrafficPeriod::Dates {
+            start: date(start)?,
+            end: end.map(date).transpose()?,
+        })
+    };
+    let bounds = |start: &str, end: &str| -> Result<ZonedBounds, String> {
+        Ok(ZonedBounds {
+            start_at: Some(at(start)?),
+            end_at: Some(at(end)?),
+        })
+    };
+
+    let last_week =
+        TimeWindow::from_bounds(TrafficPeriod::LastDays(7), ZonedBounds::default(), now)?;
+    assert_eq!(last_week.start, at("2024-03-03T12:00:00Z")?);
+    assert_eq!(last_week.end, now);
+
+    let this_month = TimeWindow::from_bounds(
+        dates("2024-03-01", Some("2024-03-31"))?,
+        bounds("2024-03-01T00:00:00Z", "2024-04-01T00:00:00Z")?,
+        now,
+    )?;
+    assert_eq!(this_month.start, at("2024-03-01T00:00:00Z")?);
+    assert_eq!(this_month.end, now);
+
+    assert!(TimeWindow::from_bounds(
+        dates("2024-03-11", None)?,
+        bounds("2024-03-11T00:00:00Z", "2024-03-12T00:00:00Z")?,
+        now,
+    )
+    .is_err());
+    assert!(TimeWindow::from_bounds(
+        dates("2024-03-01", Some("2024-03-02"))?,
+        bounds("2024-03-05T00:00:00Z", "2024-03-03T00:00:00Z")?,
+        now,
+    )
+    .is_err());
+    assert!(
+        TimeWindow::from_bounds(dates("2024-03-01", None)?, ZonedBounds::default(), now).is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn series_windows_are_capped_at_a_fixed_bucket_count() -> Result<(), String> {
+    let end = at("2024-03-31T00:00:00Z")?;
+    let window = |days: i64| TimeWindow {
+        start: end - Duration::days(days),
+        end,
+    };
+
+    assert_eq!(window(7).bucket_count(Granularity::Day), 8);
+    assert_eq!(window(7).bucket_count(Granularity::Hour), 169);
+    assert!(window(30).ensure_bucket_limit(Granularity::Hour).is_ok());
+    assert!(window(90).ensure_bucket_limit(Granularity::Hour).is_err());
+    assert!(window(90).ensure_bucket_limit(Granularity::Day).is_ok());
+    assert!(window(3650).ensure_bucket_limit(Granularity::Day).is_err());
+    assert!(window(3650).ensure_bucket_limit(Granularity::Week).is_ok());
+    assert!(window(3650).bucket_count(Granularity::Week) <= MAX_SERIES_BUCKETS);
+    Ok(())
+}
+
+#[test]
+fn percent_change_is_undefined_without_a_baseline() {
+    assert_eq!(percent_change(150.0, 100.0), Some(50.0));
+    assert_eq!(percent_change(50.0, 100.0), Some(-50.0));
+    assert_eq!(percent_change(10.0, 0.0), None);
+}
+
+#[test]
+fn granularity_defaults_to_day_and_rejects_unknown_buckets() -> Result<(), String> {
+    assert_eq!(
+        parse_traffic_range(&args(json!({})))?.granularity,
+        Granularity::Day
+    );
+    assert_eq!(
+        parse_traffic_range(&args(json!({ "granularity": "hour" })))?.granularity,
+        Granularity::Hour
+    );
+    assert!(parse_traffic_range(&args(json!({ "granularity": "month" }))).is_err());
+    Ok(())
+}
+
+#[test]
+fn bot_report_is_opt_in() -> Result<(), String> {
+    assert!(!parse_traffic_range(&args(json!({})))?.include_bots);
+    assert!(parse_traffic_range(&args(json!({ "include": "bots" })))?.include_bots);
+    assert!(parse_traffic_range(&args(json!({ "include": "crawlers" }))).is_err());
+    Ok(())
+}
+
+#[test]
+fn automated_share_counts_bots_and_scanners() {
+    let summary = BotTrafficSummary {
+        bot_sessions: 3,
+        scanner_sessions: 1,
+        bot_requests: 30,
+        scanner_requests: 10,
+        total_requests: 160,
+    };
+    assert_eq!(summary.automated_requests(), 40);
+    assert!((summary.automated_share() - 25.0).abs() < f64::EPSILON);
+
+    let empty = BotTrafficSummary {
+        total_requests: 0,
+        ..summary
+    };
+    assert!(empty.automated_share().abs() < f64::EPSILON);
+}
@@ -0,0 +1,125 @@
+use chrono::Duration;
+use rmcp::model::{CallToolResult, Content};
+use serde_json::json;
+use systemprompt_admin::services::user_tokens::hash_token;
+use systemprompt_admin::tools::undo::attach_undo;
+use systemprompt_admin::tools::undo::models::{
+    check_redeemable, parse_undo_ttl, IssuedUndo, UndoAction, DEFAULT_UNDO_TTL_MINUTES,
+    MAX_UNDO_TTL_DAYS,
+};
+
+use super::super::common::fixed_now;
+
+#[test]
+fn issued_undo_stores_only_the_hash_and_expires_after_ttl() -> Result<(), String> {
+    let now = fixed_now()?;
+    let undo = IssuedUndo::issue(now, Duration::minutes(15))?;
+
+    assert_eq!(undo.token.len(), 64);
+    assert_eq!(undo.token_hash, hash_token(&undo.token));
+    assert_ne!(undo.token_hash, undo.token);
+    assert_eq!(undo.expires_at, now + Duration::minutes(15));
+    assert_eq!(undo.to_json()["token"], json!(undo.token));
+    Ok(())
+}
+
+#[test]
+fn undo_ttl_defaults_when_unset_and_rejects_invalid_values() -> Result<(), String> {
+    assert_eq!(parse_undo_ttl(Some("2h"))?, Duration::hours(2));
+    assert_eq!(parse_undo_ttl(Some(" 45m "))?, Duration::minutes(45));
+    assert_eq!(
+        parse_undo_ttl(Some("7d"))?,
+        Duration::days(MAX_UNDO_TTL_DAYS)
+    );
+    assert_eq!(
+        parse_undo_ttl(None)?,
+        Duration::minutes(DEFAULT_UNDO_TTL_MINUTES)
+    );
+
+    assert!(parse_undo_ttl(Some("soon")).is_err());
+    assert!(parse_undo_ttl(Some("0m")).is_err());
+    assert!(parse_undo_ttl(Some("8d")).is_err());
+    assert!(parse_undo_ttl(Some("99999999999999d")).is_err());
+    Ok(())
+}
+
+#[test]
+fn issuing_undo_rejects_lifetimes_past_the_clock() -> Result<(), String> {
+    assert!(IssuedUndo::issue(fixed_now()?, Duration::MAX).is_err());
+    Ok(())
+}
+
+#[test]
+fn used_or_expired_tokens_cannot_be_redeemed() -> Result<(), String> {
+    let now = fixed_now()?;
+
+    assert!(check_redeemable(None, now + Duration::minutes(1), now).is_ok());
+    assert!(check_redeemable(None, now, now).is_err());
+    assert!(check_redeemable(Some(now), now + Duration::minutes(1), now).is_err());
+    Ok(())
+}
+
+#[test]
+fn undo_actions_round_trip_through_json() -> anyhow::Result<()> {
+    let actions = [
+        UndoAction::RestoreFile {
+            file_id: "f-1".to_string(),
+        },
+        UndoAction::RestoreContent {
+            content_id: "c-1".to_string(),
+            snapshot: json!({ "id": "c-1", "title": "Post" }),
+        },
+        UndoAction::RestoreRoles {
+            user_id: "u-1".to_string(),
+            roles_before: vec!["user".to_string()],
+            roles_after: vec!["user".to_string(), "admin".to_string()],
+        },
+        UndoAction::RestoreStatus {
+            user_id: "u-2".to_string(),
+            status_before: "active".to_string(),
+            status_after: "deleted".to_string(),
+        },
+    ];
+
+    for action in actions {
+        let value = serde_json::to_value(&action)?;
+        assert!(value["kind"]
+            .as_str()
+            .is_some_and(|kind| kind.starts_with("restore_")));
+        assert_eq!(serde_json::from_value::<UndoAction>(value)?, action);
+    }
+    Ok(())
+}
+
+#[test]
+fn undo_actions_describe_their_target() {
+    let roles = UndoAction::RestoreRoles {
+        user_id: "u-1".to_string(),
+        roles_before: vec!["user".to_string()],
+        roles_after: vec![],
+    };
+
+    assert_eq!(roles.target_id(), "u-1");
+    assert_eq!(roles.describe(), "restored roles of user u-1 to [user]");
+}
+
+#[test]
+fn attach_undo_adds_token_to_text_and_structured_content() -> Result<(), String> {
+    let undo = IssuedUndo::issue(fixed_now()?, Duration::minutes(5))?;
+    let result = CallToolResult {
+        content: vec![Content::text("Deleted")],
+        structured_content: Some(json!({ "success": true })),
+        is_error: Some(false),
+        meta: None,
+    };
+
+    let result = attach_undo(result, &undo);
+
+    assert_eq!(result.content.len(), 2);
+    let structured = result
+        .structured_content
+        .ok_or("structured content missing")?;
+    assert_eq!(structured["undo"]["token"], json!(undo.token));
+    assert_eq!(structured["success"], json!(true));
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use chrono::Duration;
+use serde_json::{json, Map};
+use systemprompt_admin::tools::users::filter::{parse_expiry, parse_user_filter, parse_user_sort};
+use systemprompt_admin::tools::users::models::{UserSort, UserSortColumn};
+
+use super::super::common::{args, fixed_now, utc};
+
+#[test]
+fn empty_args_hide_deleted_users_by_default() -> Result<(), String> {
+    let filter = parse_user_filter(&Map::new(), fixed_now()?)?;
+
+    assert!(filter.status.is_none());
+    assert!(filter.email.is_none());
+    assert!(filter.min_sessions.is_none());
+    assert!(filter.is_empty());
+    assert!(!filter.filters_activity());
+    Ok(())
+}
+
+#[test]
+fn parses_all_list_filters() -> Result<(), String> {
+    let now = fixed_now()?;
+    let filter = parse_user_filter(
+        &args(json!({
+            "email": " @example.com ",
+            "name": "ada",
+            "role": "admin",
+            "status": "deleted",
+            "created_after": "30d",
+            "created_before": "2025-01-09T00:00:00Z",
+            "min_sessions": 3,
+            "last_active_before": "90d"
+        })),
+        now,
+    )?;
+
+    assert_eq!(filter.email.as_deref(), Some("@example.com"));
+    assert_eq!(filter.name.as_deref(), Some("ada"));
+    assert_eq!(filter.role.as_deref(), Some("admin"));
+    assert_eq!(filter.status.as_deref(), Some("deleted"));
+    assert_eq!(filter.created_after, Some(now - Duration::days(30)));
+    assert_eq!(filter.min_sessions, Some(3));
+    assert_eq!(filter.last_active_before, Some(now - Duration::days(90)));
+    assert!(!filter.is_empty());
+    assert!(filter.filters_activity());
+    Ok(())
+}
+
+#[test]
+fn rejects_invalid_filters() -> Result<(), String> {
+    let now = fixed_now()?;
+
+    assert!(parse_user_filter(&args(json!({ "status": "banned" })), now).is_err());
+    assert!(parse_user_filter(&args(json!({ "min_sessions": -1 })), now).is_err());
+    assert!(parse_user_filter(&args(json!({ "min_sessions": "many" })), now).is_err());
+    assert!(parse_user_filter(
+        &args(json!({ "created_after": "1d", "created_before": "7d" })),
+        now
+    )
+    .is_err());
+    Ok(())
+}
+
+#[test]
+fn sort_defaults_to_newest_first() -> Result<(), String> {
+    let sort = parse_user_sort(&Map::new())?;
+
+    assert_eq!(sort, UserSort::default());
+    assert_eq!(sort.cursor_name(), "created_at:desc");
+    assert!(!sort.column.is_activity());
+    Ok(())
+}
+
+#[test]
+fn parses_sort_column_and_order() -> Result<(), String> {
+    let sort = parse_user_sort(&args(json!({ "sort": "last_active", "order": "asc" })))?;
+
+    assert_eq!(sort.column, UserSortColumn::LastActive);
+    assert!(sort.column.is_activity());
+    assert!(sort.ascending);
+    assert_eq!(sort.cursor_name(), "last_active:asc");
+
+    assert!(parse_user_sort(&args(json!({ "sort": "password" }))).is_err());
+    assert!(parse_user_sort(&args(json!({ "order": "sideways" }))).is_err());
+    Ok(())
+}
+
+#[test]
+fn parses_suspension_expiry_as_future_time() -> Result<(), String> {
+    let now = fixed_now()?;
+
+    assert_eq!(parse_expiry("7d", now)?, now + Duration::days(7));
+    assert_eq!(
+        parse_expiry("2025-02-01T00:00:00Z", now)?,
+        utc(2025, 2, 1, 0, 0)?
+    );
+    assert!(parse_expiry("2025-01-01T00:00:00Z", now).is_err());
+    assert!(parse_expiry("0h", now).is_err());
+    assert!(parse_expiry("soon", now).is_err());
+    assert!(parse_expiry("100000000d", now).is_err());
+    Ok(())
+}
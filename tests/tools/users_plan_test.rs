@@ -0,0 +1,159 @@
+use chrono::Duration;
+use serde_json::{json, Map};
+use systemprompt_admin::tools::undo::models::UndoAction;
+use systemprompt_admin::tools::users::models::User;
+use systemprompt_admin::tools::users::plan::{
+    plan_bulk, plan_change, roles_without, BulkOperation, LAST_ROLE_ERROR,
+};
+
+use super::super::common::{args, fixed_now};
+
+fn user(id: &str, status: &str, roles: &[&str]) -> User {
+    User {
+        id: id.to_string(),
+        name: format!("name-{id}"),
+        email: format!("{id}@example.com"),
+        display_name: None,
+        status: status.to_string(),
+        roles: roles.iter().map(|r| (*r).to_string()).collect(),
+        total_sessions: 0,
+        created_at: "2025-01-01 00:00:00+00".to_string(),
+        last_active: None,
+        sort_key: String::new(),
+    }
+}
+
+#[test]
+fn roles_without_keeps_at_least_one_role() {
+    let roles = vec!["user".to_string(), "admin".to_string()];
+
+    assert_eq!(roles_without(&roles, "admin"), Ok(vec!["user".to_string()]));
+    assert_eq!(
+        roles_without(&["user".to_string()], "user"),
+        Err(LAST_ROLE_ERROR)
+    );
+}
+
+#[test]
+fn remove_role_skips_users_whose_last_role_it_is() {
+    let operation = BulkOperation::RemoveRole("beta".to_string());
+    let users = vec![
+        user("a", "active", &["user", "beta"]),
+        user("b", "active", &["beta"]),
+        user("c", "active", &["user"]),
+    ];
+
+    let plan = plan_bulk(&users, &operation);
+
+    assert!(plan[0].applies());
+    assert_eq!(plan[0].roles_after, vec!["user"]);
+    assert_eq!(plan[1].skip_reason.as_deref(), Some(LAST_ROLE_ERROR));
+    assert_eq!(plan[1].roles_after, vec!["beta"]);
+    assert!(!plan[2].applies());
+}
+
+#[test]
+fn assign_role_skips_users_who_already_hold_it() {
+    let operation = BulkOperation::AssignRole("admin".to_string());
+
+    let existing = plan_change(&user("a", "active", &["admin"]), &operation);
+    let added = plan_change(&user("b", "active", &["user"]), &operation);
+
+    assert!(!existing.applies());
+    assert!(added.applies());
+    assert_eq!(added.roles_after, vec!["user", "admin"]);
+}
+
+#[test]
+fn status_operations_skip_users_already_in_target_state() {
+    let suspend = BulkOperation::Suspend {
+        reason: "abuse".to_string(),
+        expires_at: None,
+    };
+
+    assert_eq!(
+        plan_change(&user("a", "active", &["user"]), &suspend).status_after,
+        "suspended"
+    );
+    assert!(!plan_change(&user("b", "suspended", &["user"]), &suspend).applies());
+    assert!(!plan_change(&user("c", "deleted", &["user"]), &suspend).applies());
+    assert!(!plan_change(&user("d", "deleted", &["user"]), &BulkOperation::Delete).applies());
+    assert_eq!(
+        plan_change(&user("e", "inactive", &["user"]), &BulkOperation::Delete).status_after,
+        "deleted"
+    );
+}
+
+#[test]
+fn undo_restores_whichever_field_the_change_touched() {
+    let suspend = BulkOperation::Suspend {
+        reason: "abuse".to_string(),
+        expires_at: None,
+    };
+    let remove = BulkOperation::RemoveRole("beta".to_string());
+
+    assert_eq!(
+        plan_change(&user("a", "inactive", &["user"]), &suspend).undo_action(),
+        UndoAction::RestoreStatus {
+            user_id: "a".to_string(),
+            status_before: "inactive".to_string(),
+            status_after: "suspended".to_string(),
+        }
+    );
+    assert_eq!(
+        plan_change(&user("b", "active", &["user", "beta"]), &remove).undo_action(),
+        UndoAction::RestoreRoles {
+            user_id: "b".to_string(),
+            roles_before: vec!["user".to_string(), "beta".to_string()],
+            roles_after: vec!["user".to_string()],
+        }
+    );
+}
+
+#[test]
+fn parses_bulk_operations() -> Result<(), String> {
+    let now = fixed_now()?;
+    let known = vec!["user".to_string(), "admin".to_string()];
+
+    assert_eq!(
+        BulkOperation::parse(
+            &args(json!({ "operation": "remove_role", "role": "beta" })),
+            &known,
+            now
+        )?,
+        BulkOperation::RemoveRole("beta".to_string())
+    );
+    assert_eq!(
+        BulkOperation::parse(
+            &args(json!({ "operation": "suspend", "reason": "spam", "expires_at": "1d" })),
+            &known,
+            now
+        )?,
+        BulkOperation::Suspend {
+            reason: "spam".to_string(),
+            expires_at: Some(now + Duration::days(1)),
+        }
+    );
+    assert_eq!(
+        BulkOperation::parse(
+            &args(json!({ "operation": "assign_role", "role": "admin" })),
+            &known,
+            now
+        )?,
+        BulkOperation::AssignRole("admin".to_string())
+    );
+    assert_eq!(
+        BulkOperation::parse(&args(json!({ "operation": "delete" })), &known, now)?,
+        BulkOperation::Delete
+    );
+
+    let rejects = |value| BulkOperation::parse(&args(value), &known, now).is_err();
+    assert!(rejects(json!({ "operation": "assign_role" })));
+    assert!(rejects(
+        json!({ "operation": "assign_role", "role": "superuser" })
+    ));
+    assert!(rejects(json!({ "operation": "suspend" })));
+    assert!(rejects(json!({ "operation": "purge" })));
+    assert!(BulkOperation::parse(&Map::new(), &known, now).is_err());
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use serde_json::Value as JsonValue;
+use systemprompt_admin::tools::users_input_schema;
+
+fn required_fields(schema: &JsonValue, action: &str) -> Vec<String> {
+    schema["allOf"]
+        .as_array()
+        .and_then(|rules| {
+            rules
+                .iter()
+                .find(|rule| rule["if"]["properties"]["action"]["const"] == action)
+        })
+        .and_then(|rule| rule["then"]["required"].as_array())
+        .map(|fields| {
+            fields
+                .iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn users_schema_advertises_lifecycle_actions() {
+    let schema = users_input_schema();
+
+    let actions: Vec<&str> = schema["properties"]["action"]["enum"]
+        .as_array()
+        .map(|values| values.iter().filter_map(JsonValue::as_str).collect())
+        .unwrap_or_default();
+
+    for action in [
+        "list",
+        "get",
+        "create",
+        "delete",
+        "suspend",
+        "reactivate",
+        "restore",
+        "bulk",
+    ] {
+        assert!(actions.contains(&action), "missing action {action}");
+    }
+}
+
+#[test]
+fn users_schema_describes_required_fields_per_action() {
+    let schema = users_input_schema();
+
+    assert!(required_fields(&schema, "list").is_empty());
+    assert_eq!(
+        required_fields(&schema, "suspend"),
+        vec!["user_id", "reason"]
+    );
+    assert_eq!(required_fields(&schema, "create"), vec!["name", "email"]);
+    assert_eq!(required_fields(&schema, "reactivate"), vec!["user_id"]);
+    assert_eq!(required_fields(&schema, "restore"), vec!["user_id"]);
+    assert_eq!(
+        required_fields(&schema, "bulk"),
+        vec!["operation", "filter"]
+    );
+    assert_eq!(
+        required_fields(&schema, "assign_role"),
+        vec!["user_id", "role"]
+    );
+}
+
+#[test]
+fn users_schema_documents_every_property_referenced_as_required() {
+    let schema = users_input_schema();
+
+    for rule in schema["allOf"].as_array().into_iter().flatten() {
+        for field in rule["then"]["required"].as_array().into_iter().flatten() {
+            let field = field.as_str().unwrap_or_default();
+            assert!(
+                schema["properties"].get(field).is_some(),
+                "required field {field} is not a declared property"
+            );
+        }
+    }
+}
+
+#[test]
+fn bulk_filter_offers_every_list_filter_field() {
+    let schema = users_input_schema();
+    let filter = &schema["properties"]["filter"]["properties"];
+
+    for field in [
+        "email",
+        "name",
+        "role",
+        "status",
+        "created_after",
+        "created_before",
+        "min_sessions",
+        "last_active_before",
+    ] {
+        assert!(filter.get(field).is_some(), "filter is missing {field}");
+        assert!(
+            schema["properties"].get(field).is_some(),
+            "list is missing {field}"
+        );
+    }
+}
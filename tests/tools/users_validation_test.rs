@@ -0,0 +1,71 @@
+use serde_json::json;
+use systemprompt_admin::tools::users::validation::{
+    parse_new_user, validate_email, validate_roles, DEFAULT_NEW_USER_ROLE,
+};
+
+use super::super::common::args;
+
+fn known_roles() -> Vec<String> {
+    ["anonymous", "user", "admin", "editor"]
+        .iter()
+        .map(|r| (*r).to_string())
+        .collect()
+}
+
+#[test]
+fn parses_new_user_with_defaults() -> Result<(), String> {
+    let user = parse_new_user(
+        &args(json!({ "name": "ada", "email": " Ada@Example.COM " })),
+        &known_roles(),
+    )?;
+
+    assert_eq!(user.name, "ada");
+    assert_eq!(user.email, "ada@example.com");
+    assert_eq!(user.display_name, None);
+    assert_eq!(user.roles, vec![DEFAULT_NEW_USER_ROLE]);
+    Ok(())
+}
+
+#[test]
+fn roles_must_be_discovered_and_are_deduplicated() -> Result<(), String> {
+    let roles = validate_roles(Some(&json!(["editor", "user", "editor"])), &known_roles())?;
+    assert_eq!(roles, vec!["editor", "user"]);
+
+    assert!(validate_roles(Some(&json!(["superuser"])), &known_roles()).is_err());
+    assert!(validate_roles(Some(&json!([])), &known_roles()).is_err());
+    assert!(validate_roles(Some(&json!("admin")), &known_roles()).is_err());
+    assert!(validate_roles(None, &["admin".to_string()]).is_err());
+    Ok(())
+}
+
+#[test]
+fn rejects_malformed_emails() {
+    for email in [
+        "",
+        "ada",
+        "@example.com",
+        "ada@",
+        "ada@example",
+        "a b@example.com",
+        "a@b@c.com",
+    ] {
+        assert!(validate_email(email).is_err(), "{email} should be rejected");
+    }
+    assert_eq!(
+        validate_email("grace.hopper@navy.mil"),
+        Ok("grace.hopper@navy.mil".to_string())
+    );
+}
+
+#[test]
+fn requires_name_and_email() {
+    let roles = known_roles();
+
+    assert!(parse_new_user(&args(json!({ "email": "ada@example.com" })), &roles).is_err());
+    assert!(parse_new_user(&args(json!({ "name": "ada" })), &roles).is_err());
+    assert!(parse_new_user(
+        &args(json!({ "name": "ada lovelace", "email": "ada@example.com" })),
+        &roles
+    )
+    .is_err());
+}
@@ -1 +1,10 @@
+mod common {
+    mod args;
+    mod clock;
+    mod micros;
+
+    pub use args::args;
+    pub use clock::{fixed_now, utc};
+    pub use micros::utc_micros;
+}
 mod tools;